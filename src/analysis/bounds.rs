@@ -113,33 +113,8 @@ impl Bounds {
                         finish_function_name(func_name)
                     };
                     if let Some(function) = find_function(env, &finish_func_name) {
-                        // FIXME: This should work completely based on the analysis of the finish()
-                        // function but that a) happens afterwards and b) is
-                        // not accessible from here either.
-                        let mut out_parameters =
-                            find_out_parameters(env, function, configured_functions);
-                        if use_function_return_for_result(
-                            env,
-                            function.ret.typ(),
-                            &func.name,
-                            configured_functions,
-                        ) {
-                            let nullable = configured_functions
-                                .iter()
-                                .find_map(|f| f.ret.nullable)
-                                .unwrap_or(function.ret.is_nullable());
-
-                            out_parameters.insert(
-                                0,
-                                RustType::builder(env, function.ret.typ())
-                                    .direction(function.ret.direction())
-                                    .nullable(nullable)
-                                    .try_build()
-                                    .into_string(),
-                            );
-                        }
-                        let parameters = format_out_parameters(&out_parameters);
-                        let error_type = find_error_type(env, function);
+                        let (parameters, error_type) =
+                            result_types(env, function, &func.name, configured_functions);
                         if let Some(ref error) = error_type {
                             type_string =
                                 format!("FnOnce(Result<{parameters}, {error}>) + 'static");
@@ -275,6 +250,12 @@ impl Bounds {
         self.iter().find(move |n| n.parameter_name == name)
     }
 
+    pub fn set_parameter_type_str(&mut self, name: &str, type_str: String) {
+        if let Some(bound) = self.used.iter_mut().find(|n| n.parameter_name == name) {
+            bound.type_str = type_str;
+        }
+    }
+
     pub fn update_imports(&self, imports: &mut Imports) {
         // TODO: import with versions
         use self::BoundType::*;
@@ -322,6 +303,39 @@ impl PropertyBound {
     }
 }
 
+/// Returns the Rust success and error types of the results of `function`,
+/// as passed to the callback of the async function it finishes.
+pub fn result_types(
+    env: &Env,
+    function: &Function,
+    func_name: &str,
+    configured_functions: &[&config::functions::Function],
+) -> (String, Option<String>) {
+    // FIXME: This should work completely based on the analysis of the finish()
+    // function but that a) happens afterwards and b) is
+    // not accessible from here either.
+    let mut out_parameters = find_out_parameters(env, function, configured_functions);
+    if use_function_return_for_result(env, function.ret.typ(), func_name, configured_functions) {
+        let nullable = configured_functions
+            .iter()
+            .find_map(|f| f.ret.nullable)
+            .unwrap_or(function.ret.is_nullable());
+
+        out_parameters.insert(
+            0,
+            RustType::builder(env, function.ret.typ())
+                .direction(function.ret.direction())
+                .nullable(nullable)
+                .try_build()
+                .into_string(),
+        );
+    }
+    (
+        format_out_parameters(&out_parameters),
+        find_error_type(env, function),
+    )
+}
+
 fn find_out_parameters(
     env: &Env,
     function: &Function,
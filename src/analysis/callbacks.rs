@@ -0,0 +1,396 @@
+use std::collections::BTreeMap;
+
+use super::{
+    bounds::Bounds, imports::Imports, namespaces, rust_type::RustType, trampoline_parameters,
+    trampolines::Trampoline,
+};
+use crate::{
+    env::Env,
+    library::{self, TypeId},
+    nameutil::{module_name, split_namespace_name},
+    traits::*,
+};
+
+/// A GIR callback type used by several functions, generated once as a trait
+/// with a shared trampoline.
+#[derive(Debug)]
+pub struct Info {
+    pub type_id: TypeId,
+    /// Name of the trait.
+    pub name: String,
+    /// Closure signature the trait stands for.
+    pub signature: String,
+    /// Trampoline calling a closure of type `P` stored in the user data.
+    pub trampoline: Trampoline,
+}
+
+/// Name of the shared trampoline calling a callback of type `tid`.
+pub fn trampoline_name(env: &Env, tid: TypeId) -> String {
+    format!("{}_trampoline", module_name(&env.type_(tid).get_name()))
+}
+
+/// Name of the shared function dropping a boxed callback of type `tid`.
+pub fn destroy_name(env: &Env, tid: TypeId) -> String {
+    format!("{}_destroy", module_name(&env.type_(tid).get_name()))
+}
+
+/// Closure signature of callbacks of type `tid`, without lifetime or thread
+/// safety bounds.
+fn signature(env: &Env, tid: TypeId) -> Option<String> {
+    let rust_type = RustType::builder(env, tid)
+        .scope(Some(gir_parser::FunctionScope::Notified))
+        .try_build()
+        .ok()?;
+    rust_type
+        .as_str()
+        .strip_suffix(" + 'static")
+        .map(ToOwned::to_owned)
+}
+
+/// Returns the configured object whose generated or manual items already use
+/// the name of the callback type `tid`.
+fn name_clash(env: &Env, tid: TypeId) -> Option<&str> {
+    let full_name = tid.full_name(&env.library);
+    let name = env.type_(tid).get_name();
+    env.config
+        .objects
+        .values()
+        .filter(|obj| obj.name != full_name)
+        .find(|obj| {
+            let short_name = split_namespace_name(&obj.name).1;
+            let trait_name = obj
+                .trait_name
+                .clone()
+                .unwrap_or_else(|| format!("{short_name}Ext"));
+            short_name == name
+                || trait_name == name
+                || format!("{trait_name}Manual") == name
+                || format!("{short_name}Builder") == name
+                || obj.manual_traits.contains(&name)
+                || obj.iterator.as_ref().is_some_and(|iterator| {
+                    iterator
+                        .name
+                        .clone()
+                        .unwrap_or_else(|| format!("{short_name}Iterator"))
+                        == name
+                })
+        })
+        .map(|obj| obj.name.as_str())
+}
+
+/// Whether the callback type `tid` gets a shared trait and trampoline.
+///
+/// They are generated with `options.shared_callbacks`, for the callback types
+/// that aren't configured as `manual` or `ignore` and whose name isn't already
+/// used by a configured object.
+fn is_shared(env: &Env, tid: TypeId) -> bool {
+    env.config.shared_callbacks
+        && tid.ns_id == namespaces::MAIN
+        && env.type_(tid).is_function()
+        && tid.full_name(&env.library) != "Gio.AsyncReadyCallback"
+        && env
+            .config
+            .objects
+            .get(&tid.full_name(&env.library))
+            .is_none_or(|obj| obj.status.need_generate())
+        && name_clash(env, tid).is_none()
+}
+
+/// Warns about the callback types that can't get a shared trait because their
+/// name is already used.
+pub fn check_names(env: &Env) {
+    if !env.config.shared_callbacks {
+        return;
+    }
+    let ns = env.library.namespace(namespaces::MAIN);
+    for (id, typ) in ns.types.iter().enumerate() {
+        let Some(library::Type::Function(_)) = typ else {
+            continue;
+        };
+        let tid = TypeId {
+            ns_id: namespaces::MAIN,
+            id: id as u32,
+        };
+        if let Some(obj) = name_clash(env, tid) {
+            warn_main!(
+                tid,
+                "Not sharing callback {}, its name is already used by {}",
+                tid.full_name(&env.library),
+                obj
+            );
+        }
+    }
+}
+
+/// Returns the callback type of a callback parameter if it can use the shared
+/// trait and trampoline of its type.
+pub fn shared_callback_candidate(
+    env: &Env,
+    tid: TypeId,
+    scope: Option<gir_parser::FunctionScope>,
+    nullable: bool,
+    has_parameters_config: bool,
+) -> Option<TypeId> {
+    // `call` closures are `FnMut` and `async` ones `FnOnce`, only `Fn` callbacks are shared
+    if nullable || has_parameters_config || scope.is_some_and(|s| s.is_call() || s.is_async()) {
+        return None;
+    }
+    is_shared(env, tid).then_some(tid)
+}
+
+/// Switches the function's callbacks that can use the shared trait and
+/// trampoline of their type over to them.
+pub fn share(
+    env: &Env,
+    callbacks: &mut [Trampoline],
+    destroys: &mut [Trampoline],
+    bounds: &mut Bounds,
+    imports: &mut Imports,
+) {
+    for i in 0..callbacks.len() {
+        let Some(tid) = callbacks[i].shared_callback else {
+            continue;
+        };
+        let user_data_index = callbacks[i].user_data_index;
+        // Closures sharing their user data with other closures are boxed together
+        let shares_user_data = callbacks
+            .iter()
+            .filter(|c| c.user_data_index == user_data_index)
+            .count()
+            > 1;
+        let bound = signature(env, tid).and_then(|signature| {
+            let type_str = &bounds.get_parameter_bound(&callbacks[i].name)?.type_str;
+            let extra_bounds = type_str.strip_prefix(signature.as_str())?;
+            Some(format!("{}{extra_bounds}", env.type_(tid).get_name()))
+        });
+        let Some(bound) = bound.filter(|_| !shares_user_data) else {
+            callbacks[i].shared_callback = None;
+            continue;
+        };
+
+        bounds.set_parameter_type_str(&callbacks[i].name, bound);
+        for destroy in destroys
+            .iter_mut()
+            .filter(|d| d.user_data_index == user_data_index)
+        {
+            destroy.shared_callback = Some(tid);
+        }
+        imports.add(&format!("crate::{}", env.type_(tid).get_name()));
+    }
+}
+
+/// Trampoline calling a closure of type `P` implementing the shared trait of
+/// the callback type `tid`.
+///
+/// It only depends on the callback type, not on the functions using it.
+fn shared_trampoline(env: &Env, tid: TypeId, func: &library::Function) -> Trampoline {
+    Trampoline {
+        name: trampoline_name(env, tid),
+        parameters: trampoline_parameters::analyze(env, &func.parameters, tid, &[], None),
+        ret: func.ret.clone(),
+        bound_name: "P".to_owned(),
+        bounds: Bounds::default(),
+        version: None,
+        inhibit: false,
+        concurrency: library::Concurrency::None,
+        is_notify: false,
+        scope: Some(gir_parser::FunctionScope::Notified),
+        user_data_index: 0,
+        destroy_index: 0,
+        nullable: false,
+        type_name: env.type_(tid).get_name(),
+        shared_callback: Some(tid),
+    }
+}
+
+/// Collects the callback types used through their shared trait by the
+/// generated functions.
+pub fn analyze(env: &Env, imports: &mut Imports) -> Vec<Info> {
+    let analysis = &env.analysis;
+    let functions = analysis
+        .objects
+        .values()
+        .flat_map(|o| o.functions.iter())
+        .chain(analysis.records.values().flat_map(|r| r.functions.iter()))
+        .chain(
+            analysis
+                .enumerations
+                .iter()
+                .flat_map(|e| e.functions.iter()),
+        )
+        .chain(analysis.flags.iter().flat_map(|f| f.functions.iter()))
+        .chain(
+            analysis
+                .global_functions
+                .iter()
+                .flat_map(|g| g.functions.iter()),
+        )
+        .filter(|f| f.status.need_generate() && !f.commented);
+
+    let mut callbacks = BTreeMap::new();
+    for trampoline in functions.flat_map(|f| f.callbacks.iter()) {
+        let Some(tid) = trampoline.shared_callback else {
+            continue;
+        };
+        let name = env.type_(tid).get_name();
+        if callbacks.contains_key(&name) {
+            continue;
+        }
+        let Some(signature) = signature(env, tid) else {
+            continue;
+        };
+        let func: &library::Function = env.type_(tid).to_ref_as();
+        for typ in func
+            .parameters
+            .iter()
+            .map(library::Parameter::typ)
+            .chain(std::iter::once(func.ret.typ()))
+        {
+            if let Ok(rust_type) = RustType::try_new(env, typ) {
+                imports.add_used_types(rust_type.used_types());
+            }
+        }
+
+        let trampoline = shared_trampoline(env, tid, func);
+        callbacks.insert(
+            name.clone(),
+            Info {
+                type_id: tid,
+                name,
+                signature,
+                trampoline,
+            },
+        );
+    }
+
+    if !callbacks.is_empty() {
+        imports.add(&format!("crate::{}", env.main_sys_crate_name()));
+        imports.add("glib::translate::*");
+        imports.add("std::boxed::Box as Box_");
+    }
+
+    callbacks.into_values().collect()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::analysis::test_env;
+
+    pub const GIR: &str = r#"
+    <callback name="TickCallback" c:type="TestTickCallback">
+      <return-value transfer-ownership="none"><type name="gboolean" c:type="gboolean"/></return-value>
+      <parameters>
+        <parameter name="time" transfer-ownership="none"><type name="gint64" c:type="gint64"/></parameter>
+        <parameter name="user_data" transfer-ownership="none" nullable="1" allow-none="1" closure="1"><type name="gpointer" c:type="gpointer"/></parameter>
+      </parameters>
+    </callback>
+    <function name="add_tick" c:identifier="test_add_tick">
+      <return-value transfer-ownership="none"><type name="none" c:type="void"/></return-value>
+      <parameters>
+        <parameter name="callback" transfer-ownership="none" scope="forever" closure="1"><type name="TickCallback" c:type="TestTickCallback"/></parameter>
+        <parameter name="user_data" transfer-ownership="none" nullable="1" allow-none="1"><type name="gpointer" c:type="gpointer"/></parameter>
+      </parameters>
+    </function>
+    <function name="add_late_tick" c:identifier="test_add_late_tick">
+      <return-value transfer-ownership="none"><type name="none" c:type="void"/></return-value>
+      <parameters>
+        <parameter name="priority" transfer-ownership="none"><type name="gint" c:type="gint"/></parameter>
+        <parameter name="callback" transfer-ownership="none" scope="forever" closure="2"><type name="TickCallback" c:type="TestTickCallback"/></parameter>
+        <parameter name="user_data" transfer-ownership="none" nullable="1" allow-none="1"><type name="gpointer" c:type="gpointer"/></parameter>
+      </parameters>
+    </function>
+"#;
+
+    /// Analyzes a library using the `TickCallback` callback type from two
+    /// functions, with `config` appended to the options.
+    pub fn analyzed_env(name: &str, config: &str) -> Env {
+        test_env::analyzed_env(&format!("callbacks-{name}"), GIR, config)
+    }
+
+    fn shared_callbacks(env: &Env) -> Vec<Option<String>> {
+        env.analysis
+            .global_functions
+            .as_ref()
+            .unwrap()
+            .functions
+            .iter()
+            .flat_map(|f| &f.callbacks)
+            .map(|c| c.shared_callback.map(|tid| env.type_(tid).get_name()))
+            .collect()
+    }
+
+    #[test]
+    fn disabled_by_default() {
+        let env = analyzed_env("disabled", "");
+        assert!(env.analysis.callbacks.is_empty());
+        assert_eq!(shared_callbacks(&env), vec![None, None]);
+    }
+
+    #[test]
+    fn shared_trampoline() {
+        let env = analyzed_env("shared", "shared_callbacks = true\n");
+        let tick = Some("TickCallback".to_owned());
+        assert_eq!(shared_callbacks(&env), vec![tick.clone(), tick]);
+
+        let [info] = env.analysis.callbacks.as_slice() else {
+            panic!("expected a single shared callback");
+        };
+        assert_eq!(info.name, "TickCallback");
+        assert_eq!(info.signature, "Fn(i64) -> bool");
+        // Built from the callback type, not from either call site
+        let trampoline = &info.trampoline;
+        assert_eq!(trampoline.name, "tick_callback_trampoline");
+        assert_eq!(trampoline.bound_name, "P");
+        assert_eq!(trampoline.type_name, "TickCallback");
+        assert_eq!(trampoline.user_data_index, 0);
+        assert!(!trampoline.nullable);
+        let names = trampoline
+            .parameters
+            .c_parameters
+            .iter()
+            .map(|p| p.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["this", "time", "user_data"]);
+    }
+
+    #[test]
+    fn manual_callback_type() {
+        let env = analyzed_env(
+            "manual",
+            "shared_callbacks = true\n\n[[object]]\nname = \"Test.TickCallback\"\n\
+             status = \"manual\"\n",
+        );
+        assert!(env.analysis.callbacks.is_empty());
+        assert_eq!(shared_callbacks(&env), vec![None, None]);
+    }
+
+    #[test]
+    fn name_clashes() {
+        for object in [
+            "name = \"Test.Tick\"\nstatus = \"manual\"\ntrait_name = \"TickCallback\"\n",
+            "name = \"Test.Tick\"\nstatus = \"manual\"\nmanual_traits = [\"TickCallback\"]\n",
+            "name = \"Test.Tick\"\nstatus = \"manual\"\n[object.iterator]\n\
+             init = \"test_tick_iter_init\"\nnext = \"test_tick_iter_next\"\n\
+             name = \"TickCallback\"\n",
+            "name = \"GLib.TickCallback\"\nstatus = \"manual\"\n",
+        ] {
+            let env = analyzed_env(
+                "clash",
+                &format!("shared_callbacks = true\n\n[[object]]\n{object}"),
+            );
+            assert!(env.analysis.callbacks.is_empty(), "{object}");
+            assert_eq!(shared_callbacks(&env), vec![None, None], "{object}");
+        }
+    }
+
+    #[test]
+    fn configured_callback_type() {
+        let env = analyzed_env(
+            "configured",
+            "shared_callbacks = true\n\n[[object]]\nname = \"Test.TickCallback\"\n\
+             status = \"generate\"\n",
+        );
+        assert_eq!(env.analysis.callbacks.len(), 1);
+    }
+}
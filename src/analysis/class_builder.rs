@@ -4,7 +4,7 @@ use crate::{
     analysis::{
         bounds::Bounds,
         imports::Imports,
-        properties::{Property, get_property_ref_modes},
+        properties::{Property, get_property_ref_modes, value_doc},
         rust_type::RustType,
     },
     config::{self, GObject},
@@ -148,5 +148,6 @@ fn analyze_property(
         version: prop_version,
         deprecated_version: prop.deprecated_version,
         cfg_condition,
+        value_doc: value_doc(env, prop),
     })
 }
@@ -1,5 +1,7 @@
 use std::borrow::Borrow;
 
+use log::warn;
+
 use crate::{config, env::Env, library, nameutil, traits::*, version::Version};
 
 #[derive(Debug)]
@@ -7,6 +9,8 @@ pub struct Info {
     pub name: String,
     pub glib_name: String,
     pub typ: library::TypeId,
+    /// Value as written in the GIR
+    pub value: String,
     pub version: Option<Version>,
     pub deprecated_version: Option<Version>,
     pub cfg_condition: Option<String>,
@@ -34,9 +38,13 @@ pub fn analyze<F: Borrow<library::Constant>>(
             continue;
         }
 
-        match env.type_(constant.typ) {
-            library::Type::Basic(library::Basic::Utf8) => (),
-            _ => continue,
+        if !is_supported_type(env, constant.typ) {
+            warn!(
+                "Can't generate constant {} of unsupported type {}",
+                constant.c_identifier,
+                constant.typ.full_name(&env.library)
+            );
+            continue;
         }
 
         let version = configured_constants
@@ -56,6 +64,7 @@ pub fn analyze<F: Borrow<library::Constant>>(
             name,
             glib_name: constant.c_identifier.clone(),
             typ: constant.typ,
+            value: constant.value.clone(),
             version,
             deprecated_version,
             cfg_condition,
@@ -64,3 +73,19 @@ pub fn analyze<F: Borrow<library::Constant>>(
 
     consts
 }
+
+fn is_supported_type(env: &Env, typ: library::TypeId) -> bool {
+    use crate::library::{Basic, Type};
+
+    match env.type_(typ) {
+        Type::Basic(Basic::Utf8 | Basic::Boolean | Basic::UniChar) => true,
+        Type::Basic(basic) => basic.is_numeric(),
+        Type::Enumeration(_) | Type::Bitfield(_) => true,
+        Type::Alias(alias) => matches!(
+            env.type_(alias.typ),
+            Type::Basic(basic)
+                if basic.is_numeric() || matches!(basic, Basic::Boolean | Basic::UniChar)
+        ),
+        _ => false,
+    }
+}
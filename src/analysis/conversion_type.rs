@@ -92,17 +92,19 @@ impl ConversionType {
             Enumeration(_) => Self::Scalar,
             Interface(_) => Self::Pointer,
             Class(_) => Self::Pointer,
+            Array(_) => Self::Pointer,
+            ByteArray => Self::Pointer,
             CArray(_) => Self::Pointer,
             FixedArray(..) => Self::Pointer,
             List(_) => Self::Pointer,
             SList(_) => Self::Pointer,
             PtrArray(_) => Self::Pointer,
+            HashTable(..) => Self::Pointer,
             Function(f) if f.name == "AsyncReadyCallback" => Self::Direct,
             Function(_) => Self::Direct,
             Custom(super::library::Custom {
                 conversion_type, ..
             }) => conversion_type.clone(),
-            _ => Self::Unknown,
         }
     }
 
@@ -110,3 +112,21 @@ impl ConversionType {
         matches!(self, Self::Option | Self::Result { .. })
     }
 }
+
+/// Whether `type_id` is a fixed-size C array of numbers, which has the layout
+/// of the corresponding Rust array.
+pub fn is_direct_fixed_array(env: &env::Env, type_id: TypeId) -> bool {
+    matches!(
+        env.library.type_(type_id),
+        Type::FixedArray(inner_tid, ..)
+            if matches!(env.library.type_(*inner_tid), Type::Basic(..))
+                && ConversionType::of(env, *inner_tid) == ConversionType::Direct
+    )
+}
+
+/// Whether the elements of a C array of `type_id` have to be converted one by
+/// one with `into_glib`/`from_glib`, e.g. enums, flags and `gboolean`s.
+pub fn is_scalar_array_element(env: &env::Env, type_id: TypeId) -> bool {
+    ConversionType::of(env, type_id) == ConversionType::Scalar
+        && !matches!(env.library.type_(type_id), Type::Basic(Basic::UniChar))
+}
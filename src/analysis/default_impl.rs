@@ -0,0 +1,129 @@
+use log::warn;
+
+use super::{
+    functions,
+    out_parameters::{Mode, ThrowFunctionReturnStrategy},
+};
+use crate::{
+    config::{DefaultConstructor, gobjects::GObject},
+    env::Env,
+    library::{self, Type},
+    nameutil::use_glib_type,
+    version::Version,
+};
+
+/// `Default` implementation configured with `default_constructor`
+#[derive(Debug)]
+pub struct Info {
+    /// Expression creating the default value
+    pub body: String,
+    pub version: Option<Version>,
+    pub cfg_condition: Option<String>,
+}
+
+pub fn analyze(
+    env: &Env,
+    obj: &GObject,
+    type_id: library::TypeId,
+    functions: &[functions::Info],
+) -> Option<Info> {
+    match obj.default_constructor.as_ref()? {
+        DefaultConstructor::Function(name) => from_function(obj, type_id, name, functions),
+        DefaultConstructor::ObjectNew => from_object_new(env, obj, type_id),
+    }
+}
+
+fn from_function(
+    obj: &GObject,
+    type_id: library::TypeId,
+    name: &str,
+    functions: &[functions::Info],
+) -> Option<Info> {
+    let Some(func) = functions.iter().find(|f| {
+        f.name == name
+            && f.kind != library::FunctionKind::Method
+            && !f.hidden
+            && f.status.need_generate()
+            && !f.commented
+    }) else {
+        warn!(
+            "Default constructor {name} of {} isn't a generated constructor",
+            obj.name
+        );
+        return None;
+    };
+    if !func.parameters.rust_parameters.is_empty() {
+        warn!("Default constructor {name} of {} takes arguments", obj.name);
+        return None;
+    }
+    let Some(ret) = func
+        .ret
+        .parameter
+        .as_ref()
+        .filter(|ret| ret.lib_par.typ() == type_id)
+    else {
+        warn!(
+            "Default constructor {name} of {} doesn't return the type itself",
+            obj.name
+        );
+        return None;
+    };
+    if ret.lib_par.is_nullable() {
+        warn!("Default constructor {name} of {} can return NULL", obj.name);
+        return None;
+    }
+    if func.outs.mode == Mode::Throws(ThrowFunctionReturnStrategy::ReturnResult) {
+        warn!("Default constructor {name} of {} can fail", obj.name);
+        return None;
+    }
+
+    Some(Info {
+        body: format!("Self::{}()", func.codegen_name()),
+        version: func.version,
+        cfg_condition: func.cfg_condition.clone(),
+    })
+}
+
+fn from_object_new(env: &Env, obj: &GObject, type_id: library::TypeId) -> Option<Info> {
+    let Type::Class(klass) = env.library.type_(type_id) else {
+        warn!(
+            "Default constructor Object::new of {} is only supported for classes",
+            obj.name
+        );
+        return None;
+    };
+    if klass.is_abstract || klass.is_fundamental {
+        warn!(
+            "Default constructor Object::new of {} can't create an abstract or fundamental type",
+            obj.name
+        );
+        return None;
+    }
+
+    // Construct-only properties without a default value have to be set at
+    // construction, including the ones of the parent classes
+    let mut class = Some(klass);
+    while let Some(klass) = class {
+        if let Some(prop) = klass
+            .properties
+            .iter()
+            .find(|prop| prop.construct_only && prop.default_value.is_none())
+        {
+            warn!(
+                "Default constructor Object::new of {} doesn't set the construct-only property {}.{}",
+                obj.name, klass.name, prop.name
+            );
+            return None;
+        }
+        class = klass.parent.and_then(|tid| match env.library.type_(tid) {
+            Type::Class(parent) => Some(parent),
+            _ => None,
+        });
+    }
+
+    Some(Info {
+        body: format!("{}::<Self>()", use_glib_type(env, "object::Object::new")),
+        version: None,
+        cfg_condition: None,
+    })
+}
@@ -133,6 +133,7 @@ fn ffi_inner(env: &Env, tid: TypeId, inner: &str) -> Result {
         Type::FixedArray(inner_tid, size, _) => ffi_inner(env, inner_tid, inner)
             .map_any(|rust_type| rust_type.alter_type(|typ_| format!("[{typ_}; {size}]"))),
         Type::Array(..)
+        | Type::ByteArray
         | Type::PtrArray(..)
         | Type::List(..)
         | Type::SList(..)
@@ -178,6 +179,7 @@ fn fix_name(env: &Env, type_id: TypeId, name: &str) -> Result {
     if type_id.ns_id == INTERNAL_NAMESPACE {
         match env.library.type_(type_id) {
             Type::Array(..)
+            | Type::ByteArray
             | Type::PtrArray(..)
             | Type::List(..)
             | Type::SList(..)
@@ -1,8 +1,12 @@
 use std::collections::HashMap;
 
 use super::{
-    conversion_type::ConversionType, out_parameters::can_as_return,
-    override_string_type::override_string_type_parameter, ref_mode::RefMode, rust_type::RustType,
+    c_type::is_mut_ptr,
+    conversion_type::{ConversionType, is_direct_fixed_array, is_scalar_array_element},
+    out_parameters::can_as_return,
+    override_string_type::override_string_type_parameter,
+    ref_mode::RefMode,
+    rust_type::RustType,
     try_from_glib::TryFromGlib,
 };
 use crate::{
@@ -101,6 +105,21 @@ pub enum TransformationType {
         nullable: bool,
         move_: bool,
     },
+    /// Converts the elements of a slice of enums, flags or booleans into a
+    /// temporary C array before the call.
+    ToGlibScalarArray {
+        name: String,
+        mut_ptr: bool,
+        /// The array is filled by the function and copied back into the slice.
+        caller_allocates: bool,
+    },
+    /// Builds a `GHashTable` or `GArray` from a `HashMap` or slice before the
+    /// call.
+    ToGlibContainer {
+        name: String,
+        typ: TypeId,
+        transfer: gir_parser::TransferOwnership,
+    },
     ToGlibBorrow,
     ToGlibUnknown {
         name: String,
@@ -121,6 +140,8 @@ impl TransformationType {
             Self::ToGlibDirect { .. }
                 | Self::ToGlibScalar { .. }
                 | Self::ToGlibPointer { .. }
+                | Self::ToGlibContainer { .. }
+                | Self::ToGlibScalarArray { .. }
                 | Self::ToGlibBorrow
                 | Self::ToGlibUnknown { .. }
                 | Self::ToSome(_)
@@ -237,7 +258,11 @@ pub fn analyze(
         let mut add_rust_parameter = match par.direction() {
             library::ParameterDirection::In | library::ParameterDirection::InOut => true,
             library::ParameterDirection::Return => false,
-            library::ParameterDirection::Out => !can_as_return(env, par) && !async_func,
+            library::ParameterDirection::Out => {
+                (is_caller_allocated_array(env, par, function_parameters)
+                    || !can_as_return(env, par))
+                    && !async_func
+            }
             library::ParameterDirection::None => {
                 panic!("undefined direction for parameter {par:?}")
             }
@@ -253,10 +278,13 @@ pub fn analyze(
 
         let mut caller_allocates = par.is_caller_allocates();
         let conversion = ConversionType::of(env, typ);
-        if let ConversionType::Direct
-        | ConversionType::Scalar
-        | ConversionType::Option
-        | ConversionType::Result { .. } = conversion
+        if matches!(
+            conversion,
+            ConversionType::Direct
+                | ConversionType::Scalar
+                | ConversionType::Option
+                | ConversionType::Result { .. }
+        ) || is_direct_fixed_array(env, typ)
         {
             // For simple types no reason to have these flags
             caller_allocates = false;
@@ -310,8 +338,12 @@ pub fn analyze(
         }
 
         let immutable = configured_parameters.iter().any(|p| p.constant);
-        let ref_mode =
-            RefMode::without_unneeded_mut(env, par, immutable, in_trait && is_instance_parameter);
+        let caller_allocated_array = is_caller_allocated_array(env, par, function_parameters);
+        let ref_mode = if caller_allocated_array {
+            RefMode::ByRefMut
+        } else {
+            RefMode::without_unneeded_mut(env, par, immutable, in_trait && is_instance_parameter)
+        };
 
         let nullable_override = configured_parameters.iter().find_map(|p| p.nullable);
         let nullable = nullable_override.unwrap_or(par.is_nullable());
@@ -392,6 +424,52 @@ pub fn analyze(
                     needs_into,
                 }
             }
+            ConversionType::Pointer
+                if matches!(
+                    env.library.type_(typ),
+                    library::Type::CArray(inner_tid) if is_scalar_array_element(env, *inner_tid)
+                ) =>
+            {
+                TransformationType::ToGlibScalarArray {
+                    name,
+                    mut_ptr: caller_allocated_array || is_mut_ptr(c_type),
+                    caller_allocates: caller_allocated_array,
+                }
+            }
+            ConversionType::Pointer if is_direct_fixed_array(env, typ) => {
+                TransformationType::ToGlibDirect {
+                    name: format!(
+                        "{name}.as_ptr(){}",
+                        if is_mut_ptr(c_type) { " as *mut _" } else { "" }
+                    ),
+                }
+            }
+            ConversionType::Pointer if caller_allocated_array => {
+                // Slices of numbers and inline boxed records share the C layout
+                let pointer_cast = if matches!(
+                    env.library.type_(typ),
+                    library::Type::CArray(inner_tid) if matches!(env.library.type_(*inner_tid), library::Type::Record(..))
+                ) {
+                    " as *mut _"
+                } else {
+                    ""
+                };
+                TransformationType::ToGlibDirect {
+                    name: format!("{name}.as_mut_ptr(){pointer_cast}"),
+                }
+            }
+            ConversionType::Pointer
+                if matches!(
+                    env.library.type_(typ),
+                    library::Type::HashTable(..) | library::Type::Array(..)
+                ) =>
+            {
+                TransformationType::ToGlibContainer {
+                    name,
+                    typ,
+                    transfer,
+                }
+            }
             ConversionType::Pointer => TransformationType::ToGlibPointer {
                 name,
                 is_instance_parameter,
@@ -448,6 +526,38 @@ pub fn analyze(
     parameters
 }
 
+/// Whether `par` is an array allocated by the caller and filled by the
+/// function, whose size is given by an input length parameter. Such arrays
+/// are passed as `&mut [T]`.
+pub fn is_caller_allocated_array(
+    env: &Env,
+    par: &library::Parameter,
+    parameters: &[library::Parameter],
+) -> bool {
+    if par.direction() != library::ParameterDirection::Out || !par.is_caller_allocates() {
+        return false;
+    }
+    let library::Type::CArray(inner_tid) = env.library.type_(par.typ()) else {
+        return false;
+    };
+    let has_in_length = par
+        .array_length()
+        .and_then(|pos| parameters.get(pos as usize))
+        .is_some_and(|length| length.direction().is_in());
+    let shares_layout = match env.library.type_(*inner_tid) {
+        library::Type::Record(..) => env
+            .config
+            .objects
+            .get(&inner_tid.full_name(&env.library))
+            .is_some_and(|obj| obj.boxed_inline),
+        _ => {
+            ConversionType::of(env, *inner_tid) == ConversionType::Direct
+                || is_scalar_array_element(env, *inner_tid)
+        }
+    };
+    has_in_length && shares_layout
+}
+
 fn get_length_type(
     env: &Env,
     array_name: &str,
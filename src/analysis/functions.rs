@@ -14,7 +14,8 @@ use super::{namespaces::NsId, special_functions};
 use crate::{
     analysis::{
         self,
-        bounds::{Bounds, CallbackInfo},
+        bounds::{self, Bounds, CallbackInfo},
+        callbacks,
         function_parameters::{self, CParameter, Parameters, Transformation, TransformationType},
         imports::Imports,
         is_gpointer,
@@ -27,7 +28,7 @@ use crate::{
         trampolines::Trampoline,
     },
     codegen::Visibility,
-    config::{self, gobjects::GStatus},
+    config::{self, FutureStyle, gobjects::GStatus},
     env::Env,
     library::{self, Function, FunctionKind, MAIN_NAMESPACE, ParameterDirection, Type},
     nameutil,
@@ -54,6 +55,9 @@ pub struct AsyncFuture {
     pub success_parameters: String,
     pub error_parameters: Option<String>,
     pub assertion: SafetyAssertionMode,
+    pub style: FutureStyle,
+    /// Whether the future can be sent to other threads
+    pub is_send: bool,
 }
 
 #[derive(Debug)]
@@ -97,6 +101,10 @@ pub struct Info {
     pub generate_doc: bool,
     pub get_property: Option<String>,
     pub set_property: Option<String>,
+    /// C identifier of the synchronous variant of this async function
+    pub sync_func: Option<String>,
+    /// C identifier of the async variant of this synchronous function
+    pub async_func: Option<String>,
 }
 
 impl Info {
@@ -226,9 +234,130 @@ pub fn analyze<F: Borrow<library::Function>>(
         funcs.push(info);
     }
 
+    for warning in link_sync_and_async(env, functions, &mut funcs, obj) {
+        warn!("{warning}");
+    }
+
     funcs
 }
 
+/// Links the synchronous and async variants of functions given by the
+/// `glib:sync-func` and `glib:async-func` GIR attributes.
+///
+/// Returns the warnings about inconsistent pairs.
+fn link_sync_and_async<F: Borrow<library::Function>>(
+    env: &Env,
+    functions: &[F],
+    funcs: &mut [Info],
+    obj: &config::gobjects::GObject,
+) -> Vec<String> {
+    let find = |name: &str| {
+        functions
+            .iter()
+            .map(Borrow::borrow)
+            .find(|func: &&library::Function| func.name == name)
+    };
+    let is_generated = |funcs: &[Info], c_identifier: &str| {
+        funcs.iter().any(|info| {
+            info.glib_name == c_identifier && info.status.need_generate() && !info.commented
+        })
+    };
+    let mut warnings = Vec::new();
+
+    for i in 0..funcs.len() {
+        let Some(func) = functions
+            .iter()
+            .map(Borrow::borrow)
+            .find(|func| func.c_identifier == funcs[i].glib_name)
+        else {
+            continue;
+        };
+
+        if let Some(ref sync_name) = func.sync_func {
+            let Some(sync_func) = find(sync_name) else {
+                warnings.push(format!(
+                    "Synchronous variant `{sync_name}` of {} not found",
+                    func.c_identifier
+                ));
+                continue;
+            };
+            let guessed_names = sync_function_names(&func.name);
+            if !guessed_names.contains(sync_name)
+                && let Some(guessed) = guessed_names.iter().find(|name| find(name).is_some())
+            {
+                warnings.push(format!(
+                    "{}: GIR synchronous variant {sync_name} differs from {guessed} guessed from its name",
+                    func.c_identifier
+                ));
+            }
+            if let Some(ref async_future) = funcs[i].async_future {
+                let configured_functions = obj.functions.matched(&sync_func.name);
+                let (success, error) =
+                    bounds::result_types(env, sync_func, &sync_func.name, &configured_functions);
+                if success != async_future.success_parameters
+                    || error != async_future.error_parameters
+                {
+                    warnings.push(format!(
+                        "{} returns {} but the future of its async variant {} returns {}",
+                        sync_func.c_identifier,
+                        result_type(&success, error.as_deref()),
+                        func.c_identifier,
+                        result_type(
+                            &async_future.success_parameters,
+                            async_future.error_parameters.as_deref()
+                        ),
+                    ));
+                }
+            }
+            if is_generated(funcs, &sync_func.c_identifier) {
+                funcs[i].sync_func = Some(sync_func.c_identifier.clone());
+            }
+        }
+
+        if let Some(ref async_name) = func.async_func {
+            let Some(async_func) = find(async_name) else {
+                warnings.push(format!(
+                    "Async variant `{async_name}` of {} not found",
+                    func.c_identifier
+                ));
+                continue;
+            };
+            let guessed = async_function_name(&func.name);
+            if guessed != *async_name && find(&guessed).is_some() {
+                warnings.push(format!(
+                    "{}: GIR async variant {async_name} differs from {guessed} guessed from its name",
+                    func.c_identifier
+                ));
+            }
+            if is_generated(funcs, &async_func.c_identifier) {
+                funcs[i].async_func = Some(async_func.c_identifier.clone());
+            }
+        }
+    }
+    warnings
+}
+
+/// Names the synchronous variant of the async function `name` usually has,
+/// e.g. `load` and `load_sync` for `load_async`.
+fn sync_function_names(name: &str) -> Vec<String> {
+    let base = name.strip_suffix("_async").unwrap_or(name);
+    vec![base.to_owned(), format!("{base}_sync")]
+}
+
+/// Name the async variant of the synchronous function `name` usually has,
+/// e.g. `load_async` for `load` or `load_sync`.
+fn async_function_name(name: &str) -> String {
+    let base = name.strip_suffix("_sync").unwrap_or(name);
+    format!("{base}_async")
+}
+
+fn result_type(success: &str, error: Option<&str>) -> String {
+    match error {
+        Some(error) => format!("Result<{success}, {error}>"),
+        None => success.to_owned(),
+    }
+}
+
 fn fixup_gpointer_parameter(
     env: &Env,
     type_tid: library::TypeId,
@@ -297,6 +426,64 @@ fn fixup_special_functions(
     }
 }
 
+/// Class methods take the class struct as instance parameter, which is
+/// passed from the `glib::Class<T>` they are generated on.
+///
+/// Only methods taking a `const` class struct, or whose instance parameter
+/// is configured as `const`, take `&self`.
+fn fixup_class_method_instance(
+    env: &Env,
+    type_tid: library::TypeId,
+    configured_functions: &[&config::functions::Function],
+    parameters: &mut Parameters,
+) {
+    use crate::analysis::ffi_type;
+
+    let Some(par) = parameters
+        .c_parameters
+        .first()
+        .filter(|par| par.is_instance_parameter)
+    else {
+        return;
+    };
+    let Ok(ffi_name) = ffi_type::ffi_type(env, par.typ, &par.c_type) else {
+        return;
+    };
+    let name = par.name.clone();
+    let is_const = par.c_type.starts_with("const ")
+        || configured_functions
+            .matched_parameters(&name)
+            .iter()
+            .any(|p| p.constant);
+    let (ref_mode, self_ptr) = if is_const {
+        (RefMode::ByRef, "*const Self")
+    } else {
+        (RefMode::ByRefMut, "*mut Self")
+    };
+    if let Some(rust_par) = parameters.rust_parameters.first_mut() {
+        rust_par.typ = type_tid;
+    }
+    parameters.c_parameters[0].typ = type_tid;
+    parameters.c_parameters[0].ref_mode = ref_mode;
+    parameters.c_parameters[0].transfer = gir_parser::TransferOwnership::None;
+    if let Some(transformation) = parameters.transformations.iter_mut().find(|t| t.ind_c == 0) {
+        transformation.transformation_type = TransformationType::ToGlibPointer {
+            name,
+            is_instance_parameter: true,
+            transfer: gir_parser::TransferOwnership::None,
+            ref_mode: RefMode::ByRefFake,
+            to_glib_extra: Default::default(),
+            explicit_target_type: Default::default(),
+            pointer_cast: format!(" as {self_ptr} as {}", ffi_name.as_str()),
+            // The instance is passed as is, without the `as_ref()` of the
+            // object traits
+            in_trait: false,
+            nullable: false,
+            move_: false,
+        };
+    }
+}
+
 fn find_callback_bound_to_destructor(
     callbacks: &[Trampoline],
     destroy: &mut Trampoline,
@@ -540,7 +727,7 @@ fn analyze_callbacks(
     }
 }
 
-fn analyze_function(
+pub(super) fn analyze_function(
     env: &Env,
     obj: &config::gobjects::GObject,
     func_name: &str,
@@ -667,6 +854,10 @@ fn analyze_function(
         .unwrap_or_default();
     let disable_length_detect = configured_functions.iter().any(|f| f.disable_length_detect);
     let no_future = configured_functions.iter().any(|f| f.no_future);
+    let future_style = configured_functions
+        .iter()
+        .find_map(|f| f.future_style)
+        .unwrap_or(env.config.future_style);
     let unsafe_ = configured_functions.iter().any(|f| f.unsafe_);
     let assertion = configured_functions.iter().find_map(|f| f.assertion);
 
@@ -711,6 +902,9 @@ fn analyze_function(
         in_trait,
         &mut parameters,
     );
+    if func.kind == FunctionKind::ClassMethod {
+        fixup_class_method_instance(env, type_tid, configured_functions, &mut parameters);
+    }
 
     // Key: destroy callback index
     // Value: associated user data index
@@ -777,6 +971,7 @@ fn analyze_function(
                     &mut commented,
                     &mut trampoline,
                     no_future,
+                    future_style,
                     &mut async_future,
                     configured_functions,
                     &parameters,
@@ -854,7 +1049,16 @@ fn analyze_function(
 
     if r#async && status.need_generate() && !commented {
         imports.add("std::boxed::Box as Box_");
-        imports.add("std::pin::Pin");
+        if let Some(ref mut async_future) = async_future {
+            if async_future.style == FutureStyle::Boxed {
+                imports.add("std::pin::Pin");
+            } else {
+                // The future holds the object, which is `Self` in traits
+                async_future.is_send = concurrency != library::Concurrency::None
+                    && !(async_future.is_method && in_trait)
+                    && is_send_future(env, &parameters, &bounds, trampoline.as_ref());
+            }
+        }
 
         if let Some(ref trampoline) = trampoline {
             for out in &trampoline.output_params {
@@ -884,6 +1088,16 @@ fn analyze_function(
             imports.add("std::boxed::Box as Box_");
         }
 
+        if parameters
+            .c_parameters
+            .iter()
+            .map(|par| par.typ)
+            .chain(std::iter::once(func.ret.typ()))
+            .any(|typ| matches!(env.type_(typ), Type::HashTable(..)))
+        {
+            imports.add("std::collections::HashMap");
+        }
+
         for transformation in &mut parameters.transformations {
             if let Some(to_glib_extra) = to_glib_extras.get(&transformation.ind_c) {
                 transformation
@@ -904,10 +1118,16 @@ fn analyze_function(
         {
             imports.add("glib::translate::*");
         }
+        callbacks::share(env, &mut callbacks, &mut destroys, &mut bounds, imports);
         bounds.update_imports(imports);
     }
 
-    let is_method = func.kind == library::FunctionKind::Method;
+    // The class struct of class methods can only exist once the type system
+    // is initialized
+    let is_method = matches!(
+        func.kind,
+        library::FunctionKind::Method | library::FunctionKind::ClassMethod
+    );
     let assertion =
         assertion.unwrap_or_else(|| SafetyAssertionMode::of(env, is_method, &parameters));
 
@@ -948,6 +1168,8 @@ fn analyze_function(
         generate_doc,
         get_property: func.get_property.clone(),
         set_property: func.set_property.clone(),
+        sync_func: None,
+        async_func: None,
     }
 }
 
@@ -961,6 +1183,54 @@ pub fn is_carray_with_direct_elements(env: &Env, typ: library::TypeId) -> bool {
     }
 }
 
+/// Whether everything captured and returned by the future of an async
+/// function is known to be `Send`.
+fn is_send_future(
+    env: &Env,
+    parameters: &Parameters,
+    bounds: &Bounds,
+    trampoline: Option<&AsyncTrampoline>,
+) -> bool {
+    let Some(trampoline) = trampoline else {
+        return false;
+    };
+    let captured = parameters.rust_parameters.iter().all(|par| {
+        let c_par = &parameters.c_parameters[par.ind_c];
+        c_par.is_instance_parameter
+            || par.name == "cancellable"
+            || par.name == "callback"
+            || (bounds.get_parameter_bound(&c_par.name).is_none() && is_send_type(env, par.typ))
+    });
+    let returned = trampoline
+        .output_params
+        .iter()
+        .filter(|out| out.lib_par.direction().is_out() && !out.lib_par.is_error())
+        .chain(trampoline.ffi_ret.as_ref())
+        .all(|out| is_send_type(env, out.lib_par.typ()));
+    captured && returned
+}
+
+fn is_send_type(env: &Env, type_id: library::TypeId) -> bool {
+    use crate::library::Basic;
+
+    match env.library.type_(type_id) {
+        Type::Basic(Basic::Pointer | Basic::VarArgs | Basic::Unsupported) => false,
+        Type::Basic(_) | Type::Enumeration(_) | Type::Bitfield(_) => true,
+        Type::Alias(alias) => is_send_type(env, alias.typ),
+        Type::Class(_) | Type::Interface(_) | Type::Record(_) | Type::Union(_) => env
+            .config
+            .objects
+            .get(&type_id.full_name(&env.library))
+            .is_some_and(|obj| obj.concurrency != library::Concurrency::None),
+        Type::CArray(inner)
+        | Type::FixedArray(inner, ..)
+        | Type::PtrArray(inner)
+        | Type::List(inner)
+        | Type::SList(inner) => is_send_type(env, *inner),
+        _ => false,
+    }
+}
+
 fn analyze_async(
     env: &Env,
     func: &library::Function,
@@ -970,6 +1240,7 @@ fn analyze_async(
     commented: &mut bool,
     trampoline: &mut Option<AsyncTrampoline>,
     no_future: bool,
+    future_style: FutureStyle,
     async_future: &mut Option<AsyncFuture>,
     configured_functions: &[&config::functions::Function],
     parameters: &function_parameters::Parameters,
@@ -985,6 +1256,16 @@ fn analyze_async(
         *commented |= callback_type.contains("/*");
         let func_name = &func.c_identifier;
         let finish_func_name = if let Some(finish_func_name) = &func.finish_func {
+            let guessed_name = finish_function_name(func_name);
+            if *finish_func_name != guessed_name && find_function(env, &guessed_name).is_some() {
+                warn_main!(
+                    type_tid,
+                    "{}: GIR finish function {} differs from {} guessed from its name",
+                    func_name,
+                    finish_func_name,
+                    guessed_name
+                );
+            }
             finish_func_name.to_string()
         } else {
             finish_function_name(func_name)
@@ -1053,9 +1334,10 @@ fn analyze_async(
         });
 
         if !no_future {
+            let base_name = codegen_name.trim_end_matches("_async");
             *async_future = Some(AsyncFuture {
                 is_method,
-                name: format!("{}_future", codegen_name.trim_end_matches("_async")),
+                name: format!("{base_name}_future"),
                 success_parameters,
                 error_parameters,
                 assertion: match SafetyAssertionMode::of(env, is_method, parameters) {
@@ -1064,6 +1346,9 @@ fn analyze_async(
                     // need to do it twice.
                     _ => SafetyAssertionMode::Skip,
                 },
+                style: future_style,
+                // Known once all parameters are analyzed
+                is_send: false,
             });
         }
         true
@@ -1256,6 +1541,17 @@ fn analyze_callback(
                     destroy_index: 0,
                     nullable: par.nullable,
                     type_name: env.library.type_(type_tid).get_name(),
+                    shared_callback: if par.c_type != "GDestroyNotify" {
+                        callbacks::shared_callback_candidate(
+                            env,
+                            par.typ,
+                            par.scope,
+                            par.nullable,
+                            callback_parameters_config.is_some_and(|c| !c.is_empty()),
+                        )
+                    } else {
+                        None
+                    },
                 },
                 par.destroy_index
                     .map(|destroy_index| c_parameters[destroy_index].1),
@@ -1287,6 +1583,10 @@ pub fn find_function<'a>(env: &'a Env, c_identifier: &str) -> Option<&'a Functio
                 && let Some(f) = find(&interface.functions)
             {
                 return Some(f);
+            } else if let Some(Type::Record(record)) = typ
+                && let Some(f) = find(&record.functions)
+            {
+                return Some(f);
             }
         }
     }
@@ -1314,8 +1614,9 @@ pub fn find_index_to_ignore<'a>(
 }
 
 #[cfg(test)]
-mod tests {
+pub mod tests {
     use super::*;
+    use crate::analysis::test_env;
 
     #[test]
     fn test_finish_function_name() {
@@ -1325,4 +1626,207 @@ mod tests {
         );
         assert_eq!("g_bus_get_finish", &finish_function_name("g_bus_get"));
     }
+
+    const ASYNC_GIR: &str = r#"
+    <record name="Buffer" c:type="TestBuffer" glib:type-name="TestBuffer" glib:get-type="test_buffer_get_type" c:symbol-prefix="buffer">
+    </record>
+    <function name="load_async" c:identifier="test_load_async">
+      <return-value transfer-ownership="none"><type name="none" c:type="void"/></return-value>
+      <parameters>
+        <parameter name="count" transfer-ownership="none"><type name="gint" c:type="gint"/></parameter>
+        <parameter name="cancellable" transfer-ownership="none" nullable="1" allow-none="1"><type name="Gio.Cancellable" c:type="GCancellable*"/></parameter>
+        <parameter name="callback" transfer-ownership="none" nullable="1" allow-none="1" scope="async" closure="3"><type name="Gio.AsyncReadyCallback" c:type="GAsyncReadyCallback"/></parameter>
+        <parameter name="user_data" transfer-ownership="none" nullable="1" allow-none="1"><type name="gpointer" c:type="gpointer"/></parameter>
+      </parameters>
+    </function>
+    <function name="load_finish" c:identifier="test_load_finish" throws="1">
+      <return-value transfer-ownership="full"><type name="utf8" c:type="gchar*"/></return-value>
+      <parameters>
+        <parameter name="result" transfer-ownership="none"><type name="Gio.AsyncResult" c:type="GAsyncResult*"/></parameter>
+      </parameters>
+    </function>
+    <function name="read_async" c:identifier="test_read_async">
+      <return-value transfer-ownership="none"><type name="none" c:type="void"/></return-value>
+      <parameters>
+        <parameter name="buffer" transfer-ownership="none"><type name="Buffer" c:type="TestBuffer*"/></parameter>
+        <parameter name="cancellable" transfer-ownership="none" nullable="1" allow-none="1"><type name="Gio.Cancellable" c:type="GCancellable*"/></parameter>
+        <parameter name="callback" transfer-ownership="none" nullable="1" allow-none="1" scope="async" closure="3"><type name="Gio.AsyncReadyCallback" c:type="GAsyncReadyCallback"/></parameter>
+        <parameter name="user_data" transfer-ownership="none" nullable="1" allow-none="1"><type name="gpointer" c:type="gpointer"/></parameter>
+      </parameters>
+    </function>
+    <function name="read_finish" c:identifier="test_read_finish" throws="1">
+      <return-value transfer-ownership="full"><type name="Buffer" c:type="TestBuffer*"/></return-value>
+      <parameters>
+        <parameter name="result" transfer-ownership="none"><type name="Gio.AsyncResult" c:type="GAsyncResult*"/></parameter>
+      </parameters>
+    </function>
+    <function name="peek_async" c:identifier="test_peek_async">
+      <return-value transfer-ownership="none"><type name="none" c:type="void"/></return-value>
+      <parameters>
+        <parameter name="data" transfer-ownership="none"><type name="gpointer" c:type="gpointer"/></parameter>
+        <parameter name="cancellable" transfer-ownership="none" nullable="1" allow-none="1"><type name="Gio.Cancellable" c:type="GCancellable*"/></parameter>
+        <parameter name="callback" transfer-ownership="none" nullable="1" allow-none="1" scope="async" closure="3"><type name="Gio.AsyncReadyCallback" c:type="GAsyncReadyCallback"/></parameter>
+        <parameter name="user_data" transfer-ownership="none" nullable="1" allow-none="1"><type name="gpointer" c:type="gpointer"/></parameter>
+      </parameters>
+    </function>
+    <function name="peek_finish" c:identifier="test_peek_finish" throws="1">
+      <return-value transfer-ownership="none"><type name="gboolean" c:type="gboolean"/></return-value>
+      <parameters>
+        <parameter name="result" transfer-ownership="none"><type name="Gio.AsyncResult" c:type="GAsyncResult*"/></parameter>
+      </parameters>
+    </function>"#;
+
+    /// Whether the futures of the async global functions are `Send`, by name.
+    fn send_futures(name: &str, config: &str) -> Vec<(String, bool)> {
+        let env = test_env::analyzed_env(name, ASYNC_GIR, config);
+        env.analysis
+            .global_functions
+            .as_ref()
+            .unwrap()
+            .functions
+            .iter()
+            .filter_map(|f| Some((f.name.clone(), f.async_future.as_ref()?.is_send)))
+            .collect()
+    }
+
+    fn futures(send: [bool; 3]) -> Vec<(String, bool)> {
+        ["load_async", "read_async", "peek_async"]
+            .into_iter()
+            .map(ToOwned::to_owned)
+            .zip(send)
+            .collect()
+    }
+
+    #[test]
+    fn send_future() {
+        let buffer = "\n[[object]]\nname = \"Test.Buffer\"\nstatus = \"generate\"\n";
+        // Captured and returned types must be `Send`, `gpointer` never is
+        assert_eq!(
+            send_futures(
+                "send-future",
+                &format!("future_style = \"impl\"\n{buffer}concurrency = \"send\"\n")
+            ),
+            futures([true, true, false])
+        );
+        assert_eq!(
+            send_futures(
+                "send-future-buffer",
+                &format!("future_style = \"impl\"\n{buffer}")
+            ),
+            futures([true, false, false])
+        );
+        // Boxed futures are never `Send`
+        assert_eq!(
+            send_futures(
+                "send-future-boxed",
+                &format!("{buffer}concurrency = \"send\"\n")
+            ),
+            futures([false, false, false])
+        );
+    }
+
+    /// `load` returns a string but its future doesn't, the synchronous
+    /// variant of `fetch_async` isn't `fetch_sync` as its name suggests.
+    pub const PAIRS_GIR: &str = r#"
+    <function name="load_async" c:identifier="test_load_async" glib:sync-func="load" glib:finish-func="load_finish">
+      <return-value transfer-ownership="none"><type name="none" c:type="void"/></return-value>
+      <parameters>
+        <parameter name="cancellable" transfer-ownership="none" nullable="1" allow-none="1"><type name="Gio.Cancellable" c:type="GCancellable*"/></parameter>
+        <parameter name="callback" transfer-ownership="none" nullable="1" allow-none="1" scope="async" closure="2"><type name="Gio.AsyncReadyCallback" c:type="GAsyncReadyCallback"/></parameter>
+        <parameter name="user_data" transfer-ownership="none" nullable="1" allow-none="1"><type name="gpointer" c:type="gpointer"/></parameter>
+      </parameters>
+    </function>
+    <function name="load_finish" c:identifier="test_load_finish" throws="1">
+      <return-value transfer-ownership="none"><type name="none" c:type="void"/></return-value>
+      <parameters>
+        <parameter name="result" transfer-ownership="none"><type name="Gio.AsyncResult" c:type="GAsyncResult*"/></parameter>
+      </parameters>
+    </function>
+    <function name="load" c:identifier="test_load" glib:async-func="load_async" throws="1">
+      <return-value transfer-ownership="full"><type name="utf8" c:type="gchar*"/></return-value>
+      <parameters>
+        <parameter name="cancellable" transfer-ownership="none" nullable="1" allow-none="1"><type name="Gio.Cancellable" c:type="GCancellable*"/></parameter>
+      </parameters>
+    </function>
+    <function name="fetch_async" c:identifier="test_fetch_async" glib:sync-func="fetch_blocking" glib:finish-func="fetch_complete">
+      <return-value transfer-ownership="none"><type name="none" c:type="void"/></return-value>
+      <parameters>
+        <parameter name="cancellable" transfer-ownership="none" nullable="1" allow-none="1"><type name="Gio.Cancellable" c:type="GCancellable*"/></parameter>
+        <parameter name="callback" transfer-ownership="none" nullable="1" allow-none="1" scope="async" closure="2"><type name="Gio.AsyncReadyCallback" c:type="GAsyncReadyCallback"/></parameter>
+        <parameter name="user_data" transfer-ownership="none" nullable="1" allow-none="1"><type name="gpointer" c:type="gpointer"/></parameter>
+      </parameters>
+    </function>
+    <function name="fetch_complete" c:identifier="test_fetch_complete" throws="1">
+      <return-value transfer-ownership="full"><type name="utf8" c:type="gchar*"/></return-value>
+      <parameters>
+        <parameter name="result" transfer-ownership="none"><type name="Gio.AsyncResult" c:type="GAsyncResult*"/></parameter>
+      </parameters>
+    </function>
+    <function name="fetch_blocking" c:identifier="test_fetch_blocking" glib:async-func="fetch_async" throws="1">
+      <return-value transfer-ownership="full"><type name="utf8" c:type="gchar*"/></return-value>
+      <parameters>
+        <parameter name="cancellable" transfer-ownership="none" nullable="1" allow-none="1"><type name="Gio.Cancellable" c:type="GCancellable*"/></parameter>
+      </parameters>
+    </function>
+    <function name="fetch_sync" c:identifier="test_fetch_sync" throws="1">
+      <return-value transfer-ownership="full"><type name="utf8" c:type="gchar*"/></return-value>
+    </function>"#;
+
+    #[test]
+    fn sync_and_async_pairs() {
+        let mut env = test_env::analyzed_env("sync-async-pairs", PAIRS_GIR, "");
+        let mut funcs = env.analysis.global_functions.take().unwrap().functions;
+        let pairs = funcs
+            .iter()
+            .map(|f| {
+                (
+                    f.glib_name.as_str(),
+                    f.sync_func.as_deref(),
+                    f.async_func.as_deref(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            pairs,
+            [
+                ("test_load_async", Some("test_load"), None),
+                ("test_load_finish", None, None),
+                ("test_load", None, Some("test_load_async")),
+                ("test_fetch_async", Some("test_fetch_blocking"), None),
+                ("test_fetch_complete", None, None),
+                ("test_fetch_blocking", None, Some("test_fetch_async")),
+                ("test_fetch_sync", None, None),
+            ]
+        );
+
+        let functions = &env.library.namespace(library::MAIN_NAMESPACE).functions;
+        let warnings = link_sync_and_async(&env, functions, &mut funcs, &Default::default());
+        assert_eq!(
+            warnings,
+            [
+                "test_load returns Result<glib::GString, glib::Error> but the future of its \
+                 async variant test_load_async returns Result<(), glib::Error>",
+                "test_fetch_async: GIR synchronous variant fetch_blocking differs from \
+                 fetch_sync guessed from its name",
+            ]
+        );
+    }
+
+    #[test]
+    fn sync_result_types() {
+        let env = test_env::analyzed_env("sync-result-types", PAIRS_GIR, "");
+        let ns = env.library.namespace(library::MAIN_NAMESPACE);
+        let result_types = |name: &str| {
+            let func = ns.functions.iter().find(|f| f.name == name).unwrap();
+            bounds::result_types(&env, func, name, &[])
+        };
+        assert_eq!(
+            result_types("load"),
+            ("glib::GString".to_owned(), Some("glib::Error".to_owned()))
+        );
+        assert_eq!(
+            result_types("load_finish"),
+            ("()".to_owned(), Some("glib::Error".to_owned()))
+        );
+    }
 }
@@ -0,0 +1,51 @@
+use super::{
+    conversion_type::{ConversionType, is_scalar_array_element},
+    ffi_type::ffi_type,
+};
+use crate::{
+    env::Env,
+    library::{Basic, Type, TypeId},
+    traits::*,
+};
+
+/// How a `GArray` element is stored in the array data.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ElementKind {
+    /// Number with the same layout in Rust and C.
+    Direct,
+    /// Enum, flags or `gboolean`, converted with `into_glib`/`from_glib`.
+    Scalar,
+    /// NUL-terminated string pointer.
+    String,
+    /// Object or interface pointer.
+    Object,
+}
+
+#[derive(Clone, Debug)]
+pub struct Element {
+    pub kind: ElementKind,
+    /// FFI type of the values stored in the array.
+    pub ffi_type: String,
+}
+
+/// Returns how the elements of a `GArray` of `tid` are converted, if supported.
+pub fn element(env: &Env, tid: TypeId) -> Option<Element> {
+    let typ = env.type_(tid);
+    let (kind, c_type) = match typ {
+        Type::Basic(Basic::Utf8) => (ElementKind::String, "gchar*".to_owned()),
+        Type::Basic(_) if ConversionType::of(env, tid) == ConversionType::Direct => {
+            (ElementKind::Direct, typ.get_name())
+        }
+        _ if is_scalar_array_element(env, tid) => match typ {
+            Type::Basic(_) => (ElementKind::Scalar, typ.get_name()),
+            _ => (ElementKind::Scalar, typ.get_glib_name()?.to_owned()),
+        },
+        Type::Class(klass) => (ElementKind::Object, format!("{}*", klass.c_type)),
+        Type::Interface(iface) => (ElementKind::Object, format!("{}*", iface.c_type)),
+        _ => return None,
+    };
+    Some(Element {
+        kind,
+        ffi_type: ffi_type(env, tid, &c_type).ok()?.into_string(),
+    })
+}
@@ -0,0 +1,80 @@
+use super::{ffi_type::ffi_type, rust_type::RustType};
+use crate::{
+    env::Env,
+    library::{Basic, Type, TypeId},
+    traits::*,
+};
+
+/// How a `GHashTable` key or value is stored in the table's `gpointer` slots.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ElementKind {
+    /// NUL-terminated string, owned by the table when it has destroy functions.
+    String,
+    /// Integer packed into the pointer itself (`GINT_TO_POINTER`).
+    Integer,
+    /// Object or boxed pointer.
+    Pointer,
+}
+
+#[derive(Clone, Debug)]
+pub struct Element {
+    pub kind: ElementKind,
+    pub rust_type: RustType,
+    /// FFI pointer type the `gpointer` is cast to, empty for integers.
+    pub ffi_type: String,
+}
+
+impl Element {
+    fn new(env: &Env, tid: TypeId, is_key: bool) -> Option<Self> {
+        match env.type_(tid) {
+            Type::Basic(Basic::Utf8) => Some(Self {
+                kind: ElementKind::String,
+                rust_type: "String".to_owned().into(),
+                ffi_type: "*mut std::ffi::c_char".to_owned(),
+            }),
+            Type::Basic(
+                Basic::Int8
+                | Basic::UInt8
+                | Basic::Int16
+                | Basic::UInt16
+                | Basic::Int32
+                | Basic::UInt32
+                | Basic::Short
+                | Basic::UShort
+                | Basic::Int
+                | Basic::UInt,
+            ) => Some(Self {
+                kind: ElementKind::Integer,
+                rust_type: RustType::try_new(env, tid).ok()?,
+                ffi_type: String::new(),
+            }),
+            // Boxed types don't implement `Hash`, so they can only be values
+            Type::Record(record) if !is_key => Self::pointer(env, tid, &record.c_type),
+            Type::Class(klass) => Self::pointer(env, tid, &klass.c_type),
+            Type::Interface(iface) => Self::pointer(env, tid, &iface.c_type),
+            _ => None,
+        }
+    }
+
+    fn pointer(env: &Env, tid: TypeId, c_type: &str) -> Option<Self> {
+        Some(Self {
+            kind: ElementKind::Pointer,
+            rust_type: RustType::try_new(env, tid).ok()?,
+            ffi_type: ffi_type(env, tid, &format!("{c_type}*"))
+                .ok()?
+                .into_string(),
+        })
+    }
+}
+
+/// Returns the key and value conversions of a `GHashTable` type, if both
+/// are supported.
+pub fn elements(env: &Env, tid: TypeId) -> Option<(Element, Element)> {
+    match env.type_(tid) {
+        Type::HashTable(key_tid, value_tid) => Some((
+            Element::new(env, *key_tid, true)?,
+            Element::new(env, *value_tid, false)?,
+        )),
+        _ => None,
+    }
+}
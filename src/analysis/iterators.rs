@@ -0,0 +1,237 @@
+use log::{info, warn};
+
+use super::{
+    conversion_type::ConversionType,
+    ffi_type::ffi_type,
+    function_parameters::TransformationType,
+    functions::{self, find_function},
+    imports::Imports,
+    namespaces,
+    out_parameters::Mode,
+    rust_type::RustType,
+    safety_assertion_mode::SafetyAssertionMode,
+};
+use crate::{
+    codegen::Visibility,
+    config::{gobjects::GObject, iterator::IteratorFunctions, matchable::Matchable},
+    env::Env,
+    library::{self, Type, TypeId},
+    nameutil::split_namespace_name,
+    traits::*,
+    version::Version,
+};
+
+/// How the C iterator is stored in the generated iterator type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Storage {
+    /// The C iterator struct is allocated by the caller and filled by the
+    /// init function.
+    Inline,
+    /// The init function returns a new C iterator, owned through its Rust type.
+    Boxed,
+}
+
+#[derive(Debug)]
+pub struct Info {
+    pub full_name: String,
+    pub owner_id: TypeId,
+    /// Rust type of the iterated object or record.
+    pub owner_name: String,
+    /// Name of the generated iterator type.
+    pub name: String,
+    pub storage: Storage,
+    /// FFI struct for inline storage, Rust type for boxed storage.
+    pub iter_type: String,
+    /// Path of the init function.
+    pub init: String,
+    pub init_arguments: Vec<String>,
+    /// Analysis of the next function, taking the stored C iterator.
+    pub next: functions::Info,
+    pub version: Option<Version>,
+    pub visibility: Visibility,
+}
+
+pub fn new(
+    env: &Env,
+    obj: &GObject,
+    config: &IteratorFunctions,
+    imports: &mut Imports,
+) -> Option<Info> {
+    info!("Analyzing iterator of {}", obj.name);
+
+    let owner_id = env.library.find_type(0, &obj.name)?;
+    let owner_name = RustType::try_new(env, owner_id).ok()?.into_string();
+
+    if ConversionType::of(env, owner_id) != ConversionType::Pointer {
+        warn!("Iterator owner {} must be an object or record", obj.name);
+        return None;
+    }
+
+    let Some(init) = find_function(env, &config.init) else {
+        warn!("Iterator init function {} not found", config.init);
+        return None;
+    };
+    let Some(next) = find_function(env, &config.next) else {
+        warn!("Iterator next function {} not found", config.next);
+        return None;
+    };
+
+    if next.ret.typ() != TypeId::tid_bool() && next.ret.typ() != TypeId::tid_c_bool() {
+        warn!(
+            "Iterator next function {} of {} must return a boolean",
+            next.c_identifier, obj.name
+        );
+        return None;
+    }
+    let Some(iter_id) = next.parameters.first().map(library::Parameter::typ) else {
+        warn!(
+            "Iterator next function {} of {} takes no iterator",
+            next.c_identifier, obj.name
+        );
+        return None;
+    };
+    let Type::Record(iter_record) = env.library.type_(iter_id) else {
+        warn!(
+            "Iterator next function {} of {} must take an iterator record first",
+            next.c_identifier, obj.name
+        );
+        return None;
+    };
+
+    let is_owner = |par: &library::Parameter| par.typ() == owner_id;
+    let is_iter = |par: &library::Parameter| par.typ() == iter_id;
+    let storage = if init.parameters.len() == 2
+        && init.parameters.iter().any(is_owner)
+        && init.parameters.iter().any(is_iter)
+    {
+        Storage::Inline
+    } else if init.ret.typ() == iter_id
+        && init.parameters.len() == 1
+        && init.parameters.iter().all(is_owner)
+    {
+        Storage::Boxed
+    } else {
+        warn!(
+            "Iterator init function {} of {} must take the iterator and {} or return the iterator",
+            init.c_identifier, obj.name, owner_name
+        );
+        return None;
+    };
+
+    let (iter_type, iter_argument) = match storage {
+        Storage::Inline => {
+            if iter_record.disguised || iter_record.pointer {
+                warn!(
+                    "Iterator record {} of {} can't be allocated inline",
+                    iter_record.name, obj.name
+                );
+                return None;
+            }
+            let Ok(iter_type) = ffi_type(env, iter_id, &iter_record.c_type) else {
+                warn!(
+                    "Iterator record {} of {} has no usable C type",
+                    iter_record.name, obj.name
+                );
+                return None;
+            };
+            let iter_type = iter_type.into_string();
+            if iter_id.ns_id != namespaces::MAIN {
+                imports.add_used_type(&iter_type);
+            }
+            (iter_type, "&mut self.iter")
+        }
+        Storage::Boxed => match RustType::try_new(env, iter_id) {
+            Ok(rust_type) => {
+                imports.add_used_types(rust_type.used_types());
+                (rust_type.into_string(), "self.iter.to_glib_none_mut().0")
+            }
+            Err(_) => {
+                warn!(
+                    "Iterator record {} of {} must be generated or manual",
+                    iter_record.name, obj.name
+                );
+                return None;
+            }
+        },
+    };
+
+    let init_arguments = init
+        .parameters
+        .iter()
+        .map(|par| {
+            if is_iter(par) {
+                "iter.as_mut_ptr()".to_owned()
+            } else if matches!(env.library.type_(owner_id), Type::Record(_))
+                && !par.c_type().starts_with("const ")
+            {
+                "mut_override(owner.to_glib_none().0)".to_owned()
+            } else {
+                "owner.to_glib_none().0".to_owned()
+            }
+        })
+        .collect();
+
+    // The stored iterator is passed as is, so analyze the call as if it took
+    // the owner, which always has a Rust type
+    let mut next = next.clone();
+    next.parameters[0].set_tid(owner_id);
+    let configured_functions = obj.functions.matched(&next.name);
+    let mut next = functions::analyze_function(
+        env,
+        obj,
+        &next.name,
+        next.name.clone(),
+        obj.status,
+        &next,
+        Some(iter_id),
+        false,
+        false,
+        &configured_functions,
+        imports,
+    );
+    if next.commented
+        || next.outs.mode != Mode::Optional
+        || next.ret.nullable_return_is_error.is_some()
+    {
+        warn!(
+            "Iterator next function {} of {} must return its items in out parameters",
+            config.next, obj.name
+        );
+        return None;
+    }
+    for transformation in &mut next.parameters.transformations {
+        if transformation.ind_c == 0 {
+            transformation.transformation_type = TransformationType::ToGlibDirect {
+                name: iter_argument.to_owned(),
+            };
+        }
+    }
+    next.assertion = SafetyAssertionMode::None;
+
+    let name = config
+        .name
+        .clone()
+        .unwrap_or_else(|| format!("{}Iterator", split_namespace_name(&obj.name).1));
+    let version = env
+        .config
+        .filter_version(init.version.max(next.version).max(obj.version));
+
+    imports.add_used_type(&owner_name);
+    imports.add(&format!("crate::{}", env.main_sys_crate_name()));
+    imports.add("glib::translate::*");
+    imports.add("std::marker::PhantomData");
+
+    Some(Info {
+        full_name: obj.name.clone(),
+        owner_id,
+        owner_name,
+        name,
+        storage,
+        iter_type,
+        init: format!("{}::{}", env.main_sys_crate_name(), init.c_identifier),
+        init_arguments,
+        next,
+        version,
+        visibility: obj.visibility,
+    })
+}
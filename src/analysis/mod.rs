@@ -10,20 +10,25 @@ use crate::{
 
 pub mod bounds;
 pub mod c_type;
+pub mod callbacks;
 pub mod child_properties;
 pub mod class_builder;
 pub mod class_hierarchy;
 pub mod constants;
 pub mod conversion_type;
+pub mod default_impl;
 pub mod enums;
 pub mod ffi_type;
 pub mod flags;
 pub mod function_parameters;
 pub use function_parameters::Parameter;
 pub mod functions;
+pub mod garray;
 pub mod general;
+pub mod hash_table;
 pub mod imports;
 pub mod info_base;
+pub mod iterators;
 pub mod namespaces;
 pub mod object;
 pub mod out_parameters;
@@ -40,10 +45,13 @@ pub mod signatures;
 pub mod special_functions;
 pub mod supertypes;
 pub mod symbols;
+#[cfg(test)]
+pub mod test_env;
 pub mod trampoline_parameters;
 pub mod trampolines;
 pub mod try_from_glib;
 pub mod types;
+pub mod unions;
 
 #[derive(Debug, Default)]
 pub struct Analysis {
@@ -57,6 +65,15 @@ pub struct Analysis {
 
     pub flags: Vec<flags::Info>,
     pub flags_imports: Imports,
+
+    pub unions: Vec<unions::Info>,
+    pub union_imports: Imports,
+
+    pub callbacks: Vec<callbacks::Info>,
+    pub callback_imports: Imports,
+
+    pub iterators: Vec<iterators::Info>,
+    pub iterator_imports: Imports,
 }
 
 fn find_function<'a>(
@@ -114,6 +131,24 @@ impl Analysis {
             })
     }
 
+    pub fn find_object_by_class_method<
+        F: Fn(&functions::Info) -> bool + Copy,
+        G: Fn(&object::Info) -> bool + Copy,
+    >(
+        &self,
+        env: &Env,
+        search_obj: G,
+        search_fn: F,
+    ) -> Option<(&object::Info, &functions::Info)> {
+        self.objects
+            .values()
+            .filter(|o| search_obj(o))
+            .find_map(|obj_info| {
+                find_function(env, obj_info.class_methods.iter(), search_fn)
+                    .map(|fn_info| (obj_info, fn_info))
+            })
+    }
+
     pub fn find_object_by_function<
         F: Fn(&functions::Info) -> bool + Copy,
         G: Fn(&object::Info) -> bool + Copy,
@@ -170,6 +205,8 @@ impl Analysis {
 }
 
 pub fn run(env: &mut Env) {
+    callbacks::check_names(env);
+
     let mut to_analyze: Vec<(TypeId, Vec<TypeId>)> = Vec::with_capacity(env.config.objects.len());
     for obj in env.config.objects.values() {
         if obj.status.ignored() {
@@ -210,10 +247,16 @@ pub fn run(env: &mut Env) {
 
     analyze_flags(env);
 
+    analyze_unions(env);
+
     analyze_constants(env);
 
     // Analyze free functions as the last step once all types are analyzed
     analyze_global_functions(env);
+
+    analyze_shared_callbacks(env);
+
+    analyze_iterators(env);
 }
 
 fn analyze_enums(env: &mut Env) {
@@ -258,6 +301,50 @@ fn analyze_flags(env: &mut Env) {
     env.analysis.flags_imports = imports;
 }
 
+fn analyze_unions(env: &mut Env) {
+    let mut imports = Imports::new(&env.library);
+
+    for obj in env.config.objects.values() {
+        if obj.status.ignored() {
+            continue;
+        }
+        let Some(tid) = env.library.find_type(0, &obj.name) else {
+            continue;
+        };
+
+        if let Type::Union(_) = env.library.type_(tid)
+            && let Some(info) = unions::new(env, obj, &mut imports)
+        {
+            env.analysis.unions.push(info);
+        }
+    }
+
+    env.analysis.union_imports = imports;
+}
+
+fn analyze_shared_callbacks(env: &mut Env) {
+    let mut imports = Imports::new(&env.library);
+    env.analysis.callbacks = callbacks::analyze(env, &mut imports);
+    env.analysis.callback_imports = imports;
+}
+
+fn analyze_iterators(env: &mut Env) {
+    let mut imports = Imports::new(&env.library);
+
+    for obj in env.config.objects.values() {
+        if !obj.status.need_generate() {
+            continue;
+        }
+        if let Some(ref config) = obj.iterator
+            && let Some(info) = iterators::new(env, obj, config, &mut imports)
+        {
+            env.analysis.iterators.push(info);
+        }
+    }
+
+    env.analysis.iterator_imports = imports;
+}
+
 fn analyze_global_functions(env: &mut Env) {
     let ns = env.library.namespace(library::MAIN_NAMESPACE);
 
@@ -1,12 +1,13 @@
 use std::{borrow::Cow, ops::Deref};
 
-use log::info;
+use log::{info, warn};
 
 use super::{
     child_properties::ChildProperties, imports::Imports, info_base::InfoBase,
     signatures::Signatures, *,
 };
 use crate::{
+    case::CaseExt,
     config::gobjects::{GObject, GStatus},
     env::Env,
     library::{self, FunctionKind},
@@ -41,17 +42,31 @@ pub struct Info {
     pub has_constructors: bool,
     pub has_functions: bool,
     pub virtual_methods: Vec<functions::Info>,
+    /// Methods of the class struct, generated on `glib::Class<T>`
+    pub class_methods: Vec<functions::Info>,
+    /// GIR name of the class struct, e.g. `WidgetClass`
+    pub class_struct_name: Option<String>,
     pub signals: Vec<signals::Info>,
     pub notify_signals: Vec<signals::Info>,
     pub properties: Vec<properties::Property>,
     pub builder_properties: Vec<(Vec<properties::Property>, TypeId)>,
     pub builder_postprocess: Option<String>,
     pub child_properties: ChildProperties,
+    pub default_consts: Vec<properties::DefaultConst>,
+    pub property_bindings: Vec<properties::Binding>,
+    /// `Default` implementation configured with `default_constructor`
+    pub default_impl: Option<default_impl::Info>,
     pub signatures: Signatures,
     /// Specific to fundamental types
     pub ref_fn: Option<String>,
     /// Specific to fundamental types
     pub unref_fn: Option<String>,
+    /// Specific to fundamental types
+    pub set_value_fn: Option<String>,
+    /// Specific to fundamental types
+    pub get_value_fn: Option<String>,
+    /// Specific to fundamental types, creates its `GParamSpec`
+    pub param_spec_fn: Option<String>,
 }
 
 impl Info {
@@ -63,15 +78,18 @@ impl Info {
     /// Whether we should generate an impl block for this object
     /// We don't generate an impl block if the type doesn't have any of the
     /// followings:
-    /// - Constructors / Functions / Builder properties (no build function)
+    /// - Constructors / Functions / Builder properties (no build function) /
+    ///   Default value constants
     /// - Is a final type & doesn't have either methods / properties / child
     ///   properties / signals
     pub fn should_generate_impl_block(&self) -> bool {
         self.has_constructors
             || has_builder_properties(&self.builder_properties)
+            || !self.default_consts.is_empty()
             || !(self.need_generate_trait()
                 && self.methods().is_empty()
                 && self.properties.is_empty()
+                && self.property_bindings.is_empty()
                 && self.child_properties.is_empty()
                 && self.signals.is_empty())
             || self.has_functions
@@ -82,12 +100,27 @@ impl Info {
             || self.has_functions
             || !self.need_generate_trait()
             || has_builder_properties(&self.builder_properties)
+            || !self.default_consts.is_empty()
     }
 
     pub fn need_generate_trait(&self) -> bool {
         self.generate_trait
     }
 
+    /// Name of the trait containing the class methods, e.g.
+    /// `WidgetClassMethodsExt`
+    pub fn class_trait_name(&self) -> Option<String> {
+        self.class_struct_name
+            .as_ref()
+            .map(|name| format!("{name}MethodsExt"))
+    }
+
+    pub fn need_generate_class_trait(&self) -> bool {
+        self.class_methods
+            .iter()
+            .any(|f| f.status.need_generate() && !f.commented)
+    }
+
     pub fn has_action_signals(&self) -> bool {
         self.signals.iter().any(|s| s.action_emit_name.is_some())
     }
@@ -95,8 +128,11 @@ impl Info {
     /// Returns the location of the function within this object
     pub fn function_location(&self, fn_info: &functions::Info) -> LocationInObject {
         if fn_info.kind == FunctionKind::ClassMethod {
-            // TODO: Fix location here once we can auto generate virtual methods
-            LocationInObject::ClassExt
+            if fn_info.status == GStatus::Generate {
+                LocationInObject::ClassExt
+            } else {
+                LocationInObject::ClassExtManual
+            }
         } else if fn_info.kind == FunctionKind::VirtualMethod {
             // TODO: Fix location here once we can auto generate virtual methods
             LocationInObject::VirtualExt
@@ -140,8 +176,12 @@ impl Info {
                     trait_name.into(),
                 )
             }
-            LocationInObject::ClassExt | LocationInObject::ClassExtManual => {
-                let trait_name = format!("{}Ext", self.trait_name);
+            LocationInObject::ClassExt => {
+                let trait_name = self.class_trait_name().unwrap_or_default();
+                (format!("prelude::{trait_name}").into(), trait_name.into())
+            }
+            LocationInObject::ClassExtManual => {
+                let trait_name = self.class_trait_name().unwrap_or_default();
                 (
                     format!("subclass::prelude::{trait_name}").into(),
                     trait_name.into(),
@@ -233,6 +273,32 @@ pub fn class(env: &Env, obj: &GObject, deps: &[library::TypeId]) -> Option<Info>
         Some(&mut signatures),
         Some(deps),
     );
+    let class_struct = klass.type_struct.as_ref().and_then(|name| {
+        let tid = env.library.find_type(class_tid.ns_id, name)?;
+        env.type_(tid).maybe_ref_as::<library::Record>()
+    });
+    let class_methods = class_struct.map_or_else(Vec::new, |record| {
+        // Class methods are configured in their own `class_method` entries
+        let class_obj = GObject {
+            functions: obj.class_methods.clone(),
+            ..obj.clone()
+        };
+        functions::analyze(
+            env,
+            &record.functions,
+            Some(class_tid),
+            true,
+            false,
+            &class_obj,
+            &mut imports,
+            None,
+            Some(deps),
+        )
+    });
+    if class_methods.iter().any(|f| f.status.need_generate()) {
+        imports.add("glib::prelude::*");
+    }
+
     let mut specials = special_functions::extract(&mut functions, type_, obj);
     // `copy` will duplicate an object while `clone` just adds a reference
     special_functions::unhide(&mut functions, &specials, special_functions::Type::Copy);
@@ -273,10 +339,23 @@ pub fn class(env: &Env, obj: &GObject, deps: &[library::TypeId]) -> Option<Info>
 
     let builder_properties =
         class_builder::analyze(env, &klass.properties, class_tid, obj, &mut imports);
+    let default_consts =
+        properties::analyze_default_consts(env, &klass.properties, obj, &mut imports);
+    let property_bindings = properties::analyze_bindings(
+        env,
+        &klass.properties,
+        &supertypes_properties,
+        class_tid,
+        is_fundamental,
+        obj,
+        &mut imports,
+    );
 
     let child_properties =
         child_properties::analyze(env, obj.child_properties.as_ref(), class_tid, &mut imports);
 
+    let default_impl = default_impl::analyze(env, obj, class_tid, &functions);
+
     let has_methods = functions
         .iter()
         .any(|f| f.kind == library::FunctionKind::Method && f.status.need_generate());
@@ -289,7 +368,11 @@ pub fn class(env: &Env, obj: &GObject, deps: &[library::TypeId]) -> Option<Info>
     // possible subtypes
     let generate_trait = !final_type
         && !is_fundamental
-        && (has_signals || has_methods || !properties.is_empty() || !child_properties.is_empty());
+        && (has_signals
+            || has_methods
+            || !properties.is_empty()
+            || !property_bindings.is_empty()
+            || !child_properties.is_empty());
 
     imports.add("crate::ffi");
     if is_fundamental {
@@ -346,20 +429,67 @@ pub fn class(env: &Env, obj: &GObject, deps: &[library::TypeId]) -> Option<Info>
         has_constructors,
         has_functions,
         virtual_methods,
+        class_methods,
+        class_struct_name: klass.type_struct.clone(),
         signals,
         notify_signals,
         properties,
         builder_properties,
         builder_postprocess: obj.builder_postprocess.clone(),
         child_properties,
+        default_consts,
+        property_bindings,
+        default_impl,
         signatures,
         ref_fn: klass.ref_fn.clone(),
         unref_fn: klass.unref_fn.clone(),
+        set_value_fn: klass.set_value_fn.clone(),
+        get_value_fn: klass.get_value_fn.clone(),
+        param_spec_fn: if is_fundamental {
+            param_spec_function(env, class_tid, klass)
+        } else {
+            None
+        },
     };
 
     Some(info)
 }
 
+/// Finds the function creating the `GParamSpec` subtype of a fundamental
+/// type, e.g. `gtk_param_spec_expression()` for `GtkExpression`.
+fn param_spec_function(
+    env: &Env,
+    class_tid: library::TypeId,
+    klass: &library::Class,
+) -> Option<String> {
+    use crate::library::{Basic, Type};
+
+    let name = format!("param_spec_{}", klass.name.to_snake());
+    let func = env
+        .library
+        .namespace(class_tid.ns_id)
+        .functions
+        .iter()
+        .find(|f| f.name == name)?;
+    let is_string =
+        |par: &library::Parameter| matches!(env.library.type_(par.typ()), Type::Basic(Basic::Utf8));
+    let pars = &func.parameters;
+    let is_param_spec_constructor = pars.len() == 4
+        && pars[..3].iter().all(is_string)
+        && pars[3].typ().full_name(&env.library) == "GObject.ParamFlags"
+        && func.ret.typ().full_name(&env.library) == "GObject.ParamSpec";
+    if !is_param_spec_constructor {
+        warn_main!(
+            class_tid,
+            "{} doesn't take a name, nick, blurb and flags, not implementing `HasParamSpec` for {}",
+            func.c_identifier,
+            klass.name
+        );
+        return None;
+    }
+    Some(func.c_identifier.clone())
+}
+
 pub fn interface(env: &Env, obj: &GObject, deps: &[library::TypeId]) -> Option<Info> {
     info!("Analyzing interface {}", obj.name);
     let full_name = obj.name.clone();
@@ -372,6 +502,13 @@ pub fn interface(env: &Env, obj: &GObject, deps: &[library::TypeId]) -> Option<I
 
     let iface: &library::Interface = type_.maybe_ref()?;
 
+    if obj.default_constructor.is_some() {
+        warn!(
+            "Default constructor of interface {} isn't supported",
+            obj.name
+        );
+    }
+
     let version = obj.version.or(iface.version);
     let deprecated_version = iface.deprecated_version;
 
@@ -432,6 +569,17 @@ pub fn interface(env: &Env, obj: &GObject, deps: &[library::TypeId]) -> Option<I
         deps,
         &functions,
     );
+    let default_consts =
+        properties::analyze_default_consts(env, &iface.properties, obj, &mut imports);
+    let property_bindings = properties::analyze_bindings(
+        env,
+        &iface.properties,
+        &supertypes_properties,
+        iface_tid,
+        false,
+        obj,
+        &mut imports,
+    );
 
     let base = InfoBase {
         full_name,
@@ -463,9 +611,142 @@ pub fn interface(env: &Env, obj: &GObject, deps: &[library::TypeId]) -> Option<I
         signals,
         notify_signals,
         properties,
+        default_consts,
+        property_bindings,
         signatures,
         ..Default::default()
     };
 
     Some(info)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::analysis::{ref_mode::RefMode, test_env::analyzed_env};
+
+    const GIR: &str = r#"
+    <class name="Widget" c:type="TestWidget" parent="GObject.Object" glib:type-name="TestWidget" glib:get-type="test_widget_get_type" glib:type-struct="WidgetClass" c:symbol-prefix="widget">
+      <method name="get_css_name" c:identifier="test_widget_get_css_name">
+        <return-value transfer-ownership="none"><type name="utf8" c:type="const char*"/></return-value>
+        <parameters><instance-parameter name="widget" transfer-ownership="none"><type name="Widget" c:type="TestWidget*"/></instance-parameter></parameters>
+      </method>
+    </class>
+    <record name="WidgetClass" c:type="TestWidgetClass" glib:is-gtype-struct-for="Widget">
+      <method name="set_css_name" c:identifier="test_widget_class_set_css_name">
+        <return-value transfer-ownership="none"><type name="none" c:type="void"/></return-value>
+        <parameters><instance-parameter name="widget_class" transfer-ownership="none"><type name="WidgetClass" c:type="TestWidgetClass*"/></instance-parameter>
+        <parameter name="name" transfer-ownership="none"><type name="utf8" c:type="const char*"/></parameter></parameters>
+      </method>
+      <method name="get_css_name" c:identifier="test_widget_class_get_css_name">
+        <return-value transfer-ownership="none"><type name="utf8" c:type="const char*"/></return-value>
+        <parameters><instance-parameter name="widget_class" transfer-ownership="none"><type name="WidgetClass" c:type="TestWidgetClass*"/></instance-parameter></parameters>
+      </method>
+      <method name="get_name" c:identifier="test_widget_class_get_name">
+        <return-value transfer-ownership="none"><type name="utf8" c:type="const char*"/></return-value>
+        <parameters><instance-parameter name="widget_class" transfer-ownership="none"><type name="WidgetClass" c:type="const TestWidgetClass*"/></instance-parameter></parameters>
+      </method>
+    </record>
+"#;
+
+    fn class_methods(config: &str) -> Vec<(String, RefMode)> {
+        let env = analyzed_env(
+            &format!("class-methods-{}", config.len()),
+            GIR,
+            &format!("[[object]]\nname = \"Test.Widget\"\nstatus = \"generate\"\n{config}"),
+        );
+        let info = &env.analysis.objects["Test.Widget"];
+        assert_eq!(
+            info.class_trait_name().as_deref(),
+            Some("WidgetClassMethodsExt")
+        );
+        info.class_methods
+            .iter()
+            .filter(|f| f.status.need_generate())
+            .map(|f| (f.glib_name.clone(), f.parameters.c_parameters[0].ref_mode))
+            .collect()
+    }
+
+    #[test]
+    fn class_method_self() {
+        // Only the `const` class struct is taken by `&self`, whatever the name
+        assert_eq!(
+            class_methods(""),
+            [
+                (
+                    "test_widget_class_set_css_name".to_owned(),
+                    RefMode::ByRefMut
+                ),
+                (
+                    "test_widget_class_get_css_name".to_owned(),
+                    RefMode::ByRefMut
+                ),
+                ("test_widget_class_get_name".to_owned(), RefMode::ByRef),
+            ]
+        );
+    }
+
+    #[test]
+    fn class_method_config() {
+        let config = r#"
+    [[object.function]]
+    name = "get_css_name"
+    ignore = true
+    [[object.class_method]]
+    name = "set_css_name"
+    ignore = true
+    [[object.class_method]]
+    name = "get_css_name"
+        [[object.class_method.parameter]]
+        name = "widget_class"
+        const = true
+"#;
+        // The instance method doesn't hide the class method of the same name
+        assert_eq!(
+            class_methods(config),
+            [
+                ("test_widget_class_get_css_name".to_owned(), RefMode::ByRef),
+                ("test_widget_class_get_name".to_owned(), RefMode::ByRef),
+            ]
+        );
+    }
+
+    const FUNDAMENTAL_GIR: &str = r#"
+    <class name="Expression" c:type="TestExpression" glib:type-name="TestExpression" glib:get-type="test_expression_get_type" glib:fundamental="1" glib:ref-func="test_expression_ref" glib:unref-func="test_expression_unref" glib:set-value-func="test_value_set_expression" glib:get-value-func="test_value_get_expression" c:symbol-prefix="expression">
+    </class>
+    <class name="Node" c:type="TestNode" glib:type-name="TestNode" glib:get-type="test_node_get_type" glib:fundamental="1" glib:ref-func="test_node_ref" glib:unref-func="test_node_unref" c:symbol-prefix="node">
+    </class>
+    <function name="param_spec_expression" c:identifier="test_param_spec_expression">
+      <return-value transfer-ownership="full"><type name="GObject.ParamSpec" c:type="GParamSpec*"/></return-value>
+      <parameters>
+        <parameter name="name" transfer-ownership="none"><type name="utf8" c:type="const char*"/></parameter>
+        <parameter name="nick" transfer-ownership="none" nullable="1"><type name="utf8" c:type="const char*"/></parameter>
+        <parameter name="blurb" transfer-ownership="none" nullable="1"><type name="utf8" c:type="const char*"/></parameter>
+        <parameter name="flags" transfer-ownership="none"><type name="GObject.ParamFlags" c:type="GParamFlags"/></parameter>
+      </parameters>
+    </function>
+    <function name="param_spec_node" c:identifier="test_param_spec_node">
+      <return-value transfer-ownership="full"><type name="GObject.ParamSpec" c:type="GParamSpec*"/></return-value>
+      <parameters>
+        <parameter name="name" transfer-ownership="none"><type name="utf8" c:type="const char*"/></parameter>
+        <parameter name="flags" transfer-ownership="none"><type name="GObject.ParamFlags" c:type="GParamFlags"/></parameter>
+      </parameters>
+    </function>
+"#;
+
+    #[test]
+    fn fundamental_param_spec_function() {
+        let env = analyzed_env(
+            "param-spec-function",
+            FUNDAMENTAL_GIR,
+            "[[object]]\nname = \"Test.Expression\"\nstatus = \"generate\"\n\
+             [[object]]\nname = \"Test.Node\"\nstatus = \"generate\"\n",
+        );
+        let objects = &env.analysis.objects;
+        assert_eq!(
+            objects["Test.Expression"].param_spec_fn.as_deref(),
+            Some("test_param_spec_expression")
+        );
+        // Without a nick and a blurb, it isn't a usual `GParamSpec` constructor
+        assert_eq!(objects["Test.Node"].param_spec_fn, None);
+    }
+}
@@ -4,8 +4,12 @@ use log::error;
 
 use crate::{
     analysis::{
-        self, conversion_type::ConversionType, function_parameters::CParameter,
-        functions::is_carray_with_direct_elements, return_value, rust_type::RustType,
+        self,
+        conversion_type::{ConversionType, is_direct_fixed_array},
+        function_parameters::{CParameter, is_caller_allocated_array},
+        functions::is_carray_with_direct_elements,
+        return_value,
+        rust_type::RustType,
     },
     config::{self, parameter_matchable::ParameterMatchable},
     env::Env,
@@ -75,7 +79,9 @@ pub fn analyze(
     }
 
     for lib_par in &func.parameters {
-        if lib_par.direction() != ParameterDirection::Out {
+        if lib_par.direction() != ParameterDirection::Out
+            || is_caller_allocated_array(env, lib_par, &func.parameters)
+        {
             continue;
         }
         if can_as_return(env, lib_par) {
@@ -133,6 +139,10 @@ pub fn can_as_return(env: &Env, par: &library::Parameter) -> bool {
             if is_carray_with_direct_elements(env, par.typ()) && par.array_length().is_none() {
                 return false;
             }
+            // Fixed-size arrays are only copied out of caller-allocated storage
+            if is_direct_fixed_array(env, par.typ()) {
+                return par.is_caller_allocates();
+            }
 
             RustType::builder(env, par.typ())
                 .direction(ParameterDirection::Out)
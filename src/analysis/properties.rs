@@ -1,4 +1,4 @@
-use log::warn;
+use log::{info, warn};
 
 use crate::{
     analysis::{
@@ -10,7 +10,7 @@ use crate::{
         signatures::{Signature, Signatures},
         trampolines,
     },
-    config::{self, GObject, PropertyGenerateFlags, gobjects::GStatus},
+    config::{self, GObject, PropertyGenerateFlags},
     env::Env,
     library, nameutil,
     traits::*,
@@ -33,6 +33,33 @@ pub struct Property {
     pub version: Option<Version>,
     pub deprecated_version: Option<Version>,
     pub cfg_condition: Option<String>,
+    /// "Default value" and "Allowed values" doc lines, see [`value_doc`]
+    pub value_doc: Vec<String>,
+}
+
+/// A typed `DEFAULT_*` associated constant holding a property's default value
+#[derive(Debug)]
+pub struct DefaultConst {
+    pub name: String,
+    pub typ: String,
+    pub value: String,
+    pub version: Option<Version>,
+    pub deprecated_version: Option<Version>,
+    pub cfg_condition: Option<String>,
+}
+
+/// Typed `bind_*` helpers returning a `glib::BindingBuilder` for a property
+#[derive(Debug)]
+pub struct Binding {
+    pub name: String,
+    pub func_name: String,
+    /// Rust type of the property value
+    pub typ: String,
+    /// Whether the binding can also be bidirectional
+    pub writable: bool,
+    pub version: Option<Version>,
+    pub deprecated_version: Option<Version>,
+    pub cfg_condition: Option<String>,
 }
 
 pub fn analyze(
@@ -171,6 +198,33 @@ fn analyze_property(
         notifiable = generate.contains(PropertyGenerateFlags::NOTIFY);
     }
 
+    let getter_func = functions.iter().find(|f| {
+        f.get_property.as_ref() == Some(&prop.name) && Some(&f.name) == prop.getter.as_ref()
+    });
+    let setter_func = functions.iter().find(|f| {
+        f.set_property.as_ref() == Some(&prop.name) && Some(&f.name) == prop.setter.as_ref()
+    });
+
+    let has_getter = readable
+        && has_accessor(
+            &type_name,
+            &name,
+            "getter",
+            prop.getter.as_deref(),
+            getter_func,
+        );
+    let has_setter = writable
+        && has_accessor(
+            &type_name,
+            &name,
+            "setter",
+            prop.setter.as_deref(),
+            setter_func,
+        );
+
+    // Functions commented out in the generated code can't replace the accessors
+    let is_commented = |name: &str| functions.iter().any(|f| f.name == name && f.commented);
+
     if readable {
         for check_get_func_name in check_get_func_names {
             let (has, version) = Signature::has_for_property(
@@ -181,7 +235,7 @@ fn analyze_property(
                 signatures,
                 deps,
             );
-            if has {
+            if has && !is_commented(&check_get_func_name) {
                 // There is a matching get func
                 if env.is_totally_deprecated(Some(type_tid.ns_id), version)
                     || version <= prop_version
@@ -203,7 +257,7 @@ fn analyze_property(
     if writable {
         let (has, version) =
             Signature::has_for_property(env, &set_func_name, false, prop.typ, signatures, deps);
-        if has {
+        if has && !is_commented(&set_func_name) {
             // There is a matching set func
             if env.is_totally_deprecated(Some(type_tid.ns_id), version) || version <= prop_version {
                 // And its availability covers the property's availability
@@ -222,18 +276,6 @@ fn analyze_property(
 
     let (get_out_ref_mode, set_in_ref_mode, nullable) = get_property_ref_modes(env, prop);
 
-    let getter_func = functions.iter().find(|f| {
-        f.get_property.as_ref() == Some(&prop.name) && Some(&f.name) == prop.getter.as_ref()
-    });
-    let setter_func = functions.iter().find(|f| {
-        f.set_property.as_ref() == Some(&prop.name) && Some(&f.name) == prop.setter.as_ref()
-    });
-
-    let has_getter =
-        getter_func.is_some_and(|g| matches!(g.status, GStatus::Generate | GStatus::Manual));
-    let has_setter =
-        setter_func.is_some_and(|s| matches!(s.status, GStatus::Generate | GStatus::Manual));
-
     let getter = if readable && (!has_getter || prop.version < getter_func.and_then(|g| g.version))
     {
         if let Ok(rust_type) = RustType::builder(env, prop.typ)
@@ -249,6 +291,11 @@ fn analyze_property(
         let mut getter_version = prop_version;
         if has_getter {
             let getter = getter_func.unwrap();
+            info!(
+                "Getter {} of property \"{type_name}.{name}\" is only available since {}, using a property-based getter before",
+                getter.glib_name,
+                getter.version.unwrap(),
+            );
             get_func_name = getter.new_name.as_ref().unwrap_or(&getter.name).to_string();
             get_prop_name = Some(getter.name.clone());
             getter_version = getter.version.map(|mut g| {
@@ -272,6 +319,7 @@ fn analyze_property(
             version: getter_version,
             deprecated_version: prop.deprecated_version,
             cfg_condition: cfg_condition.clone(),
+            value_doc: Vec::new(),
         })
     } else {
         None
@@ -302,6 +350,11 @@ fn analyze_property(
         let mut setter_version = prop_version;
         if has_setter {
             let setter = setter_func.unwrap();
+            info!(
+                "Setter {} of property \"{type_name}.{name}\" is only available since {}, using a property-based setter before",
+                setter.glib_name,
+                setter.version.unwrap(),
+            );
             set_func_name = setter.new_name.as_ref().unwrap_or(&setter.name).to_string();
             set_prop_name = Some(setter.name.clone());
             setter_version = setter.version.map(|mut s| {
@@ -325,6 +378,7 @@ fn analyze_property(
             version: setter_version,
             deprecated_version: prop.deprecated_version,
             cfg_condition: cfg_condition.clone(),
+            value_doc: Vec::new(),
         })
     } else {
         None
@@ -363,6 +417,26 @@ fn analyze_property(
             prop_version,
         );
 
+        let configured_signals = obj.signals.matched(&format!("notify::{name}"));
+        let stream_name = signals::analyze_stream(
+            format!("{name_for_func}_notify_stream"),
+            &format!("notify::{name}"),
+            &trampoline,
+            is_fundamental,
+            &configured_signals,
+            obj,
+            imports,
+        );
+        let weak_connect_name = signals::analyze_weak(
+            format!("connect_{name_for_func}_notify_weak"),
+            &format!("notify::{name}"),
+            &trampoline,
+            is_fundamental,
+            &configured_signals,
+            obj,
+            imports,
+        );
+
         if trampoline.is_ok() {
             imports.add_used_types(&used_types);
             if generate_trait {
@@ -373,6 +447,8 @@ fn analyze_property(
 
             Some(signals::Info {
                 connect_name: format!("connect_{name_for_func}_notify"),
+                stream_name,
+                weak_connect_name,
                 signal_name: format!("notify::{name}"),
                 trampoline,
                 action_emit_name: None,
@@ -393,6 +469,262 @@ fn analyze_property(
     (getter, setter, notify_signal)
 }
 
+pub fn analyze_default_consts(
+    env: &Env,
+    props: &[library::Property],
+    obj: &GObject,
+    imports: &mut Imports,
+) -> Vec<DefaultConst> {
+    let mut consts = Vec::new();
+    for prop in props {
+        let configured_properties = obj.properties.matched(&prop.name);
+        if !configured_properties
+            .iter()
+            .any(|p| p.generate_default_const)
+            || !configured_properties
+                .iter()
+                .all(|p| p.status.need_generate())
+        {
+            continue;
+        }
+        let name = format!("{}.{}", obj.name, prop.name);
+        let Some(ref default_value) = prop.default_value else {
+            warn!("No default value for property \"{name}\", not generating its constant");
+            continue;
+        };
+        let is_const_type = match env.library.type_(prop.typ) {
+            library::Type::Basic(basic) => {
+                basic.is_numeric() || matches!(basic, library::Basic::Boolean)
+            }
+            library::Type::Enumeration(_) => true,
+            _ => false,
+        };
+        let (Ok(typ), true) = (RustType::try_new(env, prop.typ), is_const_type) else {
+            warn!("Can't generate a constant for the default value of property \"{name}\"");
+            continue;
+        };
+        let Some(value) = rust_value(env, prop.typ, default_value) else {
+            warn!(
+                "Default value `{default_value}` of property \"{name}\" isn't a valid {}, not generating its constant",
+                typ.as_str()
+            );
+            continue;
+        };
+
+        let version = configured_properties
+            .iter()
+            .filter_map(|f| f.version)
+            .min()
+            .or(prop.version);
+        let cfg_condition = configured_properties
+            .iter()
+            .find_map(|p| p.cfg_condition.clone());
+        imports
+            .with_defaults(version, &cfg_condition)
+            .add_used_types(typ.used_types());
+
+        consts.push(DefaultConst {
+            name: format!(
+                "DEFAULT_{}",
+                nameutil::signal_to_snake(&prop.name).to_uppercase()
+            ),
+            typ: typ.into_string(),
+            value,
+            version,
+            deprecated_version: prop.deprecated_version,
+            cfg_condition,
+        });
+    }
+    consts
+}
+
+pub fn analyze_bindings(
+    env: &Env,
+    props: &[library::Property],
+    supertypes_props: &[&library::Property],
+    type_tid: library::TypeId,
+    is_fundamental: bool,
+    obj: &GObject,
+    imports: &mut Imports,
+) -> Vec<Binding> {
+    let mut bindings = Vec::new();
+    for prop in props {
+        let configured_properties = obj.properties.matched(&prop.name);
+        let configured = configured_properties.iter().find_map(|p| p.bind);
+        if !configured.unwrap_or(obj.generate_property_bindings)
+            || !configured_properties
+                .iter()
+                .all(|p| p.status.need_generate())
+            || env.is_totally_deprecated(Some(type_tid.ns_id), prop.deprecated_version)
+            || supertypes_props
+                .iter()
+                .any(|p| p.name == prop.name && p.typ == prop.typ)
+        {
+            continue;
+        }
+
+        let (get_out_ref_mode, _, nullable) = get_property_ref_modes(env, prop);
+        let typ = RustType::builder(env, prop.typ)
+            .direction(library::ParameterDirection::Return)
+            .nullable(nullable)
+            .ref_mode(get_out_ref_mode)
+            .try_build_param();
+        let reason = if is_fundamental {
+            Some("is on a fundamental type")
+        } else if !prop.readable {
+            Some("isn't readable")
+        } else if typ.is_err() {
+            Some("has an unsupported type")
+        } else {
+            None
+        };
+        if let Some(reason) = reason {
+            // Only complain about properties explicitly configured as bindable
+            if configured.is_some() {
+                warn!(
+                    "Property \"{}.{}\" {reason} and can't be bound",
+                    obj.name, prop.name
+                );
+            }
+            continue;
+        }
+        let typ = typ.unwrap();
+
+        let version = configured_properties
+            .iter()
+            .filter_map(|f| f.version)
+            .min()
+            .or(prop.version);
+        let cfg_condition = configured_properties
+            .iter()
+            .find_map(|p| p.cfg_condition.clone());
+        let imports = &mut imports.with_defaults(version, &cfg_condition);
+        imports.add_used_types(typ.used_types());
+        imports.add("glib::prelude::*");
+
+        bindings.push(Binding {
+            name: prop.name.clone(),
+            func_name: format!("bind_{}", nameutil::signal_to_snake(&prop.name)),
+            typ: typ.into_string(),
+            writable: prop.writable && !prop.construct_only,
+            version,
+            deprecated_version: prop.deprecated_version,
+            cfg_condition,
+        });
+    }
+    bindings
+}
+
+/// Turns a property value, as written in the GIR `default-value` attribute
+/// or in the configuration, into the matching Rust expression.
+pub fn rust_value(env: &Env, typ: library::TypeId, value: &str) -> Option<String> {
+    use crate::library::{Basic, Type};
+
+    let value = value.trim();
+    match env.library.type_(typ) {
+        Type::Basic(Basic::Boolean) => match value {
+            "TRUE" | "true" | "1" => Some("true".to_owned()),
+            "FALSE" | "false" | "0" => Some("false".to_owned()),
+            _ => None,
+        },
+        Type::Basic(Basic::Float | Basic::Double) => value
+            .parse::<f64>()
+            .ok()
+            .filter(|v| v.is_finite())
+            .map(|v| format!("{v:?}")),
+        Type::Basic(Basic::Utf8 | Basic::Filename | Basic::OsString) if value == "NULL" => {
+            Some("None".to_owned())
+        }
+        Type::Basic(Basic::Utf8 | Basic::Filename | Basic::OsString) => Some(format!("{value:?}")),
+        Type::Basic(basic) => integer_value(*basic, value),
+        Type::Enumeration(enum_) => {
+            let member = enum_
+                .members
+                .iter()
+                .find(|m| m.c_identifier == value || m.name == value || m.value == value)?;
+            let typ = RustType::try_new(env, typ).ok()?;
+            Some(format!(
+                "{}::{}",
+                typ.as_str(),
+                nameutil::enum_member_name(&member.name)
+            ))
+        }
+        Type::Bitfield(flags) => {
+            let typ = RustType::try_new(env, typ).ok()?;
+            if value == "0" {
+                return Some(format!("{}::empty()", typ.as_str()));
+            }
+            let members = value
+                .split('|')
+                .map(|v| {
+                    let v = v.trim();
+                    flags
+                        .members
+                        .iter()
+                        .find(|m| m.c_identifier == v || m.name == v || m.value == v)
+                        .map(|m| {
+                            format!(
+                                "{}::{}",
+                                typ.as_str(),
+                                nameutil::bitfield_member_name(&m.name)
+                            )
+                        })
+                })
+                .collect::<Option<Vec<_>>>()?;
+            Some(members.join(" | "))
+        }
+        _ if value == "NULL" => Some("None".to_owned()),
+        _ => None,
+    }
+}
+
+/// Parses an integer into the Rust type of `basic`, if it fits.
+fn integer_value(basic: library::Basic, value: &str) -> Option<String> {
+    use crate::library::Basic::{
+        Int, Int8, Int16, Int32, Int64, Long, SSize, Short, Size, UInt, UInt8, UInt16, UInt32,
+        UInt64, ULong, UShort,
+    };
+
+    fn parse<T: std::str::FromStr + ToString>(value: &str) -> Option<String> {
+        value.parse::<T>().ok().map(|v| v.to_string())
+    }
+
+    match basic {
+        Int8 => parse::<i8>(value),
+        UInt8 => parse::<u8>(value),
+        Int16 | Short => parse::<i16>(value),
+        UInt16 | UShort => parse::<u16>(value),
+        Int32 | Int => parse::<i32>(value),
+        UInt32 | UInt => parse::<u32>(value),
+        Int64 => parse::<i64>(value),
+        UInt64 => parse::<u64>(value),
+        // Only the range these have on every target
+        Long | SSize => parse::<i32>(value),
+        ULong | Size => parse::<u32>(value),
+        _ => None,
+    }
+}
+
+/// Documentation lines for the default value and the allowed range of a
+/// property, if known.
+pub fn value_doc(env: &Env, prop: &library::Property) -> Vec<String> {
+    let format = |value: &str| rust_value(env, prop.typ, value).unwrap_or_else(|| value.to_owned());
+    let mut lines = Vec::new();
+    if let Some(ref default_value) = prop.default_value {
+        lines.push(format!("Default value: `{}`", format(default_value)));
+    }
+    let range = match (&prop.minimum, &prop.maximum) {
+        (Some(min), Some(max)) => Some(format!("{}..={}", format(min), format(max))),
+        (Some(min), None) => Some(format!(">= {}", format(min))),
+        (None, Some(max)) => Some(format!("<= {}", format(max))),
+        (None, None) => None,
+    };
+    if let Some(range) = range {
+        lines.push(format!("Allowed values: `{range}`"));
+    }
+    lines
+}
+
 /// Returns (the list of get functions to check, the desired get function name).
 fn get_func_name(prop_name: &str, is_bool_getter: bool) -> (Vec<String>, String) {
     let get_rename_res = getter_rules::try_rename_getter_suffix(prop_name, is_bool_getter);
@@ -428,6 +760,42 @@ fn get_func_name(prop_name: &str, is_bool_getter: bool) -> (Vec<String>, String)
     }
 }
 
+/// Checks whether the C accessor annotated on a property replaces the
+/// property-based one, explaining why a property-based accessor is generated
+/// when it doesn't.
+fn has_accessor(
+    type_name: &str,
+    name: &str,
+    kind: &str,
+    annotated: Option<&str>,
+    func: Option<&crate::analysis::functions::Info>,
+) -> bool {
+    let Some(annotated) = annotated else {
+        return false;
+    };
+    match func {
+        Some(func) if func.status.manual() => {
+            info!(
+                "The {kind} of property \"{type_name}.{name}\" is provided by the manual {annotated}"
+            );
+            true
+        }
+        Some(func) if !func.commented => true,
+        Some(_) => {
+            info!(
+                "The {kind} {annotated} of property \"{type_name}.{name}\" is commented out, using a property-based {kind}"
+            );
+            false
+        }
+        None => {
+            info!(
+                "The {kind} {annotated} of property \"{type_name}.{name}\" isn't generated, using a property-based {kind}"
+            );
+            false
+        }
+    }
+}
+
 pub fn get_property_ref_modes(env: &Env, prop: &library::Property) -> (RefMode, RefMode, bool) {
     let get_out_ref_mode = RefMode::of(env, prop.typ, library::ParameterDirection::Return);
     let mut set_in_ref_mode = RefMode::of(env, prop.typ, library::ParameterDirection::In);
@@ -437,3 +805,67 @@ pub fn get_property_ref_modes(env: &Env, prop: &library::Property) -> (RefMode,
     let nullable = set_in_ref_mode.is_ref();
     (get_out_ref_mode, set_in_ref_mode, nullable)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::analysis::test_env::analyzed_env;
+
+    const GIR: &str = r#"
+    <enumeration name="Mode" c:type="TestMode" glib:type-name="TestMode" glib:get-type="test_mode_get_type">
+      <member name="fast" value="0" c:identifier="TEST_MODE_FAST"/>
+      <member name="slow" value="1" c:identifier="TEST_MODE_SLOW"/>
+    </enumeration>
+    <class name="Widget" c:type="TestWidget" parent="GObject.Object" glib:type-name="TestWidget" glib:get-type="test_widget_get_type" c:symbol-prefix="widget">
+      <property name="count" writable="1" readable="1" transfer-ownership="none" default-value="-3">
+        <type name="gint" c:type="gint"/>
+      </property>
+      <property name="size" writable="1" readable="1" transfer-ownership="none" default-value="-1">
+        <type name="guint" c:type="guint"/>
+      </property>
+      <property name="level" writable="1" readable="1" transfer-ownership="none" default-value="300">
+        <type name="gint8" c:type="gint8"/>
+      </property>
+      <property name="offset" writable="1" readable="1" transfer-ownership="none" default-value="18446744073709551615">
+        <type name="guint64" c:type="guint64"/>
+      </property>
+      <property name="length" writable="1" readable="1" transfer-ownership="none" default-value="4294967296">
+        <type name="gsize" c:type="gsize"/>
+      </property>
+      <property name="mode" writable="1" readable="1" transfer-ownership="none" default-value="TEST_MODE_SLOW">
+        <type name="Mode" c:type="TestMode"/>
+      </property>
+    </class>
+"#;
+
+    const CONFIG: &str = r#"
+[[object]]
+name = "Test.Mode"
+status = "generate"
+
+[[object]]
+name = "Test.Widget"
+status = "generate"
+    [[object.property]]
+    pattern = ".+"
+    generate_default_const = true
+"#;
+
+    #[test]
+    fn default_consts() {
+        let env = analyzed_env("default-consts", GIR, CONFIG);
+        let consts = env.analysis.objects["Test.Widget"]
+            .default_consts
+            .iter()
+            .map(|c| (c.name.as_str(), c.typ.as_str(), c.value.as_str()))
+            .collect::<Vec<_>>();
+        // The defaults of `size`, `level` and `length` don't fit in their types
+        assert_eq!(
+            consts,
+            [
+                ("DEFAULT_COUNT", "i32", "-3"),
+                ("DEFAULT_OFFSET", "u64", "18446744073709551615"),
+                ("DEFAULT_MODE", "Mode", "Mode::Slow"),
+            ]
+        );
+    }
+}
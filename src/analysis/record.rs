@@ -25,6 +25,8 @@ pub struct Info {
     pub init_function_expression: Option<String>,
     pub copy_into_function_expression: Option<String>,
     pub clear_function_expression: Option<String>,
+    /// `Default` implementation configured with `default_constructor`
+    pub default_impl: Option<default_impl::Info>,
 }
 
 impl Deref for Info {
@@ -195,6 +197,8 @@ pub fn new(env: &Env, obj: &GObject) -> Option<Info> {
         }
     }
 
+    let default_impl = default_impl::analyze(env, obj, record_tid, &functions);
+
     let base = InfoBase {
         full_name,
         type_id: record_tid,
@@ -218,6 +222,7 @@ pub fn new(env: &Env, obj: &GObject) -> Option<Info> {
         init_function_expression: obj.init_function_expression.clone(),
         copy_into_function_expression: obj.copy_into_function_expression.clone(),
         clear_function_expression: obj.clear_function_expression.clone(),
+        default_impl,
     };
 
     Some(info)
@@ -58,7 +58,11 @@ impl RefMode {
             | List(..)
             | SList(..)
             | PtrArray(..)
-            | CArray(..) => {
+            | CArray(..)
+            | FixedArray(..)
+            | Array(..)
+            | ByteArray
+            | HashTable(..) => {
                 if direction == library::ParameterDirection::In {
                     Self::ByRef
                 } else {
@@ -1,4 +1,6 @@
-use log::error;
+use log::{error, warn};
+
+use gir_parser::TransferOwnership;
 
 use crate::{
     analysis::{
@@ -17,6 +19,8 @@ pub struct Info {
     pub commented: bool,
     pub bool_return_is_error: Option<String>,
     pub nullable_return_is_error: Option<String>,
+    /// The returned object may be floating and is sunk before taking ownership.
+    pub floating: bool,
 }
 
 pub fn analyze(
@@ -135,12 +139,72 @@ pub fn analyze(
         par
     });
 
+    let floating = parameter
+        .as_ref()
+        .is_some_and(|par| is_floating(env, func, par, configured_functions));
+
     Info {
         parameter,
         base_tid,
         commented,
         bool_return_is_error: bool_return_error_message,
         nullable_return_is_error: nullable_return_error_message,
+        floating,
+    }
+}
+
+/// Whether `type_id` is a class derived from `GInitiallyUnowned`, whose
+/// instances start with a floating reference.
+fn is_initially_unowned(env: &Env, type_id: TypeId) -> bool {
+    env.library
+        .find_type(0, "GObject.InitiallyUnowned")
+        .is_some_and(|unowned_id| {
+            type_id == unowned_id
+                || env
+                    .class_hierarchy
+                    .supertypes(type_id)
+                    .contains(&unowned_id)
+        })
+}
+
+fn is_floating(
+    env: &Env,
+    func: &library::Function,
+    par: &analysis::Parameter,
+    configured_functions: &[&config::functions::Function],
+) -> bool {
+    let initially_unowned = is_initially_unowned(env, par.lib_par.typ());
+    let transfer = par.lib_par.transfer_ownership();
+    let is_constructor = func.kind == library::FunctionKind::Constructor;
+
+    match configured_functions.iter().find_map(|f| f.ret.floating) {
+        Some(true) if !initially_unowned => {
+            warn!(
+                "Ignoring floating configuration for {}, its return type isn't derived from GInitiallyUnowned",
+                func.c_identifier
+            );
+            false
+        }
+        Some(true) if transfer != TransferOwnership::None => {
+            warn!(
+                "Ignoring floating configuration for {}, its return value isn't transfer none",
+                func.c_identifier
+            );
+            false
+        }
+        Some(floating) => floating,
+        None if is_constructor && initially_unowned => {
+            if transfer == TransferOwnership::None {
+                true
+            } else {
+                warn!(
+                    "Constructor {} returns a floating reference but isn't annotated transfer none",
+                    func.c_identifier
+                );
+                false
+            }
+        }
+        None => false,
     }
 }
 
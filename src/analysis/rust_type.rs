@@ -1,8 +1,10 @@
 use std::{borrow::Borrow, result};
 
-use super::conversion_type::ConversionType;
+use super::conversion_type::{ConversionType, is_direct_fixed_array, is_scalar_array_element};
 use crate::{
-    analysis::{record_type::RecordType, ref_mode::RefMode, try_from_glib::TryFromGlib},
+    analysis::{
+        garray, hash_table, record_type::RecordType, ref_mode::RefMode, try_from_glib::TryFromGlib,
+    },
     config::functions::{CallbackParameter, CallbackParameters},
     env::Env,
     library::{self, ParameterDirection},
@@ -149,6 +151,28 @@ impl IntoString for RustType {
 
 pub type Result = result::Result<RustType, TypeError>;
 
+/// Rust type of the elements of C arrays of numbers.
+fn direct_array_element(fund: library::Basic) -> Option<&'static str> {
+    use crate::library::Basic::*;
+    match fund {
+        Int8 => Some("i8"),
+        UInt8 => Some("u8"),
+        Int16 => Some("i16"),
+        UInt16 => Some("u16"),
+        Int32 => Some("i32"),
+        UInt32 => Some("u32"),
+        Int64 => Some("i64"),
+        UInt64 => Some("u64"),
+
+        Int => Some("i32"),  // maybe dependent on target system
+        UInt => Some("u32"), // maybe dependent on target system
+
+        Float => Some("f32"),
+        Double => Some("f64"),
+        _ => Option::None,
+    }
+}
+
 fn into_inner(res: Result) -> String {
     use self::TypeError::*;
     match res {
@@ -386,40 +410,90 @@ impl<'env> RustTypeBuilder<'env> {
                         })
                     })
             }
+            Array(inner_tid) => {
+                skip_option = true;
+                match garray::element(self.env, inner_tid) {
+                    Some(element) => {
+                        let inner_ref_mode = if element.kind == garray::ElementKind::String {
+                            self.ref_mode
+                        } else {
+                            RefMode::None
+                        };
+                        RustType::builder(self.env, inner_tid)
+                            .ref_mode(inner_ref_mode)
+                            .try_build()
+                            .map_any(|rust_type| {
+                                rust_type.alter_type(|typ| {
+                                    if self.ref_mode.is_ref() {
+                                        format!("[{typ}]")
+                                    } else {
+                                        format!("Vec<{typ}>")
+                                    }
+                                })
+                            })
+                    }
+                    Option::None => Err(TypeError::Unimplemented(type_.get_name())),
+                }
+            }
+            ByteArray => {
+                let name = use_glib_type(self.env, "ByteArray");
+                Ok(RustType::new_and_use(&name))
+            }
+            HashTable(..) => {
+                skip_option = true;
+                // The conversion glue only exists for function parameters and return values
+                match hash_table::elements(self.env, self.type_id) {
+                    Some((key, value)) if self.direction != ParameterDirection::None => {
+                        // `HashMap` itself is imported by the function analysis
+                        let mut used_types = key.rust_type.used_types().clone();
+                        used_types.extend_from_slice(value.rust_type.used_types());
+                        Ok(RustType::new_with_uses(
+                            &format!(
+                                "HashMap<{}, {}>",
+                                key.rust_type.as_str(),
+                                value.rust_type.as_str()
+                            ),
+                            &used_types,
+                        ))
+                    }
+                    _ => Err(TypeError::Unimplemented(type_.get_name())),
+                }
+            }
+            CArray(inner_tid) if is_scalar_array_element(self.env, inner_tid) => {
+                skip_option = true;
+                RustType::try_new(self.env, inner_tid).map_any(|rust_type| {
+                    rust_type.alter_type(|typ| {
+                        if self.ref_mode.is_ref() {
+                            format!("[{typ}]")
+                        } else {
+                            format!("Vec<{typ}>")
+                        }
+                    })
+                })
+            }
             CArray(inner_tid)
                 if ConversionType::of(self.env, inner_tid) == ConversionType::Direct =>
             {
-                if let Basic(fund) = self.env.type_(inner_tid) {
-                    let array_type = match fund {
-                        Int8 => Some("i8"),
-                        UInt8 => Some("u8"),
-                        Int16 => Some("i16"),
-                        UInt16 => Some("u16"),
-                        Int32 => Some("i32"),
-                        UInt32 => Some("u32"),
-                        Int64 => Some("i64"),
-                        UInt64 => Some("u64"),
-
-                        Int => Some("i32"),  // maybe dependent on target system
-                        UInt => Some("u32"), // maybe dependent on target system
-
-                        Float => Some("f32"),
-                        Double => Some("f64"),
-                        _ => Option::None,
-                    };
-
-                    if let Some(s) = array_type {
+                match self.env.type_(inner_tid) {
+                    Basic(fund) if direct_array_element(*fund).is_some() => {
                         skip_option = true;
+                        let s = direct_array_element(*fund).unwrap();
                         if self.ref_mode.is_ref() {
                             Ok(format!("[{s}]").into())
                         } else {
                             Ok(format!("Vec<{s}>").into())
                         }
-                    } else {
-                        Err(TypeError::Unimplemented(type_.get_name()))
                     }
-                } else {
-                    Err(TypeError::Unimplemented(type_.get_name()))
+                    _ => Err(TypeError::Unimplemented(type_.get_name())),
+                }
+            }
+            FixedArray(inner_tid, size, _) if is_direct_fixed_array(self.env, self.type_id) => {
+                match self.env.type_(inner_tid) {
+                    Basic(fund) if direct_array_element(*fund).is_some() => {
+                        skip_option = true;
+                        Ok(format!("[{}; {size}]", direct_array_element(*fund).unwrap()).into())
+                    }
+                    _ => Err(TypeError::Unimplemented(type_.get_name())),
                 }
             }
             Custom(library::Custom { ref name, .. }) => {
@@ -657,6 +731,15 @@ impl<'env> RustTypeBuilder<'env> {
                 })
                 .map_any(|rust_type| rust_type.format_parameter(self.direction)),
 
+            // Generated union wrappers can only be converted from C
+            Union(..)
+                if self
+                    .env
+                    .type_status(&self.type_id.full_name(&self.env.library))
+                    .need_generate() =>
+            {
+                Err(TypeError::Unimplemented(into_inner(rust_type)))
+            }
             Enumeration(..) | Union(..) | Bitfield(..) => {
                 rust_type.map_any(|rust_type| rust_type.format_parameter(self.direction))
             }
@@ -680,16 +763,17 @@ impl<'env> RustTypeBuilder<'env> {
                 ParameterDirection::In | ParameterDirection::Return => rust_type,
                 _ => Err(TypeError::Unimplemented(into_inner(rust_type))),
             },
-            CArray(..) | PtrArray(..) => match self.direction {
-                ParameterDirection::In | ParameterDirection::Out | ParameterDirection::Return => {
-                    rust_type
+            Array(..) | ByteArray | CArray(..) | FixedArray(..) | PtrArray(..) | HashTable(..) => {
+                match self.direction {
+                    ParameterDirection::In
+                    | ParameterDirection::Out
+                    | ParameterDirection::Return => rust_type,
+                    _ => Err(TypeError::Unimplemented(into_inner(rust_type))),
                 }
-                _ => Err(TypeError::Unimplemented(into_inner(rust_type))),
-            },
+            }
             Function(func) if func.name == "AsyncReadyCallback" => Ok("AsyncReadyCallback".into()),
             Function(_) => rust_type,
             Custom(..) => rust_type.map(|rust_type| rust_type.format_parameter(self.direction)),
-            _ => Err(TypeError::Unimplemented(type_.get_name())),
         }
     }
 }
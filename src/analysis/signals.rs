@@ -1,3 +1,5 @@
+use log::warn;
+
 use super::{imports::Imports, trampolines};
 use crate::{
     analysis::trampolines::Trampoline,
@@ -11,6 +13,10 @@ use crate::{
 #[derive(Debug)]
 pub struct Info {
     pub connect_name: String,
+    /// Name of the method returning the signal emissions as a `Stream`
+    pub stream_name: Option<String>,
+    /// Name of the variant of `connect_name` holding a target object weakly
+    pub weak_connect_name: Option<String>,
     pub signal_name: String,
     pub action_emit_name: Option<String>,
     pub trampoline: Result<Trampoline, Vec<String>>,
@@ -84,6 +90,8 @@ fn analyze_signal(
     imports.add("glib::translate::*");
 
     let connect_name = format!("connect_{}", nameutil::signal_to_snake(&signal.name));
+    let stream_name = format!("{}_stream", nameutil::signal_to_snake(&signal.name));
+    let weak_connect_name = format!("{connect_name}_weak");
     let trampoline = trampolines::analyze(
         env,
         signal,
@@ -111,9 +119,29 @@ fn analyze_signal(
         imports.add("std::boxed::Box as Box_");
     }
     let generate_doc = configured_signals.iter().all(|f| f.generate_doc);
+    let stream_name = analyze_stream(
+        stream_name,
+        &signal.name,
+        &trampoline,
+        is_fundamental,
+        configured_signals,
+        obj,
+        imports,
+    );
+    let weak_connect_name = analyze_weak(
+        weak_connect_name,
+        &signal.name,
+        &trampoline,
+        is_fundamental,
+        configured_signals,
+        obj,
+        imports,
+    );
 
     Info {
         connect_name,
+        stream_name,
+        weak_connect_name,
         signal_name: signal.name.clone(),
         trampoline,
         action_emit_name,
@@ -125,3 +153,94 @@ fn analyze_signal(
         cfg_condition,
     }
 }
+
+/// Checks whether a `Stream` adapter was requested for the signal and whether
+/// its emissions can be sent through one.
+pub fn analyze_stream(
+    stream_name: String,
+    signal_name: &str,
+    trampoline: &Result<Trampoline, Vec<String>>,
+    is_fundamental: bool,
+    configured_signals: &[&config::signals::Signal],
+    obj: &GObject,
+    imports: &mut Imports,
+) -> Option<String> {
+    let configured = configured_signals.iter().find_map(|s| s.stream);
+    if !configured.unwrap_or(obj.generate_signal_streams) {
+        return None;
+    }
+    let trampoline = trampoline.as_ref().ok()?;
+
+    if let Some(reason) = unsupported_reason(trampoline, is_fundamental) {
+        // Only complain about signals explicitly configured as streams
+        if configured.is_some() {
+            warn!(
+                "Signal {} of {} {reason} and can't be turned into a stream",
+                signal_name, obj.name
+            );
+        }
+        return None;
+    }
+
+    imports.add("super::signal_stream::SignalStream");
+    Some(stream_name)
+}
+
+/// Checks whether a weakly connecting variant was requested for the signal and
+/// whether the handler can be skipped once the target is gone.
+pub fn analyze_weak(
+    weak_connect_name: String,
+    signal_name: &str,
+    trampoline: &Result<Trampoline, Vec<String>>,
+    is_fundamental: bool,
+    configured_signals: &[&config::signals::Signal],
+    obj: &GObject,
+    imports: &mut Imports,
+) -> Option<String> {
+    let configured = configured_signals.iter().find_map(|s| s.weak);
+    if !configured.unwrap_or(obj.generate_weak_signals) {
+        return None;
+    }
+    let trampoline = trampoline.as_ref().ok()?;
+
+    if let Some(reason) = unsupported_reason(trampoline, is_fundamental) {
+        // Only complain about signals explicitly configured as weak
+        if configured.is_some() {
+            warn!(
+                "Signal {} of {} {reason} and can't be connected weakly",
+                signal_name, obj.name
+            );
+        }
+        return None;
+    }
+
+    imports.add("super::signal_weak::connect_weak");
+    Some(weak_connect_name)
+}
+
+/// Signals whose handlers are wrapped by the generated code must not return
+/// anything nor take generic arguments.
+fn unsupported_reason(trampoline: &Trampoline, is_fundamental: bool) -> Option<&'static str> {
+    if is_fundamental {
+        Some("is on a fundamental type")
+    } else if trampoline.ret.typ() != Default::default() {
+        Some("returns a value")
+    } else if trampoline
+        .parameters
+        .rust_parameters
+        .iter()
+        .skip(1)
+        .any(|par| trampoline.bounds.get_parameter_bound(&par.name).is_some())
+    {
+        Some("has generic parameters")
+    } else if trampoline
+        .parameters
+        .rust_parameters
+        .iter()
+        .any(|par| par.direction.is_out())
+    {
+        Some("has out parameters")
+    } else {
+        None
+    }
+}
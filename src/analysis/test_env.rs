@@ -0,0 +1,144 @@
+//! Analysis of small GIR files in unit tests.
+
+use std::{cell::RefCell, path::Path};
+
+use crate::{
+    analysis::{self, class_hierarchy, namespaces, symbols},
+    config::Config,
+    env::Env,
+    library::Library,
+};
+
+const HEADER: &str = r#"<?xml version="1.0"?>
+<repository version="1.2" xmlns="http://www.gtk.org/introspection/core/1.0" xmlns:c="http://www.gtk.org/introspection/c/1.0" xmlns:glib="http://www.gtk.org/introspection/glib/1.0">"#;
+
+const GLIB: &str = r#"
+  <namespace name="GLib" version="2.0" shared-library="libglib-2.0.so.0" c:identifier-prefixes="G" c:symbol-prefixes="g">
+    <record name="Error" c:type="GError" glib:type-name="GError" glib:get-type="g_error_get_type" c:symbol-prefix="error">
+      <field name="domain" writable="1"><type name="guint32" c:type="GQuark"/></field>
+      <field name="code" writable="1"><type name="gint" c:type="gint"/></field>
+      <field name="message" writable="1"><type name="utf8" c:type="gchar*"/></field>
+    </record>
+    <callback name="DestroyNotify" c:type="GDestroyNotify">
+      <return-value transfer-ownership="none"><type name="none" c:type="void"/></return-value>
+      <parameters>
+        <parameter name="data" transfer-ownership="none" nullable="1" allow-none="1"><type name="gpointer" c:type="gpointer"/></parameter>
+      </parameters>
+    </callback>
+  </namespace>
+</repository>
+"#;
+
+const GOBJECT: &str = r#"
+  <include name="GLib" version="2.0"/>
+  <namespace name="GObject" version="2.0" shared-library="libgobject-2.0.so.0" c:identifier-prefixes="G" c:symbol-prefixes="g">
+    <class name="Object" c:type="GObject" glib:type-name="GObject" glib:get-type="g_object_get_type" c:symbol-prefix="object">
+    </class>
+    <class name="ParamSpec" c:type="GParamSpec" glib:type-name="GParam" glib:get-type="intern" glib:fundamental="1" c:symbol-prefix="param_spec">
+    </class>
+    <bitfield name="ParamFlags" c:type="GParamFlags">
+      <member name="readable" value="1" c:identifier="G_PARAM_READABLE"/>
+      <member name="writable" value="2" c:identifier="G_PARAM_WRITABLE"/>
+    </bitfield>
+  </namespace>
+</repository>
+"#;
+
+const GIO: &str = r#"
+  <include name="GObject" version="2.0"/>
+  <namespace name="Gio" version="2.0" shared-library="libgio-2.0.so.0" c:identifier-prefixes="G" c:symbol-prefixes="g">
+    <class name="Cancellable" c:type="GCancellable" parent="GObject.Object" glib:type-name="GCancellable" glib:get-type="g_cancellable_get_type" c:symbol-prefix="cancellable">
+    </class>
+    <interface name="AsyncResult" c:type="GAsyncResult" glib:type-name="GAsyncResult" glib:get-type="g_async_result_get_type" c:symbol-prefix="async_result">
+    </interface>
+    <callback name="AsyncReadyCallback" c:type="GAsyncReadyCallback">
+      <return-value transfer-ownership="none"><type name="none" c:type="void"/></return-value>
+      <parameters>
+        <parameter name="source_object" transfer-ownership="none" nullable="1" allow-none="1"><type name="GObject.Object" c:type="GObject*"/></parameter>
+        <parameter name="res" transfer-ownership="none"><type name="AsyncResult" c:type="GAsyncResult*"/></parameter>
+        <parameter name="data" transfer-ownership="none" nullable="1" allow-none="1" closure="2"><type name="gpointer" c:type="gpointer"/></parameter>
+      </parameters>
+    </callback>
+  </namespace>
+</repository>
+"#;
+
+/// Analyzes the `Test` namespace containing `gir`, which can use minimal
+/// `GLib`, `GObject` and `Gio` namespaces, with `config` appended to the
+/// options of the configuration.
+///
+/// `name` has to be unique among the tests running concurrently.
+pub fn analyzed_env(name: &str, gir: &str, config: &str) -> Env {
+    let dir = std::env::temp_dir().join(format!("gir-test-{name}-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let write = |file: &str, content: &str| {
+        std::fs::write(dir.join(file), format!("{HEADER}{content}")).unwrap();
+    };
+    write("GLib-2.0.gir", GLIB);
+    write("GObject-2.0.gir", GOBJECT);
+    write("Gio-2.0.gir", GIO);
+    write(
+        "Test-1.0.gir",
+        &format!(
+            r#"
+  <include name="Gio" version="2.0"/>
+  <namespace name="Test" version="1.0" shared-library="libtest.so" c:identifier-prefixes="Test" c:symbol-prefixes="test">
+{gir}
+  </namespace>
+</repository>
+"#
+        ),
+    );
+    let config_file = dir.join("Gir.toml");
+    std::fs::write(
+        &config_file,
+        format!(
+            "[options]\nwork_mode = \"normal\"\nlibrary = \"Test\"\nversion = \"1.0\"\n\
+             target_path = \".\"\ngirs_directories = [\".\"]\ngenerate = [\"Test.*\"]\n\
+             manual = [\"GLib.Error\", \"GObject.Object\", \"Gio.Cancellable\", \
+             \"Gio.AsyncResult\"]\n{config}"
+        ),
+    )
+    .unwrap();
+    let env = analyze(&config_file);
+    std::fs::remove_dir_all(&dir).unwrap();
+    env
+}
+
+fn analyze(config_file: &Path) -> Env {
+    let mut config = Config::new(
+        config_file.to_str(),
+        None,
+        &[],
+        None,
+        None,
+        None,
+        None,
+        false,
+        false,
+        false,
+        false,
+    )
+    .unwrap();
+    let mut library = Library::new("Test");
+    library
+        .read_file(&config.girs_dirs, &mut vec![config.library_full_name()])
+        .unwrap();
+    library.preprocessing(config.work_mode);
+    library.postprocessing(&config);
+    config.resolve_type_ids(&library);
+
+    let namespaces = namespaces::run(&library);
+    let symbols = symbols::run(&library, &namespaces);
+    let class_hierarchy = class_hierarchy::run(&library);
+    let mut env = Env {
+        library,
+        config,
+        namespaces,
+        symbols: RefCell::new(symbols),
+        class_hierarchy,
+        analysis: Default::default(),
+    };
+    analysis::run(&mut env);
+    env
+}
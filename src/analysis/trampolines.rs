@@ -41,6 +41,9 @@ pub struct Trampoline {
     /// This field is used to give the type name when generating the "IsA<X>"
     /// part.
     pub type_name: String,
+    /// Callback type whose shared trait and trampoline are used instead of
+    /// generating an inline trampoline.
+    pub shared_callback: Option<library::TypeId>,
 }
 
 pub type Trampolines = Vec<Trampoline>;
@@ -204,6 +207,7 @@ pub fn analyze(
         destroy_index: 0,
         nullable: false,
         type_name: env.library.type_(type_tid).get_name(),
+        shared_callback: None,
     };
     Ok(trampoline)
 }
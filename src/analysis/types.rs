@@ -156,6 +156,7 @@ impl IsIncomplete for Type {
             | Type::Enumeration(..)
             | Type::Bitfield(..)
             | Type::Array(..)
+            | Type::ByteArray
             | Type::CArray(..)
             | Type::PtrArray(..)
             | Type::HashTable(..)
@@ -212,6 +213,7 @@ impl IsExternal for Type {
             | Type::Bitfield(..)
             | Type::Function(..)
             | Type::Array(..)
+            | Type::ByteArray
             | Type::CArray(..)
             | Type::FixedArray(..)
             | Type::PtrArray(..)
@@ -0,0 +1,304 @@
+use log::{info, warn};
+
+use super::{
+    conversion_type::{ConversionType, is_direct_fixed_array},
+    ffi_type::ffi_type,
+    imports::Imports,
+    rust_type::RustType,
+};
+use crate::{
+    codegen::Visibility,
+    config::{gobjects::GObject, union_variants::UnionVariant},
+    env::Env,
+    library::{self, Basic, Type},
+    nameutil::*,
+    traits::*,
+};
+
+/// How a union member is read into the payload of its variant.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PayloadConversion {
+    /// Copied as is.
+    Direct,
+    /// Converted with `from_glib`.
+    Scalar,
+    /// Pointer member converted with `from_glib_none`, `None` if it is `NULL`.
+    Pointer,
+    /// Struct stored inline in the union, copied with `from_glib_none`.
+    Inline,
+}
+
+#[derive(Debug)]
+pub struct Payload {
+    /// Name of the union member in the FFI union.
+    pub field: String,
+    pub rust_type: String,
+    pub conversion: PayloadConversion,
+    /// FFI type of the member, used to take a pointer to inline structs.
+    pub ffi_type: String,
+    pub doc: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct Variant {
+    pub name: String,
+    /// Pattern matching the raw discriminant value.
+    pub pattern: String,
+    /// Value of the discriminant in the Rust discriminant type.
+    pub discriminant: String,
+    /// C identifier of the discriminant value, for enumeration discriminants.
+    pub c_identifier: Option<String>,
+    pub payload: Option<Payload>,
+}
+
+#[derive(Debug)]
+pub struct Info {
+    pub full_name: String,
+    pub type_id: library::TypeId,
+    pub name: String,
+    /// Name of the discriminant member in the FFI union.
+    pub discriminant_field: String,
+    pub discriminant_type: String,
+    pub discriminant_conversion: ConversionType,
+    pub variants: Vec<Variant>,
+    pub visibility: Visibility,
+}
+
+impl Info {
+    pub fn type_<'a>(&self, library: &'a library::Library) -> &'a library::Union {
+        (library
+            .type_(self.type_id)
+            .maybe_ref()
+            .unwrap_or_else(|| panic!("{} is not a union.", self.full_name))) as _
+    }
+}
+
+fn find_field<'a>(union: &'a library::Union, name: &str) -> Option<&'a library::Field> {
+    let mangled = mangle_keywords(name);
+    union
+        .fields
+        .iter()
+        .find(|f| f.name == name || f.name == mangled)
+}
+
+pub fn new(env: &Env, obj: &GObject, imports: &mut Imports) -> Option<Info> {
+    info!("Analyzing union {}", obj.name);
+
+    if !obj.status.need_generate() {
+        return None;
+    }
+
+    let union_tid = env.library.find_type(0, &obj.name)?;
+    let union: &library::Union = env.type_(union_tid).maybe_ref()?;
+    let name = split_namespace_name(&obj.name).1;
+
+    let Some(discriminant) = obj.discriminant.as_ref() else {
+        warn!(
+            "Union {} can't be generated without a `discriminant` configuration",
+            obj.name
+        );
+        return None;
+    };
+    let Some(discriminant_field) = find_field(union, discriminant) else {
+        warn!(
+            "Union {} has no discriminant member {discriminant}",
+            obj.name
+        );
+        return None;
+    };
+
+    let discriminant_conversion = ConversionType::of(env, discriminant_field.typ);
+    let discriminant_type = match env.type_(discriminant_field.typ) {
+        Type::Enumeration(_) => RustType::try_new(env, discriminant_field.typ).ok(),
+        Type::Basic(basic) if basic.is_numeric() && !is_float(*basic) => {
+            RustType::try_new(env, discriminant_field.typ).ok()
+        }
+        _ => None,
+    };
+    let Some(discriminant_type) = discriminant_type else {
+        warn!(
+            "Discriminant {discriminant} of union {} must be an enumeration or an integer",
+            obj.name
+        );
+        return None;
+    };
+
+    let mut variants = Vec::with_capacity(obj.variants.len());
+    for variant in &obj.variants {
+        if let Some(variant) = analyze_variant(
+            env,
+            obj,
+            union,
+            discriminant_field,
+            &discriminant_type,
+            variant,
+            imports,
+        ) {
+            variants.push(variant);
+        }
+    }
+
+    imports.add_used_types(discriminant_type.used_types());
+    imports.add("glib::translate::*");
+    imports.add("crate::ffi");
+
+    Some(Info {
+        full_name: obj.name.clone(),
+        type_id: union_tid,
+        name: name.to_owned(),
+        discriminant_field: discriminant_field.name.clone(),
+        discriminant_type: discriminant_type.into_string(),
+        discriminant_conversion,
+        variants,
+        visibility: obj.visibility,
+    })
+}
+
+fn is_float(basic: Basic) -> bool {
+    matches!(basic, Basic::Float | Basic::Double)
+}
+
+fn analyze_variant(
+    env: &Env,
+    obj: &GObject,
+    union: &library::Union,
+    discriminant_field: &library::Field,
+    discriminant_type: &RustType,
+    variant: &UnionVariant,
+    imports: &mut Imports,
+) -> Option<Variant> {
+    let (pattern, discriminant, c_identifier, default_name) =
+        match env.type_(discriminant_field.typ) {
+            Type::Enumeration(enum_) => {
+                let Some(member) = enum_
+                    .members
+                    .iter()
+                    .find(|m| m.name == variant.value || m.c_identifier == variant.value)
+                else {
+                    warn!(
+                        "Union {} variant value {} is not a member of {}",
+                        obj.name, variant.value, enum_.name
+                    );
+                    return None;
+                };
+                (
+                    format!(
+                        "{}::{}",
+                        env.sys_crate_import(discriminant_field.typ),
+                        member.c_identifier
+                    ),
+                    format!(
+                        "{}::{}",
+                        discriminant_type.as_str(),
+                        enum_member_name(&member.name)
+                    ),
+                    Some(member.c_identifier.clone()),
+                    Some(enum_member_name(&member.name)),
+                )
+            }
+            _ => {
+                if variant.value.parse::<i64>().is_err() {
+                    warn!(
+                        "Union {} variant value {} is not an integer",
+                        obj.name, variant.value
+                    );
+                    return None;
+                }
+                (variant.value.clone(), variant.value.clone(), None, None)
+            }
+        };
+
+    let payload = match variant.member {
+        Some(ref member) => {
+            let Some(field) = find_field(union, member) else {
+                warn!("Union {} has no member {member}", obj.name);
+                return None;
+            };
+            Some(analyze_payload(env, obj, field, imports)?)
+        }
+        None => None,
+    };
+
+    let name = variant
+        .name
+        .clone()
+        .or(default_name)
+        .or_else(|| variant.member.as_deref().map(enum_member_name));
+    let Some(name) = name else {
+        warn!(
+            "Union {} variant for value {} needs a `name`",
+            obj.name, variant.value
+        );
+        return None;
+    };
+
+    Some(Variant {
+        name,
+        pattern,
+        discriminant,
+        c_identifier,
+        payload,
+    })
+}
+
+fn analyze_payload(
+    env: &Env,
+    obj: &GObject,
+    field: &library::Field,
+    imports: &mut Imports,
+) -> Option<Payload> {
+    let rust_type = match RustType::try_new(env, field.typ) {
+        Ok(rust_type) => rust_type,
+        err => {
+            warn!(
+                "Union {} member {} has unsupported type {}",
+                obj.name,
+                field.name,
+                err.into_string()
+            );
+            return None;
+        }
+    };
+    let c_type = field.c_type.as_deref().unwrap_or_default();
+    let is_pointer = c_type.ends_with('*');
+
+    let conversion = match (ConversionType::of(env, field.typ), env.type_(field.typ)) {
+        (ConversionType::Direct, _) if !is_pointer => PayloadConversion::Direct,
+        (ConversionType::Scalar, _) if !is_pointer => PayloadConversion::Scalar,
+        (ConversionType::Pointer, Type::FixedArray(..))
+            if !is_pointer && is_direct_fixed_array(env, field.typ) =>
+        {
+            PayloadConversion::Direct
+        }
+        (ConversionType::Pointer, Type::Record(_)) if !is_pointer => PayloadConversion::Inline,
+        (
+            ConversionType::Pointer,
+            Type::Basic(Basic::Utf8 | Basic::Filename | Basic::OsString)
+            | Type::Record(_)
+            | Type::Class(_)
+            | Type::Interface(_),
+        ) if is_pointer => PayloadConversion::Pointer,
+        _ => {
+            warn!(
+                "Union {} member {} can't be converted to {}",
+                obj.name,
+                field.name,
+                rust_type.as_str()
+            );
+            return None;
+        }
+    };
+    imports.add_used_types(rust_type.used_types());
+    let ffi_type = match conversion {
+        PayloadConversion::Inline => ffi_type(env, field.typ, c_type).ok()?.into_string(),
+        _ => String::new(),
+    };
+
+    Some(Payload {
+        field: field.name.clone(),
+        rust_type: rust_type.into_string(),
+        conversion,
+        ffi_type,
+        doc: field.doc.clone(),
+    })
+}
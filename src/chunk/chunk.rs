@@ -1,7 +1,10 @@
 use super::{conversion_from_glib, parameter_ffi_call_out};
-use crate::analysis::{
-    function_parameters::TransformationType, return_value,
-    safety_assertion_mode::SafetyAssertionMode,
+use crate::{
+    analysis::{
+        function_parameters::TransformationType, return_value,
+        safety_assertion_mode::SafetyAssertionMode,
+    },
+    codegen::Visibility,
 };
 
 #[derive(Clone, Debug)]
@@ -68,6 +71,7 @@ pub enum Chunk {
     Name(String),
     ExternCFunc {
         name: String,
+        visibility: Visibility,
         parameters: Vec<Param>,
         body: Box<Chunk>,
         return_value: Option<String>,
@@ -0,0 +1,117 @@
+use std::{
+    io::{Result, prelude::*},
+    path::Path,
+};
+
+use crate::{
+    analysis::callbacks::{self, Info},
+    codegen::{
+        Visibility,
+        function_body_chunk::trampoline_func,
+        general::{self, doc_alias, version_condition},
+    },
+    env::Env,
+    file_saver, library,
+    nameutil::use_glib_if_needed,
+    traits::*,
+    writer::to_code::ToCode,
+};
+
+pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
+    if env.analysis.callbacks.is_empty() {
+        return;
+    }
+
+    let path = root_path.join("callbacks.rs");
+    file_saver::save_to_file(path, env.config.make_backup, |w| {
+        general::start_comments(w, &env.config)?;
+        general::uses(w, env, &env.analysis.callback_imports, None)?;
+
+        mod_rs.push("\nmod callbacks;".into());
+        for callback in &env.analysis.callbacks {
+            mod_rs.push(format!("pub use self::callbacks::{};", callback.name));
+            writeln!(w)?;
+            generate_callback(env, w, callback)?;
+        }
+
+        Ok(())
+    });
+}
+
+fn generate_callback(env: &Env, w: &mut dyn Write, callback: &Info) -> Result<()> {
+    let func: &library::Function = env.type_(callback.type_id).to_ref_as();
+    let version = func.version;
+
+    version_condition(w, env, None, version, false, 0)?;
+    doc_alias(w, &func.c_identifier, "", 0)?;
+    writeln!(
+        w,
+        "pub trait {}: {} {{}}",
+        callback.name, callback.signature
+    )?;
+    writeln!(w)?;
+    version_condition(w, env, None, version, false, 0)?;
+    writeln!(
+        w,
+        "impl<F: {}> {} for F {{}}",
+        callback.signature, callback.name
+    )?;
+    writeln!(w)?;
+
+    let bounds = format!("<P: {}>", callback.name);
+    let trampoline = trampoline_func(
+        env,
+        &callback.trampoline,
+        &None,
+        None,
+        &callbacks::trampoline_name(env, callback.type_id),
+        &bounds,
+        Visibility::Super,
+        false,
+    );
+    version_condition(w, env, None, version, false, 0)?;
+    for s in trampoline.to_code(env) {
+        writeln!(w, "{s}")?;
+    }
+    writeln!(w)?;
+
+    version_condition(w, env, None, version, false, 0)?;
+    writeln!(
+        w,
+        "pub(super) unsafe extern \"C\" fn {}{bounds}(data: {}) {{
+    unsafe {{
+        let _callback = Box_::from_raw(data as *mut P);
+    }}
+}}",
+        callbacks::destroy_name(env, callback.type_id),
+        use_glib_if_needed(env, "ffi::gpointer"),
+    )?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::callbacks::tests::analyzed_env;
+
+    #[test]
+    fn shared_callback() {
+        let env = analyzed_env("codegen", "shared_callbacks = true\n");
+        let mut w = Vec::new();
+        generate_callback(&env, &mut w, &env.analysis.callbacks[0]).unwrap();
+        let code = String::from_utf8(w).unwrap();
+        assert!(code.contains(
+            "#[doc(alias = \"TestTickCallback\")]\npub trait TickCallback: Fn(i64) -> bool {}"
+        ));
+        assert!(code.contains("impl<F: Fn(i64) -> bool> TickCallback for F {}"));
+        assert!(code.contains(
+            "pub(super) unsafe extern \"C\" fn tick_callback_trampoline<P: TickCallback>\
+             (time: i64, user_data: glib::ffi::gpointer) -> glib::ffi::gboolean {"
+        ));
+        assert!(code.contains(
+            "pub(super) unsafe extern \"C\" fn tick_callback_destroy<P: TickCallback>\
+             (data: glib::ffi::gpointer) {"
+        ));
+    }
+}
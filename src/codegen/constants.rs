@@ -1,12 +1,15 @@
 use std::path::Path;
 
+use log::warn;
+
 use crate::{
-    analysis::imports::Imports,
+    analysis::{self, imports::Imports, properties::rust_value, rust_type::RustType},
     codegen::general::{
         self, cfg_condition, cfg_deprecated, doc_alias, version_condition, version_condition_string,
     },
     env::Env,
     file_saver, library,
+    traits::*,
 };
 
 pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
@@ -18,7 +21,29 @@ pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
     }
 
     let sys_crate_name = env.main_sys_crate_name();
-    imports.add("glib::GStr");
+    let mut constants = Vec::with_capacity(env.analysis.constants.len());
+    for constant in &env.analysis.constants {
+        if let library::Type::Basic(library::Basic::Utf8) = env.type_(constant.typ) {
+            imports.add("glib::GStr");
+            constants.push((constant, None));
+        } else if let Some((typ, value)) = typed_value(env, constant, sys_crate_name) {
+            if let Ok(rust_type) = RustType::try_new(env, constant.typ) {
+                imports
+                    .with_defaults(constant.version, &constant.cfg_condition)
+                    .add_used_types(rust_type.used_types());
+            }
+            constants.push((constant, Some((typ, value))));
+        } else {
+            warn!(
+                "Can't generate constant {} of type {}",
+                constant.glib_name,
+                constant.typ.full_name(&env.library)
+            );
+        }
+    }
+    if constants.is_empty() {
+        return;
+    }
     imports.add("crate::ffi");
 
     file_saver::save_to_file(path, env.config.make_backup, |w| {
@@ -28,13 +53,14 @@ pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
 
         mod_rs.push("\nmod constants;".into());
 
-        for constant in &env.analysis.constants {
-            let type_ = env.type_(constant.typ);
-            if let library::Type::Basic(library::Basic::Utf8) = type_ {
-                cfg_deprecated(w, env, None, constant.deprecated_version, false, 0)?;
-                cfg_condition(w, constant.cfg_condition.as_ref(), false, 0)?;
-                version_condition(w, env, None, constant.version, false, 0)?;
-                doc_alias(w, &constant.glib_name, "", 0)?;
+        for (constant, typed_value) in &constants {
+            cfg_deprecated(w, env, None, constant.deprecated_version, false, 0)?;
+            cfg_condition(w, constant.cfg_condition.as_ref(), false, 0)?;
+            version_condition(w, env, None, constant.version, false, 0)?;
+            doc_alias(w, &constant.glib_name, "", 0)?;
+            if let Some((typ, value)) = typed_value {
+                writeln!(w, "pub const {}: {typ} = {value};", constant.name)?;
+            } else {
                 writeln!(
                     w,
                     "pub static {name}: &GStr = unsafe{{GStr::from_utf8_with_nul_unchecked({sys_crate_name}::{c_id})}};",
@@ -42,20 +68,199 @@ pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
                     name = constant.name,
                     c_id = constant.glib_name
                 )?;
-                if let Some(cfg) = version_condition_string(env, None, constant.version, false, 0) {
-                    mod_rs.push(cfg);
-                }
-                mod_rs.push(format!(
-                    "{}pub use self::constants::{};",
-                    constant
-                        .deprecated_version
-                        .map(|_| "#[allow(deprecated)]\n")
-                        .unwrap_or(""),
-                    constant.name
-                ));
             }
+            if let Some(cfg) = version_condition_string(env, None, constant.version, false, 0) {
+                mod_rs.push(cfg);
+            }
+            mod_rs.push(format!(
+                "{}pub use self::constants::{};",
+                constant
+                    .deprecated_version
+                    .map(|_| "#[allow(deprecated)]\n")
+                    .unwrap_or(""),
+                constant.name
+            ));
         }
 
         Ok(())
     });
 }
+
+/// Returns the Rust type and the value expression of a non-string constant
+fn typed_value(
+    env: &Env,
+    constant: &analysis::constants::Info,
+    sys_crate_name: &str,
+) -> Option<(String, String)> {
+    use crate::library::{Basic, Type};
+
+    let typ = RustType::try_new(env, constant.typ).ok()?.into_string();
+    // Aliases have the Rust type of the aliased type
+    let mut type_ = env.type_(constant.typ);
+    while let Type::Alias(alias) = type_ {
+        type_ = env.type_(alias.typ);
+    }
+    let value = match type_ {
+        Type::Basic(Basic::Boolean | Basic::Bool) => (constant.value == "true").to_string(),
+        Type::Basic(Basic::UniChar) => {
+            let c = char::from_u32(constant.value.parse().ok()?)?;
+            format!("'{}'", c.escape_default())
+        }
+        Type::Basic(
+            Basic::Int8
+            | Basic::UInt8
+            | Basic::Int16
+            | Basic::UInt16
+            | Basic::Int32
+            | Basic::UInt32
+            | Basic::Int64
+            | Basic::UInt64
+            | Basic::Int
+            | Basic::UInt
+            | Basic::Short
+            | Basic::UShort
+            | Basic::Long
+            | Basic::ULong
+            | Basic::Size
+            | Basic::SSize
+            | Basic::Float
+            | Basic::Double,
+        ) => format!("{sys_crate_name}::{}", constant.glib_name),
+        Type::Enumeration(_) => rust_value(env, constant.typ, &constant.value)?,
+        Type::Bitfield(_) => format!(
+            "{typ}::from_bits_retain({sys_crate_name}::{})",
+            constant.glib_name
+        ),
+        _ => return None,
+    };
+    Some((typ, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::test_env::analyzed_env;
+
+    const GIR: &str = r#"
+    <alias name="Codepoint" c:type="TestCodepoint">
+      <type name="gunichar" c:type="gunichar"/>
+    </alias>
+    <alias name="Size" c:type="TestSize">
+      <type name="gsize" c:type="gsize"/>
+    </alias>
+    <alias name="Switch" c:type="TestSwitch">
+      <type name="gboolean" c:type="gboolean"/>
+    </alias>
+    <enumeration name="Mode" c:type="TestMode" glib:type-name="TestMode" glib:get-type="test_mode_get_type">
+      <member name="fast" value="0" c:identifier="TEST_MODE_FAST"/>
+      <member name="slow" value="1" c:identifier="TEST_MODE_SLOW"/>
+    </enumeration>
+    <constant name="MAX_SIZE" value="42" c:type="TEST_MAX_SIZE">
+      <type name="gint" c:type="gint"/>
+    </constant>
+    <constant name="SCALE" value="1.5" c:type="TEST_SCALE">
+      <type name="gdouble" c:type="gdouble"/>
+    </constant>
+    <constant name="ENABLED" value="true" c:type="TEST_ENABLED">
+      <type name="gboolean" c:type="gboolean"/>
+    </constant>
+    <constant name="SEPARATOR" value="47" c:type="TEST_SEPARATOR">
+      <type name="gunichar" c:type="gunichar"/>
+    </constant>
+    <constant name="DEFAULT_MODE" value="1" c:type="TEST_DEFAULT_MODE">
+      <type name="Mode" c:type="TestMode"/>
+    </constant>
+    <constant name="REPLACEMENT" value="65533" c:type="TEST_REPLACEMENT">
+      <type name="Codepoint" c:type="TestCodepoint"/>
+    </constant>
+    <constant name="BLOCK_SIZE" value="4096" c:type="TEST_BLOCK_SIZE">
+      <type name="Size" c:type="TestSize"/>
+    </constant>
+    <constant name="ON" value="true" c:type="TEST_ON">
+      <type name="Switch" c:type="TestSwitch"/>
+    </constant>
+    <constant name="CONFIG_FILE" value="/etc/test.conf" c:type="TEST_CONFIG_FILE">
+      <type name="filename" c:type="gchar*"/>
+    </constant>
+    <constant name="NOTHING" value="0" c:type="TEST_NOTHING">
+      <type name="gpointer" c:type="gpointer"/>
+    </constant>
+    <constant name="SMALL" value="1" c:type="TEST_SMALL">
+      <type name="gchar" c:type="gchar"/>
+    </constant>
+"#;
+
+    const CONFIG: &str = r#"
+[[object]]
+name = "Test.Mode"
+status = "generate"
+
+[[object]]
+name = "Test.Codepoint"
+status = "generate"
+
+[[object]]
+name = "Test.Size"
+status = "generate"
+
+[[object]]
+name = "Test.Switch"
+status = "generate"
+"#;
+
+    #[test]
+    fn typed_values() {
+        let env = analyzed_env("typed-constants", GIR, CONFIG);
+        let values = env
+            .library
+            .namespace(library::MAIN_NAMESPACE)
+            .constants
+            .iter()
+            .map(|c| {
+                let info = analysis::constants::Info {
+                    name: c.name.clone(),
+                    glib_name: c.c_identifier.clone(),
+                    typ: c.typ,
+                    value: c.value.clone(),
+                    version: None,
+                    deprecated_version: None,
+                    cfg_condition: None,
+                };
+                (c.name.as_str(), typed_value(&env, &info, "ffi"))
+            })
+            .collect::<Vec<_>>();
+        let typed = |typ: &str, value: &str| Some((typ.to_owned(), value.to_owned()));
+        assert_eq!(
+            values,
+            [
+                ("MAX_SIZE", typed("i32", "ffi::TEST_MAX_SIZE")),
+                ("SCALE", typed("f64", "ffi::TEST_SCALE")),
+                ("ENABLED", typed("bool", "true")),
+                ("SEPARATOR", typed("char", "'/'")),
+                ("DEFAULT_MODE", typed("Mode", "Mode::Slow")),
+                ("REPLACEMENT", typed("Codepoint", "'\\u{fffd}'")),
+                ("BLOCK_SIZE", typed("Size", "ffi::TEST_BLOCK_SIZE")),
+                ("ON", typed("Switch", "true")),
+                // Neither `PathBuf` nor pointers can be constants, and
+                // `glib::Char` isn't a plain integer
+                ("CONFIG_FILE", None),
+                ("NOTHING", None),
+                ("SMALL", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn supported_constants() {
+        let env = analyzed_env("supported-constants", GIR, CONFIG);
+        let names = env
+            .analysis
+            .constants
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect::<Vec<_>>();
+        assert!(names.contains(&"REPLACEMENT"));
+        assert!(!names.contains(&"CONFIG_FILE"));
+        assert!(!names.contains(&"NOTHING"));
+    }
+}
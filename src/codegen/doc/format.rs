@@ -8,7 +8,7 @@ use super::{LocationInObject, gi_docgen};
 use crate::{
     Env,
     analysis::functions::Info,
-    library::{FunctionKind, TypeId},
+    library::{FunctionKind, MAIN_NAMESPACE, TypeId},
     nameutil,
 };
 
@@ -33,7 +33,13 @@ pub fn reformat_doc(
     env: &Env,
     in_type: Option<(&TypeId, Option<LocationInObject>)>,
 ) -> String {
-    code_blocks_transformation(input, env, in_type)
+    code_blocks_transformation(input, &|s| replace_symbols(s, env, in_type))
+}
+
+/// Same as [`reformat_doc`] but links to the raw C items of the `-sys` crate
+/// instead of the high-level bindings.
+pub fn reformat_sys_doc(input: &str, env: &Env) -> String {
+    code_blocks_transformation(input, &|s| replace_sys_symbols(s, env))
 }
 
 fn try_split<'a>(src: &'a str, needle: &str) -> (&'a str, Option<&'a str>) {
@@ -43,17 +49,13 @@ fn try_split<'a>(src: &'a str, needle: &str) -> (&'a str, Option<&'a str>) {
     }
 }
 
-fn code_blocks_transformation(
-    mut input: &str,
-    env: &Env,
-    in_type: Option<(&TypeId, Option<LocationInObject>)>,
-) -> String {
+fn code_blocks_transformation(mut input: &str, replace: &dyn Fn(&str) -> String) -> String {
     let mut out = String::with_capacity(input.len());
 
     loop {
         input = match try_split(input, LANGUAGE_BLOCK_BEGIN) {
             (before, Some(after)) => {
-                out.push_str(&format(before, env, in_type));
+                out.push_str(&format(before, replace));
                 if let (before, Some(after)) =
                     try_split(get_language(after, &mut out), LANGUAGE_BLOCK_END)
                 {
@@ -65,7 +67,7 @@ fn code_blocks_transformation(
                 }
             }
             (before, None) => {
-                out.push_str(&format(before, env, in_type));
+                out.push_str(&format(before, replace));
                 return out;
             }
         };
@@ -98,17 +100,13 @@ fn get_markdown_language(input: &str) -> (&str, &str) {
 }
 
 // Re-format codeblocks & replaces the C types and GI-docgen with proper links
-fn format(
-    mut input: &str,
-    env: &Env,
-    in_type: Option<(&TypeId, Option<LocationInObject>)>,
-) -> String {
+fn format(mut input: &str, replace: &dyn Fn(&str) -> String) -> String {
     let mut ret = String::with_capacity(input.len());
     loop {
         input = match try_split(input, "```") {
             (before, Some(after)) => {
                 // if we are inside a codeblock
-                ret.push_str(&replace_symbols(before, env, in_type));
+                ret.push_str(&replace(before));
 
                 let (lang, after) = get_markdown_language(after);
                 if !["text", "rust", "xml", "css", "json", "html"].contains(&lang)
@@ -127,7 +125,7 @@ fn format(
                 }
             }
             (before, None) => {
-                ret.push_str(&replace_symbols(before, env, in_type));
+                ret.push_str(&replace(before));
                 return ret;
             }
         }
@@ -271,6 +269,65 @@ fn replace_c_types(
     spaces().replace_all(&out, " ").into_owned()
 }
 
+// Replaces the C types and GI-docgen links with links to the `-sys` crate items,
+// those keep their C names so no Rust name lookup is needed.
+fn replace_sys_symbols(input: &str, env: &Env) -> String {
+    let out = if env.library.doc_format == gir_parser::DocFormat::GiDocgen {
+        let out = gi_docgen::replace_sys_c_types(input, env);
+        gi_docgen_symbol()
+            .replace_all(&out, |caps: &Captures<'_>| {
+                find_sys_symbol(&caps[2], env).unwrap_or_else(|| format!("`{}`", &caps[2]))
+            })
+            .into_owned()
+    } else {
+        let out = function().replace_all(input, |caps: &Captures<'_>| {
+            find_sys_symbol(&caps[3], env).unwrap_or_else(|| {
+                format!("`{}{}()`", caps.get(2).map_or("", |m| m.as_str()), &caps[3])
+            })
+        });
+        let out = symbol().replace_all(&out, |caps: &Captures<'_>| {
+            let symbol_name = &caps[2];
+            match (&caps[1], caps.get(3)) {
+                ("@", _) => format!("`{symbol_name}`"),
+                (_, Some(member_path)) => format!("`{symbol_name}{}`", member_path.as_str()),
+                _ => {
+                    find_sys_symbol(symbol_name, env).unwrap_or_else(|| format!("`{symbol_name}`"))
+                }
+            }
+        });
+        tags().replace_all(&out, "`$0`").into_owned()
+    };
+    let out = gdk_gtk().replace_all(&out, |caps: &Captures<'_>| {
+        find_sys_symbol(&caps[2], env).unwrap_or_else(|| format!("`{}`", &caps[2]))
+    });
+    spaces().replace_all(&out, " ").into_owned()
+}
+
+/// Looks up a C symbol (type, function, constant or enum member) and returns a
+/// link to it relative to the root of the `-sys` crate.
+pub(crate) fn find_sys_symbol(c_name: &str, env: &Env) -> Option<String> {
+    let crate_name = if let Some(symbol) = env.symbols.borrow().by_c_name(c_name) {
+        if symbol.is_rust_prelude() {
+            return None;
+        }
+        symbol.crate_name().map(ToOwned::to_owned)
+    } else {
+        let (ns_id, _) = env.library.namespaces.iter().enumerate().find(|(_, ns)| {
+            ns.functions.iter().any(|f| f.c_identifier == c_name)
+                || ns.constants.iter().any(|c| c.c_identifier == c_name)
+        })?;
+        (ns_id as u16 != MAIN_NAMESPACE).then(|| env.namespaces[ns_id as u16].crate_name.clone())
+    };
+    Some(gen_sys_doc_link(crate_name.as_deref(), c_name))
+}
+
+pub(crate) fn gen_sys_doc_link(crate_name: Option<&str>, c_name: &str) -> String {
+    match crate_name {
+        Some(crate_name) => format!("[`{crate_name}::{c_name}`]"),
+        None => format!("[`{c_name}`]"),
+    }
+}
+
 /// Wrapper around [`find_constant_or_variant`] that fallbacks to returning
 /// the `symbol_name`
 fn find_constant_or_variant_wrapper(
@@ -406,15 +463,20 @@ fn find_method_or_function_by_ctype(
     env: &Env,
     in_type: Option<(&TypeId, Option<LocationInObject>)>,
 ) -> Option<String> {
+    let is_class_method = c_type.is_some_and(|t| t.ends_with("Class"));
     find_method_or_function(
         env,
         in_type,
         |f| f.glib_name == name,
-        |o| c_type.is_none_or(|t| o.c_type == t),
+        |o| {
+            c_type.is_none_or(|t| {
+                o.c_type == t || (is_class_method && o.c_class_type.as_deref() == Some(t))
+            })
+        },
         |r| c_type.is_none_or(|t| r.type_(&env.library).c_type == t),
         |r| c_type.is_none_or(|t| r.type_(&env.library).c_type == t),
         |r| c_type.is_none_or(|t| r.type_(&env.library).c_type == t),
-        c_type.is_some_and(|t| t.ends_with("Class")),
+        is_class_method,
         false,
     )
 }
@@ -456,7 +518,18 @@ pub(crate) fn find_method_or_function(
             None
         }
     } else if is_class_method {
-        if let Some((record_info, fn_info)) =
+        if let Some((obj_info, fn_info)) = env
+            .analysis
+            .find_object_by_class_method(env, search_obj, search_fn)
+        {
+            Some(gen_object_fn_doc_link(
+                obj_info,
+                fn_info,
+                env,
+                in_type,
+                &obj_info.name,
+            ))
+        } else if let Some((record_info, fn_info)) =
             env.analysis
                 .find_record_by_function(env, search_record, search_fn)
         {
@@ -601,7 +674,7 @@ pub(crate) fn gen_vfunc_doc_link(symbol: &str, vfunc: &str) -> String {
 }
 
 pub(crate) fn gen_callback_doc_link(callback: &str) -> String {
-    format!("`callback::{callback}")
+    format!("`callback::{callback}`")
 }
 
 pub(crate) fn gen_alias_doc_link(alias: &str) -> String {
@@ -6,7 +6,10 @@ use std::{
 
 use regex::{Captures, Regex};
 
-use super::format::find_method_or_function;
+use super::{
+    format::{find_method_or_function, find_sys_symbol, gen_sys_doc_link},
+    link_report,
+};
 use crate::{
     Env,
     analysis::object::LocationInObject,
@@ -14,7 +17,7 @@ use crate::{
         gen_alias_doc_link, gen_callback_doc_link, gen_const_doc_link, gen_object_fn_doc_link,
         gen_property_doc_link, gen_signal_doc_link, gen_symbol_doc_link, gen_vfunc_doc_link,
     },
-    library::{MAIN_NAMESPACE, TypeId},
+    library::{Library, MAIN_NAMESPACE, TypeId},
     nameutil::mangle_keywords,
 };
 
@@ -104,12 +107,29 @@ pub(crate) fn replace_c_types(
     env: &Env,
     in_type: Option<(&TypeId, Option<LocationInObject>)>,
 ) -> String {
+    gi_docgen_symbols()
+        .replace_all(entry, |caps: &Captures<'_>| {
+            match GiDocgen::from_str(&caps[0]) {
+                Ok(gi_type) => gi_type.rust_link(env, in_type).unwrap_or_else(|fallback| {
+                    link_report::record(&caps[0], "no matching item in the bindings");
+                    fallback
+                }),
+                Err(err) => {
+                    link_report::record(&caps[0], err);
+                    // otherwise fallback to the original string
+                    caps[0].to_string()
+                }
+            }
+        })
+        .to_string()
+}
+
+pub(crate) fn replace_sys_c_types(entry: &str, env: &Env) -> String {
     gi_docgen_symbols()
         .replace_all(entry, |caps: &Captures<'_>| {
             if let Ok(gi_type) = GiDocgen::from_str(&caps[0]) {
-                gi_type.rust_link(env, in_type)
+                gi_type.sys_link(env)
             } else {
-                // otherwise fallback to the original string
                 caps[0].to_string()
             }
         })
@@ -241,7 +261,16 @@ fn find_method_or_function_by_name(
                     &env.library.namespaces[f.ns_id as usize].name == n
                 })
         },
-        |o| type_.is_none_or(|t| o.name == t && is_same_namespace(env, namespace, o.type_id)),
+        |o| {
+            type_.is_none_or(|t| {
+                let name = if is_class_method {
+                    o.class_struct_name.as_deref()
+                } else {
+                    Some(o.name.as_str())
+                };
+                name == Some(t) && is_same_namespace(env, namespace, o.type_id)
+            })
+        },
         |r| type_.is_none_or(|t| r.name == t && is_same_namespace(env, namespace, r.type_id)),
         |e| type_.is_none_or(|t| e.name == t && is_same_namespace(env, namespace, e.type_id)),
         |f| type_.is_none_or(|t| f.name == t && is_same_namespace(env, namespace, f.type_id)),
@@ -259,11 +288,13 @@ fn is_same_namespace(env: &Env, namespace: Option<&str>, type_id: TypeId) -> boo
 }
 
 impl GiDocgen {
+    /// Returns the Rust intra-doc link, or the plain text fallback as error
+    /// if the linked item couldn't be found in the bindings.
     pub fn rust_link(
         &self,
         env: &Env,
         in_type: Option<(&TypeId, Option<LocationInObject>)>,
-    ) -> String {
+    ) -> Result<String, String> {
         let symbols = env.symbols.borrow();
         match self {
             GiDocgen::Enum { type_, namespace } | GiDocgen::Error { type_, namespace } => env
@@ -271,10 +302,8 @@ impl GiDocgen {
                 .enumerations
                 .iter()
                 .find(|e| &e.name == type_)
-                .map_or_else(
-                    || format!("`{}`", ns_type_to_doc(namespace, type_)),
-                    |info| gen_symbol_doc_link(info.type_id, env),
-                ),
+                .map(|info| gen_symbol_doc_link(info.type_id, env))
+                .ok_or_else(|| format!("`{}`", ns_type_to_doc(namespace, type_))),
             GiDocgen::Class { type_, namespace } | GiDocgen::Interface { type_, namespace } => env
                 .analysis
                 .objects
@@ -282,10 +311,8 @@ impl GiDocgen {
                 .find(|o| {
                     &o.name == type_ && is_same_namespace(env, namespace.as_deref(), o.type_id)
                 })
-                .map_or_else(
-                    || format!("`{}`", ns_type_to_doc(namespace, type_)),
-                    |info| gen_symbol_doc_link(info.type_id, env),
-                ),
+                .map(|info| gen_symbol_doc_link(info.type_id, env))
+                .ok_or_else(|| format!("`{}`", ns_type_to_doc(namespace, type_))),
             GiDocgen::Flag { type_, namespace } => env
                 .analysis
                 .flags
@@ -293,19 +320,22 @@ impl GiDocgen {
                 .find(|e| {
                     &e.name == type_ && is_same_namespace(env, namespace.as_deref(), e.type_id)
                 })
-                .map_or_else(
-                    || format!("`{}`", ns_type_to_doc(namespace, type_)),
-                    |info| gen_symbol_doc_link(info.type_id, env),
-                ),
+                .map(|info| gen_symbol_doc_link(info.type_id, env))
+                .ok_or_else(|| format!("`{}`", ns_type_to_doc(namespace, type_))),
             GiDocgen::Const { type_, namespace } => env
                 .analysis
                 .constants
                 .iter()
-                .find(|c| &c.name == type_ && is_same_namespace(env, namespace.as_deref(), c.typ))
-                .map_or_else(
-                    || format!("`{}`", ns_type_to_doc(namespace, type_)),
-                    gen_const_doc_link,
-                ),
+                // Only the constants of the main namespace are analyzed, their type
+                // can come from any namespace
+                .find(|c| {
+                    &c.name == type_
+                        && namespace
+                            .as_deref()
+                            .is_none_or(|n| env.library.namespace(MAIN_NAMESPACE).name == n)
+                })
+                .map(gen_const_doc_link)
+                .ok_or_else(|| format!("`{}`", ns_type_to_doc(namespace, type_))),
             GiDocgen::Property {
                 type_,
                 name,
@@ -317,13 +347,11 @@ impl GiDocgen {
                 .find(|o| {
                     &o.name == type_ && is_same_namespace(env, namespace.as_deref(), o.type_id)
                 })
-                .map_or_else(
-                    || gen_property_doc_link(&ns_type_to_doc(namespace, type_), name),
-                    |info| {
-                        let sym = symbols.by_tid(info.type_id).unwrap();
-                        gen_property_doc_link(&sym.full_rust_name(), name)
-                    },
-                ),
+                .map(|info| {
+                    let sym = symbols.by_tid(info.type_id).unwrap();
+                    gen_property_doc_link(&sym.full_rust_name(), name)
+                })
+                .ok_or_else(|| gen_property_doc_link(&ns_type_to_doc(namespace, type_), name)),
             GiDocgen::Signal {
                 type_,
                 name,
@@ -335,17 +363,15 @@ impl GiDocgen {
                 .find(|o| {
                     &o.name == type_ && is_same_namespace(env, namespace.as_deref(), o.type_id)
                 })
-                .map_or_else(
-                    || gen_signal_doc_link(&ns_type_to_doc(namespace, type_), name),
-                    |info| {
-                        let sym = symbols.by_tid(info.type_id).unwrap();
-                        gen_signal_doc_link(&sym.full_rust_name(), name)
-                    },
-                ),
-            GiDocgen::Id(c_name) => symbols.by_c_name(c_name).map_or_else(
-                || format!("`{c_name}`"),
-                |sym| format!("[`{n}`][crate::{n}]", n = sym.full_rust_name()),
-            ),
+                .map(|info| {
+                    let sym = symbols.by_tid(info.type_id).unwrap();
+                    gen_signal_doc_link(&sym.full_rust_name(), name)
+                })
+                .ok_or_else(|| gen_signal_doc_link(&ns_type_to_doc(namespace, type_), name)),
+            GiDocgen::Id(c_name) => symbols
+                .by_c_name(c_name)
+                .map(|sym| format!("[`{n}`][crate::{n}]", n = sym.full_rust_name()))
+                .ok_or_else(|| format!("`{c_name}`")),
             GiDocgen::Struct { namespace, type_ } => env
                 .analysis
                 .records
@@ -353,10 +379,8 @@ impl GiDocgen {
                 .find(|r| {
                     &r.name == type_ && is_same_namespace(env, namespace.as_deref(), r.type_id)
                 })
-                .map_or_else(
-                    || format!("`{}`", ns_type_to_doc(namespace, type_)),
-                    |info| gen_symbol_doc_link(info.type_id, env),
-                ),
+                .map(|info| gen_symbol_doc_link(info.type_id, env))
+                .ok_or_else(|| format!("`{}`", ns_type_to_doc(namespace, type_))),
             GiDocgen::Constructor {
                 namespace,
                 type_,
@@ -368,12 +392,10 @@ impl GiDocgen {
                     |o| &o.name == type_ && is_same_namespace(env, namespace.as_deref(), o.type_id),
                     |f| f.name == mangle_keywords(name),
                 )
-                .map_or_else(
-                    || format!("`{}::{}()`", ns_type_to_doc(namespace, type_), name),
-                    |(obj_info, fn_info)| {
-                        gen_object_fn_doc_link(obj_info, fn_info, env, in_type, type_)
-                    },
-                ),
+                .map(|(obj_info, fn_info)| {
+                    gen_object_fn_doc_link(obj_info, fn_info, env, in_type, type_)
+                })
+                .ok_or_else(|| format!("`{}::{}()`", ns_type_to_doc(namespace, type_), name)),
             GiDocgen::Func {
                 namespace,
                 type_,
@@ -386,14 +408,17 @@ impl GiDocgen {
                 in_type,
                 false,
             )
-            .unwrap_or_else(|| {
+            .ok_or_else(|| {
                 if let Some(ty) = type_ {
                     format!("`{}::{}()`", ns_type_to_doc(namespace, ty), name)
                 } else {
                     format!("`{name}()`")
                 }
             }),
-            GiDocgen::Alias(alias) => gen_alias_doc_link(alias),
+            GiDocgen::Alias(alias) => {
+                let (namespace, type_) = split_alias(alias);
+                text_link(&env.library, namespace, type_, gen_alias_doc_link(alias))
+            }
             GiDocgen::Method {
                 namespace,
                 type_,
@@ -407,16 +432,171 @@ impl GiDocgen {
                 in_type,
                 *is_class_method,
             )
-            .unwrap_or_else(|| format!("`{}::{}()`", ns_type_to_doc(namespace, type_), name)),
-            GiDocgen::Callback { namespace, name } => {
-                gen_callback_doc_link(&ns_type_to_doc(namespace, name))
-            }
+            .ok_or_else(|| format!("`{}::{}()`", ns_type_to_doc(namespace, type_), name)),
+            GiDocgen::Callback { namespace, name } => text_link(
+                &env.library,
+                namespace.as_deref(),
+                name,
+                gen_callback_doc_link(&ns_type_to_doc(namespace, name)),
+            ),
             GiDocgen::VFunc {
                 namespace,
                 type_,
                 name,
             } => find_virtual_method_by_name(Some(type_), namespace.as_deref(), name, env, in_type)
-                .unwrap_or_else(|| gen_vfunc_doc_link(&ns_type_to_doc(namespace, type_), name)),
+                .ok_or_else(|| gen_vfunc_doc_link(&ns_type_to_doc(namespace, type_), name)),
+        }
+    }
+}
+
+/// Finds the type referenced by a link, `namespace` defaults to the main one
+fn find_linked_type(library: &Library, namespace: Option<&str>, type_: &str) -> Option<TypeId> {
+    let ns_id = match namespace {
+        Some(ns) => library.find_namespace(ns)?.0,
+        None => MAIN_NAMESPACE,
+    };
+    library.find_type(ns_id, type_)
+}
+
+fn split_alias(alias: &str) -> (Option<&str>, &str) {
+    match alias.split_once('.') {
+        Some((ns, type_)) => (Some(ns), type_),
+        None => (None, alias),
+    }
+}
+
+/// Aliases and callbacks are not generated as linkable items, the links to
+/// the existing ones are rendered as text
+fn text_link(
+    library: &Library,
+    namespace: Option<&str>,
+    type_: &str,
+    text: String,
+) -> Result<String, String> {
+    match find_linked_type(library, namespace, type_) {
+        Some(_) => Ok(text),
+        None => Err(text),
+    }
+}
+
+fn sys_crate_name(env: &Env, tid: TypeId) -> Option<&str> {
+    (tid.ns_id != MAIN_NAMESPACE).then(|| env.namespaces[tid.ns_id].crate_name.as_str())
+}
+
+fn sys_type_link(env: &Env, namespace: Option<&str>, type_: &str) -> Option<String> {
+    let tid = find_linked_type(&env.library, namespace, type_)?;
+    let c_name = env.library.type_(tid).get_glib_name()?;
+    Some(gen_sys_doc_link(sys_crate_name(env, tid), c_name))
+}
+
+fn sys_function_link(
+    env: &Env,
+    namespace: Option<&str>,
+    type_: Option<&str>,
+    name: &str,
+) -> Option<String> {
+    if let Some(type_) = type_ {
+        let tid = find_linked_type(&env.library, namespace, type_)?;
+        let func = env
+            .library
+            .type_(tid)
+            .functions()
+            .iter()
+            .find(|f| f.name == name)?;
+        Some(gen_sys_doc_link(
+            sys_crate_name(env, tid),
+            &func.c_identifier,
+        ))
+    } else {
+        let ns_id = match namespace {
+            Some(ns) => env.library.find_namespace(ns)?.0,
+            None => MAIN_NAMESPACE,
+        };
+        let func = env
+            .library
+            .namespace(ns_id)
+            .functions
+            .iter()
+            .find(|f| f.name == name)?;
+        find_sys_symbol(&func.c_identifier, env)
+    }
+}
+
+impl GiDocgen {
+    /// Same as [`GiDocgen::rust_link`] but links to the C items of the `-sys` crate
+    pub fn sys_link(&self, env: &Env) -> String {
+        match self {
+            GiDocgen::Class { namespace, type_ }
+            | GiDocgen::Interface { namespace, type_ }
+            | GiDocgen::Struct { namespace, type_ }
+            | GiDocgen::Enum { namespace, type_ }
+            | GiDocgen::Error { namespace, type_ }
+            | GiDocgen::Flag { namespace, type_ }
+            | GiDocgen::Callback {
+                namespace,
+                name: type_,
+            } => sys_type_link(env, namespace.as_deref(), type_)
+                .unwrap_or_else(|| format!("`{}`", ns_type_to_doc(namespace, type_))),
+            GiDocgen::Alias(alias) => {
+                let (namespace, type_) = split_alias(alias);
+                sys_type_link(env, namespace, type_).unwrap_or_else(|| format!("`{alias}`"))
+            }
+            GiDocgen::Const { namespace, type_ } => {
+                let ns_id = match namespace {
+                    Some(ns) => env.library.find_namespace(ns).map(|(id, _)| id),
+                    None => Some(MAIN_NAMESPACE),
+                };
+                ns_id
+                    .and_then(|ns_id| {
+                        env.library
+                            .namespace(ns_id)
+                            .constants
+                            .iter()
+                            .find(|c| &c.name == type_)
+                    })
+                    .and_then(|c| find_sys_symbol(&c.c_identifier, env))
+                    .unwrap_or_else(|| format!("`{}`", ns_type_to_doc(namespace, type_)))
+            }
+            GiDocgen::Id(c_name) => {
+                find_sys_symbol(c_name, env).unwrap_or_else(|| format!("`{c_name}`"))
+            }
+            GiDocgen::Constructor {
+                namespace,
+                type_,
+                name,
+            }
+            | GiDocgen::Method {
+                namespace,
+                type_,
+                name,
+                ..
+            } => sys_function_link(env, namespace.as_deref(), Some(type_), name)
+                .unwrap_or_else(|| format!("`{}::{}()`", ns_type_to_doc(namespace, type_), name)),
+            GiDocgen::Func {
+                namespace,
+                type_,
+                name,
+            } => sys_function_link(env, namespace.as_deref(), type_.as_deref(), name)
+                .unwrap_or_else(|| match type_ {
+                    Some(ty) => format!("`{}::{}()`", ns_type_to_doc(namespace, ty), name),
+                    None => format!("`{name}()`"),
+                }),
+            // There is no C item for those, only mention them
+            GiDocgen::Property {
+                namespace,
+                type_,
+                name,
+            } => format!("`{}:{}`", ns_type_to_doc(namespace, type_), name),
+            GiDocgen::Signal {
+                namespace,
+                type_,
+                name,
+            } => format!("`{}::{}`", ns_type_to_doc(namespace, type_), name),
+            GiDocgen::VFunc {
+                namespace,
+                type_,
+                name,
+            } => format!("`{}.{}()`", ns_type_to_doc(namespace, type_), name),
         }
     }
 }
@@ -540,6 +720,7 @@ impl FromStr for GiDocgen {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::library::{Alias, Function, FunctionKind, Parameter, Type};
     #[test]
     fn test_link_alias() {
         assert_eq!(
@@ -811,4 +992,57 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn test_text_links() {
+        let mut library = Library::new("Gtk");
+        library.add_type(
+            MAIN_NAMESPACE,
+            "Allocation",
+            Type::Alias(Alias {
+                name: "Allocation".to_owned(),
+                c_identifier: "GtkAllocation".to_owned(),
+                typ: TypeId::tid_none(),
+                target_c_type: "GdkRectangle".to_owned(),
+                doc: None,
+                doc_deprecated: None,
+            }),
+        );
+        library.add_type(
+            MAIN_NAMESPACE,
+            "TickCallback",
+            Type::Function(Box::new(Function {
+                name: "TickCallback".to_owned(),
+                c_identifier: "GtkTickCallback".to_owned(),
+                kind: FunctionKind::Function,
+                parameters: Vec::new(),
+                ret: Parameter::None(TypeId::tid_none()),
+                throws: false,
+                version: None,
+                deprecated_version: None,
+                doc: None,
+                doc_deprecated: None,
+                get_property: None,
+                set_property: None,
+                finish_func: None,
+                async_func: None,
+                sync_func: None,
+            })),
+        );
+
+        let (namespace, type_) = split_alias("Gtk.Allocation");
+        assert_eq!(
+            text_link(
+                &library,
+                namespace,
+                type_,
+                "`alias::Gtk.Allocation`".to_owned()
+            ),
+            Ok("`alias::Gtk.Allocation`".to_owned())
+        );
+        assert!(text_link(&library, None, "TickCallback", String::new()).is_ok());
+        assert!(text_link(&library, Some("Gtk"), "TickCallback", String::new()).is_ok());
+        assert!(text_link(&library, None, "Rectangle", String::new()).is_err());
+        assert!(text_link(&library, Some("Gdk"), "Rectangle", String::new()).is_err());
+    }
 }
@@ -0,0 +1,82 @@
+//! Collects the gi-docgen links that couldn't be turned into Rust links while
+//! generating the documentation, grouped by the item documented.
+
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+};
+
+use log::warn;
+
+#[derive(Default)]
+struct Report {
+    location: String,
+    // location -> (link, reason)
+    links: BTreeMap<String, BTreeSet<(String, String)>>,
+}
+
+thread_local! {
+    static REPORT: RefCell<Report> = RefCell::default();
+}
+
+/// Sets the item whose documentation is currently generated
+pub fn set_location(location: String) {
+    REPORT.with_borrow_mut(|report| report.location = location);
+}
+
+pub fn record(link: &str, reason: impl Display) {
+    REPORT.with_borrow_mut(|report| {
+        let location = report.location.clone();
+        report
+            .links
+            .entry(location)
+            .or_default()
+            .insert((link.to_owned(), reason.to_string()));
+    });
+}
+
+/// Prints the collected links and resets the report.
+///
+/// Returns the number of unresolved links.
+pub fn print() -> usize {
+    let report = REPORT.take();
+    let mut count = 0;
+    for (location, links) in &report.links {
+        let location = if location.is_empty() {
+            "<crate>"
+        } else {
+            location
+        };
+        let mut message = format!("Unresolved documentation links in `{location}`:");
+        for (link, reason) in links {
+            message.push_str(&format!("\n    {link}: {reason}"));
+        }
+        warn!("{message}");
+        count += links.len();
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups_by_location() {
+        set_location("struct Widget".to_owned());
+        record(
+            "[method@Gtk.Widget.nope]",
+            "no matching item in the bindings",
+        );
+        record(
+            "[method@Gtk.Widget.nope]",
+            "no matching item in the bindings",
+        );
+        record("[ctor@Widget]", "Broken link syntax for type \"ctor\"");
+        set_location("fn init".to_owned());
+        record("[class@Foo]", "no matching item in the bindings");
+        assert_eq!(print(), 3);
+        assert_eq!(print(), 0);
+    }
+}
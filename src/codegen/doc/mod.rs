@@ -7,12 +7,12 @@ use std::{
 
 use log::{error, info};
 use regex::{Captures, Regex};
-use stripper_lib::{Type as SType, TypeStruct, write_file_name, write_item_doc};
+use stripper_lib::{Type as SType, TypeStruct, write_file_comment, write_file_name};
 
 use self::format::reformat_doc;
 use crate::{
-    analysis::{self, namespaces::MAIN, object::LocationInObject},
-    config::gobjects::GObject,
+    analysis::{self, namespaces::MAIN, object::LocationInObject, properties::value_doc},
+    config::{doc_sections::DocSectionTarget, gobjects::GObject},
     env::Env,
     file_saver::save_to_file,
     library::{self, Type as LType, *},
@@ -23,6 +23,9 @@ use crate::{
 
 mod format;
 mod gi_docgen;
+mod link_report;
+
+pub use self::format::reformat_sys_doc;
 
 // A list of C parameters that are not used directly by the Rust bindings
 const IGNORED_C_FN_PARAMS: [&str; 6] = [
@@ -94,6 +97,7 @@ macro_rules! impl_function_like_type {
 impl_to_stripper_type!(Enumeration, Enum);
 impl_to_stripper_type!(Bitfield, Struct);
 impl_to_stripper_type!(Record, Struct);
+impl_to_stripper_type!(Union, Enum);
 impl_to_stripper_type!(Class, Struct);
 impl_to_stripper_type!(Function, Fn);
 impl_to_stripper_type!(Signal, Fn, false);
@@ -101,11 +105,30 @@ impl_to_stripper_type!(Signal, Fn, false);
 impl_function_like_type!(Function);
 impl_function_like_type!(Signal);
 
-pub fn generate(env: &Env) {
+pub fn generate(env: &Env) -> std::result::Result<(), String> {
     info!("Generating documentation {:?}", env.config.doc_target_path);
     save_to_file(&env.config.doc_target_path, env.config.make_backup, |w| {
         generate_doc(w, env)
     });
+    generate_doc_section_pages(env);
+
+    let unresolved = link_report::print();
+    if unresolved > 0 && env.config.deny_broken_doc_links {
+        return Err(format!(
+            "{unresolved} unresolved documentation links, see the warnings above"
+        ));
+    }
+    Ok(())
+}
+
+/// Writes the doc of an item, also remembering it as location of the
+/// unresolved links found in the doc
+fn write_item_doc<F>(w: &mut dyn Write, id: &TypeStruct, f: F) -> Result<()>
+where
+    F: FnOnce(&mut dyn Write) -> Result<()>,
+{
+    link_report::set_location(id.to_string());
+    stripper_lib::write_item_doc(w, id, f)
 }
 
 #[allow(clippy::type_complexity)]
@@ -131,6 +154,22 @@ fn generate_doc(w: &mut dyn Write, env: &Env) -> Result<()> {
         }
     }
 
+    for info in &env.analysis.unions {
+        if info.type_id.ns_id == MAIN {
+            generators.push((
+                &info.name,
+                Box::new(move |w, e| create_union_doc(w, e, info)),
+            ));
+        }
+    }
+
+    for info in &env.analysis.callbacks {
+        generators.push((
+            &info.name,
+            Box::new(move |w, e| create_callback_doc(w, e, info)),
+        ));
+    }
+
     for (tid, type_) in env.library.namespace_types(MAIN) {
         if let LType::Enumeration(enum_) = type_ {
             if !env
@@ -207,6 +246,7 @@ fn generate_doc(w: &mut dyn Write, env: &Env) -> Result<()> {
                 &doc_ignored_parameters,
                 None,
                 f_info.is_none_or(|f| f.generate_doc),
+                variant_doc(env, f_info, None),
             )?;
         }
     }
@@ -239,9 +279,56 @@ fn generate_doc(w: &mut dyn Write, env: &Env) -> Result<()> {
         f(w, env)?;
     }
 
+    // Those start a new file section, so they have to come last
+    for doc_section in &env.config.doc_sections {
+        let DocSectionTarget::Module(ref file) = doc_section.target else {
+            continue;
+        };
+        let Some(section) = find_doc_section(env, &doc_section.name) else {
+            continue;
+        };
+        link_report::set_location(format!("docsection {}", section.name));
+        write_file_name(w, Some(file))?;
+        writeln!(
+            w,
+            "{}",
+            write_file_comment(&reformat_doc(&section.doc, env, None), &None, false)
+        )?;
+    }
+
     Ok(())
 }
 
+fn generate_doc_section_pages(env: &Env) {
+    for doc_section in &env.config.doc_sections {
+        let DocSectionTarget::Page(ref page) = doc_section.target else {
+            continue;
+        };
+        let Some(section) = find_doc_section(env, &doc_section.name) else {
+            continue;
+        };
+        let path = env.config.target_path.join(page);
+        info!("Generating documentation page {path:?}");
+        save_to_file(&path, env.config.make_backup, |w| {
+            link_report::set_location(format!("docsection {}", section.name));
+            writeln!(w, "{}", reformat_doc(&section.doc, env, None))
+        });
+    }
+}
+
+fn find_doc_section<'a>(env: &'a Env, name: &str) -> Option<&'a library::DocSection> {
+    let section = env
+        .library
+        .namespace(MAIN)
+        .doc_sections
+        .iter()
+        .find(|section| section.name == name);
+    if section.is_none() {
+        error!("Configured docsection `{name}` not found in the GIR file");
+    }
+    section
+}
+
 fn create_object_doc(w: &mut dyn Write, env: &Env, info: &analysis::object::Info) -> Result<()> {
     let ty = TypeStruct::new(SType::Struct, &info.name);
     let ty_ext = TypeStruct::new(SType::Trait, &info.trait_name);
@@ -523,6 +610,7 @@ fn create_object_doc(w: &mut dyn Write, env: &Env, info: &analysis::object::Info
             &doc_ignored_parameters,
             Some((&info.type_id, object_location)),
             f_info.is_none_or(|f| f.generate_doc),
+            variant_doc(env, f_info, Some(info.type_id)),
         )?;
     }
     for signal in signals {
@@ -548,6 +636,7 @@ fn create_object_doc(w: &mut dyn Write, env: &Env, info: &analysis::object::Info
             &HashSet::new(),
             Some((&info.type_id, object_location)),
             configured_signals.iter().all(|s| s.generate_doc),
+            None,
         )?;
     }
 
@@ -595,9 +684,45 @@ fn create_object_doc(w: &mut dyn Write, env: &Env, info: &analysis::object::Info
             &doc_ignored_parameters,
             Some((&info.type_id, object_location)),
             f_info.is_none_or(|f| f.generate_doc),
+            None,
         )?;
     }
 
+    if let Some(class_trait_name) = info.class_trait_name() {
+        let class_struct: Option<&Record> = info
+            .class_struct_name
+            .as_ref()
+            .and_then(|name| env.library.find_type(info.type_id.ns_id, name))
+            .and_then(|tid| env.library.type_(tid).maybe_ref_as());
+        let ty = TypeStruct::new(SType::Trait, &class_trait_name);
+        for function in class_struct.map_or(&[][..], |r| &r.functions) {
+            let f_info = info
+                .class_methods
+                .iter()
+                .find(|f| f.glib_name == function.c_identifier);
+            let should_be_documented = f_info.is_some_and(|f| f.should_docs_be_generated(env));
+            if !should_be_documented {
+                continue;
+            }
+
+            let fn_new_name = f_info.and_then(|analysed_f| analysed_f.new_name.clone());
+            let doc_ignored_parameters = f_info
+                .map(|analyzed_f| analyzed_f.doc_ignore_parameters.clone())
+                .unwrap_or_default();
+            create_fn_doc(
+                w,
+                env,
+                function,
+                Some(Box::new(ty.clone())),
+                fn_new_name,
+                &doc_ignored_parameters,
+                Some((&info.type_id, f_info.map(|f| info.function_location(f)))),
+                f_info.is_none_or(|f| f.generate_doc),
+                None,
+            )?;
+        }
+    }
+
     for property in properties {
         let getter_name = if property.getter.is_some() {
             None // Don't generate a getter for the property, there is a getter
@@ -700,6 +825,7 @@ fn create_record_doc(w: &mut dyn Write, env: &Env, info: &analysis::record::Info
             &HashSet::new(),
             Some((&info.type_id, None)),
             f_info.is_none_or(|f| f.generate_doc),
+            variant_doc(env, f_info, Some(info.type_id)),
         )?;
         let fn_new_name = f_info.and_then(|analysed_f| analysed_f.new_name.clone());
 
@@ -712,6 +838,7 @@ fn create_record_doc(w: &mut dyn Write, env: &Env, info: &analysis::record::Info
             &HashSet::new(),
             Some((&info.type_id, None)),
             f_info.is_none_or(|f| f.generate_doc),
+            variant_doc(env, f_info, Some(info.type_id)),
         )?;
     }
     Ok(())
@@ -770,6 +897,54 @@ fn create_enum_doc(w: &mut dyn Write, env: &Env, enum_: &Enumeration, tid: TypeI
     Ok(())
 }
 
+fn create_union_doc(w: &mut dyn Write, env: &Env, info: &analysis::unions::Info) -> Result<()> {
+    let union = info.type_(&env.library);
+    let ty = union.to_stripper_type();
+    let config = env.config.objects.get(&info.full_name);
+
+    if config.is_none_or(|c| c.generate_doc) {
+        write_item_doc(w, &ty, |w| {
+            if let Some(ref doc) = union.doc {
+                writeln!(w, "{}", reformat_doc(doc, env, Some((&info.type_id, None))))?;
+            }
+            Ok(())
+        })?;
+    }
+
+    for variant in &info.variants {
+        let Some(doc) = variant.payload.as_ref().and_then(|p| p.doc.as_ref()) else {
+            continue;
+        };
+        let sub_ty = TypeStruct {
+            name: variant.name.clone(),
+            parent: Some(Box::new(ty.clone())),
+            ty: SType::Variant,
+            args: Vec::new(),
+        };
+        write_item_doc(w, &sub_ty, |w| {
+            writeln!(w, "{}", reformat_doc(doc, env, Some((&info.type_id, None))))
+        })?;
+    }
+
+    Ok(())
+}
+
+fn create_callback_doc(
+    w: &mut dyn Write,
+    env: &Env,
+    info: &analysis::callbacks::Info,
+) -> Result<()> {
+    let func: &Function = env.type_(info.type_id).to_ref_as();
+    let Some(ref doc) = func.doc else {
+        return Ok(());
+    };
+    let ty = TypeStruct::new(SType::Trait, &info.name);
+
+    write_item_doc(w, &ty, |w| {
+        writeln!(w, "{}", reformat_doc(doc, env, Some((&info.type_id, None))))
+    })
+}
+
 fn create_bitfield_doc(
     w: &mut dyn Write,
     env: &Env,
@@ -853,6 +1028,7 @@ fn create_fn_doc<T>(
     doc_ignored_parameters: &HashSet<String>,
     in_type: Option<(&TypeId, Option<LocationInObject>)>,
     generate_doc: bool,
+    variant: Option<String>,
 ) -> Result<()>
 where
     T: FunctionLikeType + ToStripperType,
@@ -867,6 +1043,7 @@ where
         && fn_.doc_deprecated().is_none()
         && fn_.ret().doc().is_none()
         && fn_.parameters().iter().all(|p| p.doc().is_none())
+        && variant.is_none()
     {
         return Ok(());
     }
@@ -890,6 +1067,12 @@ where
                 reformat_doc(&fix_param_names(doc, self_name), env, in_type)
             )?;
         }
+        if let Some(ref variant) = variant {
+            if fn_.doc().is_some() {
+                writeln!(w)?;
+            }
+            writeln!(w, "{}", reformat_doc(variant, env, in_type))?;
+        }
         if let Some(ver) = fn_.deprecated_version() {
             writeln!(w, "\n# Deprecated since {ver}\n")?;
         } else if fn_.doc_deprecated().is_some() {
@@ -984,6 +1167,46 @@ where
     })
 }
 
+/// Links a function to its synchronous or async variant, as a gi-docgen link.
+fn variant_doc(
+    env: &Env,
+    f_info: Option<&analysis::functions::Info>,
+    type_id: Option<TypeId>,
+) -> Option<String> {
+    let f_info = f_info?;
+    let (c_identifier, is_sync) = match (&f_info.sync_func, &f_info.async_func) {
+        (Some(sync_func), _) => (sync_func, true),
+        (None, Some(async_func)) => (async_func, false),
+        (None, None) => return None,
+    };
+    let function = analysis::functions::find_function(env, c_identifier)?;
+    let link = match type_id {
+        Some(tid) => {
+            let kind = match function.kind {
+                FunctionKind::Method => "method",
+                FunctionKind::Constructor => "ctor",
+                _ => "func",
+            };
+            format!(
+                "[{kind}@{}.{}.{}]",
+                env.library.namespace(tid.ns_id).name,
+                env.library.type_(tid).get_name(),
+                function.name
+            )
+        }
+        None => format!(
+            "[func@{}.{}]",
+            env.library.namespace(MAIN_NAMESPACE).name,
+            function.name
+        ),
+    };
+    Some(if is_sync {
+        format!("This is the asynchronous version of {link}.")
+    } else {
+        format!("See {link} for the asynchronous version.")
+    })
+}
+
 fn create_property_doc(
     w: &mut dyn Write,
     env: &Env,
@@ -1035,6 +1258,9 @@ fn create_property_doc(
                     reformat_doc(&fix_param_names(doc, None), env, Some(in_type))
                 )?;
             }
+            for line in value_doc(env, property) {
+                writeln!(w, "\n{line}")?;
+            }
             if let Some(ver) = property.deprecated_version {
                 writeln!(w, "\n# Deprecated since {ver}\n")?;
             } else if property.doc_deprecated.is_some() {
@@ -1138,6 +1364,9 @@ pub fn document_type_properties(
         } else {
             writeln!(w, "\n\n#### `{}`\n {}", property.name, details.join(" | "),)?;
         }
+        for line in value_doc(env, property) {
+            writeln!(w, "\n{line}")?;
+        }
     }
     if subtype.is_some() {
         writeln!(w, "</details>")?;
@@ -1184,3 +1413,31 @@ pub fn document_type_signals(
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::{functions::tests::PAIRS_GIR, test_env::analyzed_env};
+
+    #[test]
+    fn sync_and_async_links() {
+        let env = analyzed_env("variant-doc", PAIRS_GIR, "");
+        let functions = &env.analysis.global_functions.as_ref().unwrap().functions;
+        let doc = |glib_name: &str| {
+            variant_doc(
+                &env,
+                functions.iter().find(|f| f.glib_name == glib_name),
+                None,
+            )
+        };
+        assert_eq!(
+            doc("test_fetch_async").as_deref(),
+            Some("This is the asynchronous version of [func@Test.fetch_blocking].")
+        );
+        assert_eq!(
+            doc("test_fetch_blocking").as_deref(),
+            Some("See [func@Test.fetch_async] for the asynchronous version.")
+        );
+        assert_eq!(doc("test_fetch_sync"), None);
+    }
+}
@@ -17,8 +17,9 @@ use super::{
     special_functions,
 };
 use crate::{
-    analysis::{self, bounds::Bounds, try_from_glib::TryFromGlib},
+    analysis::{self, bounds::Bounds, functions::AsyncFuture, try_from_glib::TryFromGlib},
     chunk::{Chunk, ffi_function_todo},
+    config::FutureStyle,
     env::Env,
     library::{self, TypeId},
     nameutil::use_glib_type,
@@ -265,16 +266,15 @@ pub fn declaration(env: &Env, analysis: &analysis::functions::Info) -> String {
 pub fn declaration_futures(env: &Env, analysis: &analysis::functions::Info) -> String {
     let async_future = analysis.async_future.as_ref().unwrap();
 
-    let return_str = if let Some(ref error_parameters) = async_future.error_parameters {
-        format!(
-            " -> Pin<Box_<dyn std::future::Future<Output = Result<{}, {}>> + 'static>>",
-            async_future.success_parameters, error_parameters
-        )
-    } else {
-        format!(
-            " -> Pin<Box_<dyn std::future::Future<Output = {}> + 'static>>",
-            async_future.success_parameters
-        )
+    let output = future_output(async_future);
+    let send = if async_future.is_send { " + Send" } else { "" };
+    let return_str = match async_future.style {
+        FutureStyle::Boxed => {
+            format!(" -> Pin<Box_<dyn std::future::Future<Output = {output}> + 'static>>")
+        }
+        FutureStyle::Impl => {
+            format!(" -> impl std::future::Future<Output = {output}>{send} + 'static")
+        }
     };
 
     let mut param_str = String::with_capacity(100);
@@ -312,6 +312,16 @@ pub fn declaration_futures(env: &Env, analysis: &analysis::functions::Info) -> S
     )
 }
 
+fn future_output(async_future: &AsyncFuture) -> String {
+    match async_future.error_parameters {
+        Some(ref error_parameters) => format!(
+            "Result<{}, {}>",
+            async_future.success_parameters, error_parameters
+        ),
+        None => async_future.success_parameters.clone(),
+    }
+}
+
 pub fn bounds(
     bounds: &Bounds,
     skip: &[char],
@@ -486,15 +496,19 @@ pub fn body_chunk_futures(
 
     let unsafe_block = if analysis.unsafe_ { "unsafe " } else { "" };
 
+    let (open, close) = match async_future.style {
+        FutureStyle::Boxed => (format!("Box_::pin({gio_future_name}::new("), "))"),
+        FutureStyle::Impl => (format!("{gio_future_name}::new("), ")"),
+    };
     if async_future.is_method {
         writeln!(
             body,
-            "Box_::pin({gio_future_name}::new(self, move |obj, cancellable, send| {unsafe_block}{{"
+            "{open}self, move |obj, cancellable, send| {unsafe_block}{{"
         )?;
     } else {
         writeln!(
             body,
-            "Box_::pin({gio_future_name}::new(&(), move |_obj, cancellable, send| {unsafe_block}{{"
+            "{open}&(), move |_obj, cancellable, send| {unsafe_block}{{"
         )?;
     }
 
@@ -533,7 +547,7 @@ pub fn body_chunk_futures(
     writeln!(body, "\t\t\tsend.resolve(res);")?;
     writeln!(body, "\t\t}},")?;
     writeln!(body, "\t);")?;
-    writeln!(body, "}}))")?;
+    writeln!(body, "}}{close}")?;
 
     Ok(body)
 }
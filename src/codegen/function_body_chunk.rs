@@ -2,8 +2,8 @@ use std::collections::{BTreeMap, HashMap, hash_map::Entry};
 
 use crate::{
     analysis::{
-        self,
-        conversion_type::ConversionType,
+        self, callbacks,
+        conversion_type::{ConversionType, is_direct_fixed_array},
         function_parameters::{
             CParameter as AnalysisCParameter, Transformation, TransformationType,
         },
@@ -16,6 +16,10 @@ use crate::{
         trampolines::Trampoline,
     },
     chunk::{Chunk, Param, TupleMode, parameter_ffi_call_out},
+    codegen::{
+        Visibility,
+        translate_to_glib::{garray_to_glib, hash_table_to_glib},
+    },
     env::Env,
     library::{self, TypeId},
     nameutil::{is_gstring, use_gio_type, use_glib_if_needed, use_glib_type},
@@ -37,6 +41,8 @@ use self::Parameter::*;
 #[derive(Clone, Debug, Eq, PartialEq)]
 enum OutMemMode {
     Uninitialized,
+    /// Fixed-size array filled in place, returned as is.
+    UninitializedArray,
     UninitializedNamed(String),
     NullPtr,
     NullMutPtr,
@@ -44,7 +50,7 @@ enum OutMemMode {
 
 impl OutMemMode {
     fn is_uninitialized(&self) -> bool {
-        matches!(*self, Self::Uninitialized)
+        matches!(*self, Self::Uninitialized | Self::UninitializedArray)
     }
 }
 
@@ -144,6 +150,7 @@ impl Builder {
         } else {
             Vec::new()
         };
+        let after_call = self.write_converted_parameters(&mut body, env);
 
         let mut group_by_user_data = FuncParameters::new();
 
@@ -209,9 +216,27 @@ impl Builder {
         let ret = self.generate_out_return(&mut uninitialized_vars);
         let (call, ret) = self.apply_outs_mode(call, ret, &mut uninitialized_vars);
 
-        body.push(call);
-        if let Some(chunk) = ret {
+        if after_call.is_empty() {
+            body.push(call);
+            if let Some(chunk) = ret {
+                body.push(chunk);
+            }
+        } else if let Some(chunk) = ret {
+            body.push(call);
+            body.extend(after_call);
             body.push(chunk);
+        } else if self.ret.ret.parameter.is_none() {
+            body.push(call);
+            body.extend(after_call);
+        } else {
+            body.push(Chunk::Let {
+                name: "ret".into(),
+                is_mut: false,
+                value: Box::new(call),
+                type_: None,
+            });
+            body.extend(after_call);
+            body.push(Chunk::Custom("ret".into()));
         }
 
         let mut chunks = Vec::new();
@@ -419,245 +444,35 @@ impl Builder {
             }
         }
 
-        let mut body = Vec::new();
-        let mut arguments = Vec::new();
-
-        for par in &trampoline.parameters.transformations {
-            if par.name == "this"
-                || trampoline.parameters.c_parameters[par.ind_c].is_real_gpointer(env)
-            {
-                continue;
-            }
-            let ty_name = match RustType::try_new(env, par.typ) {
-                Ok(x) => x.into_string(),
-                _ => String::new(),
-            };
-            let nullable = trampoline.parameters.rust_parameters[par.ind_rust].nullable;
-            let is_basic = add_chunk_for_type(env, par.typ, par, &mut body, &ty_name, nullable);
-            if is_gstring(&ty_name) {
-                if nullable {
-                    arguments.push(Chunk::Name(format!(
-                        "(*{}).as_ref().map(|s| s.as_str())",
-                        par.name
-                    )));
-                } else {
-                    arguments.push(Chunk::Name(format!("{}.as_str()", par.name)));
-                }
-                continue;
-            }
-            if nullable && !is_basic {
-                arguments.push(Chunk::Name(format!("{}.as_ref().as_ref()", par.name)));
-                continue;
-            }
-            arguments.push(Chunk::Name(format!(
-                "{}{}",
-                if is_basic { "" } else { "&" },
-                par.name
-            )));
-        }
-
-        let func = trampoline
-            .parameters
-            .c_parameters
-            .last()
-            .map_or_else(|| "Unknown".to_owned(), |p| p.name.clone());
-
-        if let Some(full_type) = full_type {
-            if is_destroy || trampoline.scope.is_some_and(|s| s.is_async()) {
-                body.push(Chunk::Let {
-                    name: format!("{}callback", if is_destroy { "_" } else { "" }),
-                    is_mut: false,
-                    value: Box::new(Chunk::Custom(format!("Box_::from_raw({func} as *mut _)"))),
-                    type_: Some(Box::new(Chunk::Custom(full_type.1.clone()))),
-                });
+        if let Some(tid) = trampoline.shared_callback {
+            let (name, func) = if is_destroy {
+                (
+                    format!("destroy_call{}", trampoline.destroy_index),
+                    callbacks::destroy_name(env, tid),
+                )
             } else {
-                body.push(Chunk::Let {
-                    name: "callback".to_owned(),
-                    is_mut: false,
-                    value: Box::new(Chunk::Custom(format!(
-                        "{}*({} as *mut _)",
-                        if !trampoline.scope.is_some_and(|s| s.is_call()) {
-                            "&"
-                        } else if pos.is_some() {
-                            "&mut "
-                        } else {
-                            ""
-                        },
-                        func
-                    ))),
-                    type_: Some(Box::new(Chunk::Custom(
-                        if !trampoline.scope.is_some_and(|s| s.is_async())
-                            && !trampoline.scope.is_some_and(|s| s.is_call())
-                        {
-                            format!(
-                                "&{}",
-                                full_type
-                                    .1
-                                    .strip_prefix("Box_<")
-                                    .unwrap()
-                                    .strip_suffix(">")
-                                    .unwrap()
-                            )
-                        } else {
-                            full_type.1.clone()
-                        },
-                    ))),
-                });
-                if trampoline.scope.is_some_and(|s| s.is_async()) {
-                    body.push(Chunk::Custom(format!(
-                        "let callback = callback{}{};",
-                        if let Some(pos) = pos {
-                            format!(".{pos}")
-                        } else {
-                            String::new()
-                        },
-                        if trampoline.nullable {
-                            ".expect(\"cannot get closure...\")"
-                        } else {
-                            ""
-                        }
-                    )));
-                } else if !trampoline.scope.is_some_and(|s| s.is_call()) {
-                    if trampoline.nullable {
-                        body.push(Chunk::Custom(format!(
-                            "if let Some(ref callback) = callback{} {{",
-                            if let Some(pos) = pos {
-                                format!(".{pos}")
-                            } else {
-                                String::new()
-                            }
-                        )));
-                    } else {
-                        body.push(Chunk::Custom(format!(
-                            "let callback = &callback{};",
-                            if let Some(pos) = pos {
-                                format!(".{pos}")
-                            } else {
-                                String::new()
-                            }
-                        )));
-                    }
-                } else if !trampoline.scope.is_some_and(|s| s.is_async()) && trampoline.nullable {
-                    body.push(Chunk::Custom(format!(
-                        "if let Some(ref {}callback) = {} {{",
-                        if trampoline.scope.is_some_and(|s| s.is_call()) {
-                            "mut "
-                        } else {
-                            ""
-                        },
-                        if let Some(pos) = pos {
-                            format!("(*callback).{pos}")
-                        } else {
-                            "*callback".to_owned()
-                        }
-                    )));
-                }
-            }
-        } else {
-            body.push(Chunk::Let {
-                name: format!("{}callback", if is_destroy { "_" } else { "" }),
-                is_mut: false,
-                value: Box::new(Chunk::Custom(
-                    if is_destroy || trampoline.scope.is_some_and(|s| s.is_async()) {
-                        format!("Box_::from_raw({} as *mut {})", func, trampoline.bound_name)
-                    } else if trampoline.scope.is_some_and(|s| s.is_call()) {
-                        format!("{} as *mut {}", func, trampoline.bound_name)
-                    } else {
-                        format!("&*({} as *mut {})", func, trampoline.bound_name)
-                    },
-                )),
-                type_: None,
-            });
-            if !is_destroy && trampoline.nullable {
-                if trampoline.scope.is_some_and(|s| s.is_async()) {
-                    body.push(Chunk::Custom(
-                        "let callback = (*callback).expect(\"cannot get closure...\");".to_owned(),
-                    ));
-                } else {
-                    body.push(Chunk::Custom(format!(
-                        "if let Some(ref {}callback) = {} {{",
-                        if trampoline.scope.is_some_and(|s| s.is_call()) {
-                            "mut "
-                        } else {
-                            ""
-                        },
-                        if let Some(pos) = pos {
-                            format!("(*callback).{pos}")
-                        } else {
-                            "*callback".to_owned()
-                        }
-                    )));
-                }
-            }
-        }
-        if !is_destroy {
-            use crate::writer::to_code::ToCode;
-            body.push(Chunk::Custom(format!(
-                "{}({})",
-                if !trampoline.nullable {
-                    "(*callback)"
-                } else if trampoline.scope.is_some_and(|s| s.is_async()) {
-                    "callback"
-                } else {
-                    "\tcallback"
-                },
-                arguments
-                    .iter()
-                    .flat_map(|arg| arg.to_code(env))
-                    .collect::<Vec<_>>()
-                    .join(", "),
+                (
+                    trampoline.name.clone(),
+                    callbacks::trampoline_name(env, tid),
+                )
+            };
+            chunks.push(Chunk::Custom(format!(
+                "let {name} = Some(super::callbacks::{func}::<{}> as _);",
+                trampoline.bound_name
             )));
-            if !trampoline.scope.is_some_and(|s| s.is_async()) && trampoline.nullable {
-                body.push(Chunk::Custom("} else {".to_owned()));
-                body.push(Chunk::Custom(
-                    "\tpanic!(\"cannot get closure...\")".to_owned(),
-                ));
-                body.push(Chunk::Custom("}".to_owned()));
-            }
-            if trampoline.ret.c_type() != "void" {
-                use crate::codegen::trampoline_to_glib::TrampolineToGlib;
-
-                body.push(Chunk::Custom(trampoline.ret.trampoline_to_glib(env)));
-            }
+            return;
         }
 
-        let extern_func = Chunk::ExternCFunc {
-            name: format!("{}_func", trampoline.name),
-            parameters: trampoline
-                .parameters
-                .c_parameters
-                .iter()
-                .skip(1) // to skip the generated this
-                .map(|p| {
-                    if p.is_real_gpointer(env) {
-                        Param {
-                            name: p.name.clone(),
-                            typ: use_glib_if_needed(env, "ffi::gpointer"),
-                        }
-                    } else {
-                        Param {
-                            name: p.name.clone(),
-                            typ: crate::analysis::ffi_type::ffi_type(env, p.typ, &p.c_type)
-                                .expect("failed to write c_type")
-                                .into_string(),
-                        }
-                    }
-                })
-                .collect::<Vec<_>>(),
-            body: Box::new(Chunk::Chunks(body)),
-            return_value: if trampoline.ret.c_type() != "void" {
-                let p = &trampoline.ret;
-                Some(
-                    crate::analysis::ffi_type::ffi_type(env, p.typ(), p.c_type())
-                        .expect("failed to write c_type")
-                        .into_string(),
-                )
-            } else {
-                None
-            },
-            bounds: bounds.to_owned(),
-        };
-
+        let extern_func = trampoline_func(
+            env,
+            trampoline,
+            full_type,
+            pos,
+            &format!("{}_func", trampoline.name),
+            bounds,
+            Visibility::Private,
+            is_destroy,
+        );
         chunks.push(extern_func);
         let bounds_str = if bounds_names.is_empty() {
             String::new()
@@ -928,6 +743,7 @@ impl Builder {
                 "{}<{}: {}>",
                 trampoline.name, trampoline.bound_name, trampoline.callback_type
             ),
+            visibility: Visibility::Private,
             parameters,
             body: Box::new(Chunk::Chunks(body.clone())),
             return_value: None,
@@ -1105,10 +921,70 @@ impl Builder {
         }
         uninitialized_vars
     }
+    /// Converts the parameters that need a temporary C value, returning the
+    /// statements to run after the call.
+    fn write_converted_parameters(&self, body: &mut Vec<Chunk>, env: &Env) -> Vec<Chunk> {
+        let mut after_call = Vec::new();
+        for trans in &self.transformations {
+            let In = self.parameters[trans.ind_c] else {
+                continue;
+            };
+            match trans.transformation_type {
+                TransformationType::ToGlibContainer {
+                    ref name,
+                    typ,
+                    transfer,
+                } => {
+                    let (value, unref) = match env.type_(typ) {
+                        library::Type::Array(..) => {
+                            (garray_to_glib(env, typ, name), "ffi::g_array_unref")
+                        }
+                        _ => (
+                            hash_table_to_glib(env, typ, name),
+                            "ffi::g_hash_table_unref",
+                        ),
+                    };
+                    body.push(Chunk::Let {
+                        name: name.clone(),
+                        is_mut: false,
+                        value: Box::new(Chunk::Custom(value)),
+                        type_: None,
+                    });
+                    if transfer == gir_parser::TransferOwnership::None {
+                        after_call.push(Chunk::Custom(format!(
+                            "{}({name});",
+                            use_glib_type(env, unref)
+                        )));
+                    }
+                }
+                TransformationType::ToGlibScalarArray {
+                    ref name,
+                    mut_ptr,
+                    caller_allocates,
+                } => {
+                    body.push(Chunk::Let {
+                        name: format!("{name}_glib"),
+                        is_mut: mut_ptr,
+                        value: Box::new(Chunk::Custom(format!(
+                            "{name}.iter().map(|e| e.into_glib()).collect::<Vec<_>>()"
+                        ))),
+                        type_: None,
+                    });
+                    if caller_allocates {
+                        after_call.push(Chunk::Custom(format!(
+                            "for (e, glib_e) in {name}.iter_mut().zip({name}_glib) {{ *e = from_glib(glib_e); }}"
+                        )));
+                    }
+                }
+                _ => (),
+            }
+        }
+        after_call
+    }
     fn get_uninitialized(&self, mem_mode: &OutMemMode) -> Chunk {
         use self::OutMemMode::*;
         match mem_mode {
-            Uninitialized => Chunk::Uninitialized,
+            Uninitialized | UninitializedArray => Chunk::Uninitialized,
             UninitializedNamed(name) => Chunk::UninitializedNamed { name: name.clone() },
             NullPtr => Chunk::NullPtr,
             NullMutPtr => Chunk::NullMutPtr,
@@ -1152,7 +1028,7 @@ impl Builder {
         uninitialized_vars: &mut Vec<(String, bool)>,
     ) -> Chunk {
         let value = self.generate_initialized_value(&parameter.name, uninitialized_vars);
-        if let OutMemMode::UninitializedNamed(_) = mem_mode {
+        if let OutMemMode::UninitializedNamed(_) | OutMemMode::UninitializedArray = mem_mode {
             value
         } else {
             let array_length_name = self.find_array_length_name(&parameter.name);
@@ -1280,6 +1156,7 @@ fn c_type_mem_mode_lib(
 ) -> OutMemMode {
     use self::OutMemMode::*;
     match ConversionType::of(env, typ) {
+        ConversionType::Pointer if is_direct_fixed_array(env, typ) => UninitializedArray,
         ConversionType::Pointer => {
             if caller_allocates {
                 UninitializedNamed(RustType::try_new(env, typ).unwrap().into_string())
@@ -1412,3 +1289,308 @@ fn add_chunk_for_type(
         }
     }
 }
+
+/// Builds the `extern "C"` function calling the Rust closure stored in the
+/// user data of a callback.
+pub fn trampoline_func(
+    env: &Env,
+    trampoline: &Trampoline,
+    full_type: &Option<(String, String)>,
+    pos: Option<usize>,
+    name: &str,
+    bounds: &str,
+    visibility: Visibility,
+    is_destroy: bool,
+) -> Chunk {
+    let mut body = Vec::new();
+    let mut arguments = Vec::new();
+
+    for par in &trampoline.parameters.transformations {
+        if par.name == "this" || trampoline.parameters.c_parameters[par.ind_c].is_real_gpointer(env)
+        {
+            continue;
+        }
+        let ty_name = match RustType::try_new(env, par.typ) {
+            Ok(x) => x.into_string(),
+            _ => String::new(),
+        };
+        let nullable = trampoline.parameters.rust_parameters[par.ind_rust].nullable;
+        let is_basic = add_chunk_for_type(env, par.typ, par, &mut body, &ty_name, nullable);
+        if is_gstring(&ty_name) {
+            if nullable {
+                arguments.push(Chunk::Name(format!(
+                    "(*{}).as_ref().map(|s| s.as_str())",
+                    par.name
+                )));
+            } else {
+                arguments.push(Chunk::Name(format!("{}.as_str()", par.name)));
+            }
+            continue;
+        }
+        if nullable && !is_basic {
+            arguments.push(Chunk::Name(format!("{}.as_ref().as_ref()", par.name)));
+            continue;
+        }
+        arguments.push(Chunk::Name(format!(
+            "{}{}",
+            if is_basic { "" } else { "&" },
+            par.name
+        )));
+    }
+
+    let func = trampoline
+        .parameters
+        .c_parameters
+        .last()
+        .map_or_else(|| "Unknown".to_owned(), |p| p.name.clone());
+
+    if let Some(full_type) = full_type {
+        if is_destroy || trampoline.scope.is_some_and(|s| s.is_async()) {
+            body.push(Chunk::Let {
+                name: format!("{}callback", if is_destroy { "_" } else { "" }),
+                is_mut: false,
+                value: Box::new(Chunk::Custom(format!("Box_::from_raw({func} as *mut _)"))),
+                type_: Some(Box::new(Chunk::Custom(full_type.1.clone()))),
+            });
+        } else {
+            body.push(Chunk::Let {
+                name: "callback".to_owned(),
+                is_mut: false,
+                value: Box::new(Chunk::Custom(format!(
+                    "{}*({} as *mut _)",
+                    if !trampoline.scope.is_some_and(|s| s.is_call()) {
+                        "&"
+                    } else if pos.is_some() {
+                        "&mut "
+                    } else {
+                        ""
+                    },
+                    func
+                ))),
+                type_: Some(Box::new(Chunk::Custom(
+                    if !trampoline.scope.is_some_and(|s| s.is_async())
+                        && !trampoline.scope.is_some_and(|s| s.is_call())
+                    {
+                        format!(
+                            "&{}",
+                            full_type
+                                .1
+                                .strip_prefix("Box_<")
+                                .unwrap()
+                                .strip_suffix(">")
+                                .unwrap()
+                        )
+                    } else {
+                        full_type.1.clone()
+                    },
+                ))),
+            });
+            if trampoline.scope.is_some_and(|s| s.is_async()) {
+                body.push(Chunk::Custom(format!(
+                    "let callback = callback{}{};",
+                    if let Some(pos) = pos {
+                        format!(".{pos}")
+                    } else {
+                        String::new()
+                    },
+                    if trampoline.nullable {
+                        ".expect(\"cannot get closure...\")"
+                    } else {
+                        ""
+                    }
+                )));
+            } else if !trampoline.scope.is_some_and(|s| s.is_call()) {
+                if trampoline.nullable {
+                    body.push(Chunk::Custom(format!(
+                        "if let Some(ref callback) = callback{} {{",
+                        if let Some(pos) = pos {
+                            format!(".{pos}")
+                        } else {
+                            String::new()
+                        }
+                    )));
+                } else {
+                    body.push(Chunk::Custom(format!(
+                        "let callback = &callback{};",
+                        if let Some(pos) = pos {
+                            format!(".{pos}")
+                        } else {
+                            String::new()
+                        }
+                    )));
+                }
+            } else if !trampoline.scope.is_some_and(|s| s.is_async()) && trampoline.nullable {
+                body.push(Chunk::Custom(format!(
+                    "if let Some(ref {}callback) = {} {{",
+                    if trampoline.scope.is_some_and(|s| s.is_call()) {
+                        "mut "
+                    } else {
+                        ""
+                    },
+                    if let Some(pos) = pos {
+                        format!("(*callback).{pos}")
+                    } else {
+                        "*callback".to_owned()
+                    }
+                )));
+            }
+        }
+    } else {
+        body.push(Chunk::Let {
+            name: format!("{}callback", if is_destroy { "_" } else { "" }),
+            is_mut: false,
+            value: Box::new(Chunk::Custom(
+                if is_destroy || trampoline.scope.is_some_and(|s| s.is_async()) {
+                    format!("Box_::from_raw({} as *mut {})", func, trampoline.bound_name)
+                } else if trampoline.scope.is_some_and(|s| s.is_call()) {
+                    format!("{} as *mut {}", func, trampoline.bound_name)
+                } else {
+                    format!("&*({} as *mut {})", func, trampoline.bound_name)
+                },
+            )),
+            type_: None,
+        });
+        if !is_destroy && trampoline.nullable {
+            if trampoline.scope.is_some_and(|s| s.is_async()) {
+                body.push(Chunk::Custom(
+                    "let callback = (*callback).expect(\"cannot get closure...\");".to_owned(),
+                ));
+            } else {
+                body.push(Chunk::Custom(format!(
+                    "if let Some(ref {}callback) = {} {{",
+                    if trampoline.scope.is_some_and(|s| s.is_call()) {
+                        "mut "
+                    } else {
+                        ""
+                    },
+                    if let Some(pos) = pos {
+                        format!("(*callback).{pos}")
+                    } else {
+                        "*callback".to_owned()
+                    }
+                )));
+            }
+        }
+    }
+    if !is_destroy {
+        use crate::writer::to_code::ToCode;
+        body.push(Chunk::Custom(format!(
+            "{}({})",
+            if !trampoline.nullable {
+                "(*callback)"
+            } else if trampoline.scope.is_some_and(|s| s.is_async()) {
+                "callback"
+            } else {
+                "\tcallback"
+            },
+            arguments
+                .iter()
+                .flat_map(|arg| arg.to_code(env))
+                .collect::<Vec<_>>()
+                .join(", "),
+        )));
+        if !trampoline.scope.is_some_and(|s| s.is_async()) && trampoline.nullable {
+            body.push(Chunk::Custom("} else {".to_owned()));
+            body.push(Chunk::Custom(
+                "\tpanic!(\"cannot get closure...\")".to_owned(),
+            ));
+            body.push(Chunk::Custom("}".to_owned()));
+        }
+        if trampoline.ret.c_type() != "void" {
+            use crate::codegen::trampoline_to_glib::TrampolineToGlib;
+
+            body.push(Chunk::Custom(trampoline.ret.trampoline_to_glib(env)));
+        }
+    }
+
+    Chunk::ExternCFunc {
+        name: name.to_owned(),
+        visibility,
+        parameters: trampoline
+            .parameters
+            .c_parameters
+            .iter()
+            .skip(1) // to skip the generated this
+            .map(|p| {
+                if p.is_real_gpointer(env) {
+                    Param {
+                        name: p.name.clone(),
+                        typ: use_glib_if_needed(env, "ffi::gpointer"),
+                    }
+                } else {
+                    Param {
+                        name: p.name.clone(),
+                        typ: crate::analysis::ffi_type::ffi_type(env, p.typ, &p.c_type)
+                            .expect("failed to write c_type")
+                            .into_string(),
+                    }
+                }
+            })
+            .collect::<Vec<_>>(),
+        body: Box::new(Chunk::Chunks(body)),
+        return_value: if trampoline.ret.c_type() != "void" {
+            let p = &trampoline.ret;
+            Some(
+                crate::analysis::ffi_type::ffi_type(env, p.typ(), p.c_type())
+                    .expect("failed to write c_type")
+                    .into_string(),
+            )
+        } else {
+            None
+        },
+        bounds: bounds.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{analysis::callbacks::tests::analyzed_env, codegen::function};
+
+    fn generate(env: &Env) -> String {
+        let mut w = Vec::new();
+        for analysis in &env.analysis.global_functions.as_ref().unwrap().functions {
+            function::generate(&mut w, env, None, analysis, None, None, false, false, 0).unwrap();
+        }
+        String::from_utf8(w).unwrap()
+    }
+
+    #[test]
+    fn inline_trampoline() {
+        let code = generate(&analyzed_env("inline", ""));
+        assert!(code.contains(
+            "pub fn add_tick<P: Fn(i64) -> bool + Send + Sync + 'static>(callback: P) {"
+        ));
+        assert!(code.contains(
+            "unsafe extern \"C\" fn callback_func<P: Fn(i64) -> bool + Send + Sync + 'static>\
+             (time: i64, user_data: glib::ffi::gpointer) -> glib::ffi::gboolean { unsafe {\n        \
+             let callback = &*(user_data as *mut P);\n        (*callback)(time)\n        .into_glib()\n    }}"
+        ));
+        assert_eq!(
+            code.matches("let callback = Some(callback_func::<P> as _);")
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn shared_trampoline() {
+        let code = generate(&analyzed_env("shared-body", "shared_callbacks = true\n"));
+        assert!(
+            code.contains(
+                "pub fn add_tick<P: TickCallback + Send + Sync + 'static>(callback: P) {"
+            )
+        );
+        assert!(code.contains(
+            "pub fn add_late_tick<P: TickCallback + Send + Sync + 'static>(priority: i32, callback: P) {"
+        ));
+        assert_eq!(
+            code.matches(
+                "let callback = Some(super::callbacks::tick_callback_trampoline::<P> as _);"
+            )
+            .count(),
+            2
+        );
+        assert!(!code.contains("extern \"C\""));
+    }
+}
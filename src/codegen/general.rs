@@ -179,6 +179,9 @@ pub fn define_fundamental_type(
     glib_func_name: &str,
     ref_func: Option<&str>,
     unref_func: Option<&str>,
+    set_value_func: Option<&str>,
+    get_value_func: Option<&str>,
+    param_spec_func: Option<&str>,
     parents: &[StatusedTypeId],
     visibility: Visibility,
     type_id: TypeId,
@@ -251,9 +254,196 @@ pub fn define_fundamental_type(
     )?;
     writeln!(w, "\t}}")?;
     writeln!(w, "}}")?;
+
+    let value_funcs = if let (Some(set_func), Some(get_func)) = (set_value_func, get_value_func) {
+        Some((
+            set_func.to_owned(),
+            get_func.to_owned(),
+            sys_crate_name.clone(),
+        ))
+    } else {
+        // the value functions are usually only annotated on the super-type
+        parents
+            .iter()
+            .find_map(|p| match env.library.type_(p.type_id) {
+                crate::library::Type::Class(class) => Some((
+                    class.set_value_fn.clone()?,
+                    class.get_value_fn.clone()?,
+                    env.sys_crate_import(p.type_id),
+                )),
+                _ => None,
+            })
+    };
+    if let Some((set_func, get_func, ffi_crate_name)) = value_funcs {
+        define_fundamental_value_type(
+            w,
+            env,
+            type_name,
+            &format!("{sys_crate_name}::{glib_name}"),
+            &format!("{ffi_crate_name}::{set_func}"),
+            &format!("{ffi_crate_name}::{get_func}"),
+        )?;
+    }
+    if let Some(param_spec_func) = param_spec_func {
+        define_fundamental_param_spec(
+            w,
+            env,
+            type_name,
+            &format!("{sys_crate_name}::{param_spec_func}"),
+            visibility,
+        )?;
+    }
     Ok(())
 }
 
+/// Implements the `Value` traits of a fundamental type with the
+/// `glib:set-value-func` and `glib:get-value-func` of its hierarchy.
+fn define_fundamental_value_type(
+    w: &mut dyn Write,
+    env: &Env,
+    type_name: &str,
+    c_type: &str,
+    set_func: &str,
+    get_func: &str,
+) -> Result<()> {
+    // The accessors take the pointer of the super-type, or a gpointer
+    writeln!(
+        w,
+        "
+impl {valuetype} for {type_name} {{
+    type Type = Self;
+}}
+
+impl {valuetype_optional} for {type_name} {{}}
+
+unsafe impl<'a> {from_value}<'a> for {type_name} {{
+    type Checker = {checker}<Self>;
+
+    #[inline]
+    unsafe fn from_value(value: &'a {gvalue}) -> Self {{
+        unsafe {{ from_glib_none({get_func}(value.to_glib_none().0) as *mut {c_type}) }}
+    }}
+}}
+
+impl {to_value} for {type_name} {{
+    #[inline]
+    fn to_value(&self) -> {gvalue} {{
+        let mut value = {gvalue}::for_value_type::<Self>();
+        unsafe {{
+            {set_func}(value.to_glib_none_mut().0, self.as_ptr() as *mut _);
+        }}
+        value
+    }}
+
+    #[inline]
+    fn value_type(&self) -> {gtype} {{
+        <Self as {static_type}>::static_type()
+    }}
+}}
+
+impl {to_value_optional} for {type_name} {{
+    #[inline]
+    fn to_value_optional(s: Option<&Self>) -> {gvalue} {{
+        s.map_or_else({gvalue}::for_value_type::<Self>, {to_value}::to_value)
+    }}
+}}
+
+impl From<{type_name}> for {gvalue} {{
+    #[inline]
+    fn from(v: {type_name}) -> Self {{
+        {to_value}::to_value(&v)
+    }}
+}}",
+        valuetype = use_glib_type(env, "value::ValueType"),
+        valuetype_optional = use_glib_type(env, "value::ValueTypeOptional"),
+        from_value = use_glib_type(env, "value::FromValue"),
+        checker = use_glib_type(env, "value::GenericValueTypeOrNoneChecker"),
+        to_value = use_glib_type(env, "value::ToValue"),
+        to_value_optional = use_glib_type(env, "value::ToValueOptional"),
+        static_type = use_glib_type(env, "types::StaticType"),
+        gvalue = use_glib_type(env, "Value"),
+        gtype = use_glib_type(env, "Type"),
+    )
+}
+
+/// Implements `HasParamSpec` for a fundamental type with the function
+/// creating its `GParamSpec` subtype, through a `ParamSpec{type_name}Builder`.
+fn define_fundamental_param_spec(
+    w: &mut dyn Write,
+    env: &Env,
+    type_name: &str,
+    param_spec_func: &str,
+    visibility: Visibility,
+) -> Result<()> {
+    writeln!(
+        w,
+        "
+impl {has_param_spec} for {type_name} {{
+    type ParamSpec = {param_spec};
+    type SetValue = Self;
+    type BuilderFn = fn(&str) -> ParamSpec{type_name}Builder<'_>;
+
+    fn param_spec_builder() -> Self::BuilderFn {{
+        ParamSpec{type_name}Builder::new
+    }}
+}}
+
+/// A builder for the [`{param_spec}`] of [`{type_name}`] properties.
+#[must_use = \"The builder must be built to be used\"]
+{visibility} struct ParamSpec{type_name}Builder<'a> {{
+    name: &'a str,
+    nick: Option<&'a str>,
+    blurb: Option<&'a str>,
+    flags: {param_flags},
+}}
+
+impl<'a> ParamSpec{type_name}Builder<'a> {{
+    fn new(name: &'a str) -> Self {{
+        Self {{
+            name,
+            nick: None,
+            blurb: None,
+            flags: {param_flags}::default(),
+        }}
+    }}
+
+    #[must_use]
+    {visibility} fn build(self) -> {param_spec} {{
+        unsafe {{
+            from_glib_none({param_spec_func}(
+                self.name.to_glib_none().0,
+                self.nick.to_glib_none().0,
+                self.blurb.to_glib_none().0,
+                self.flags.into_glib(),
+            ))
+        }}
+    }}
+}}
+
+impl<'a> {builder_ext}<'a> for ParamSpec{type_name}Builder<'a> {{
+    fn set_nick(&mut self, nick: Option<&'a str>) {{
+        self.nick = nick;
+    }}
+
+    fn set_blurb(&mut self, blurb: Option<&'a str>) {{
+        self.blurb = blurb;
+    }}
+
+    fn set_flags(&mut self, flags: {param_flags}) {{
+        self.flags = flags;
+    }}
+
+    fn current_flags(&self) -> {param_flags} {{
+        self.flags
+    }}
+}}",
+        has_param_spec = use_glib_type(env, "HasParamSpec"),
+        param_spec = use_glib_type(env, "ParamSpec"),
+        param_flags = use_glib_type(env, "ParamFlags"),
+        builder_ext = use_glib_type(env, "ParamSpecBuilderExt"),
+    )
+}
+
 pub fn define_object_type(
     w: &mut dyn Write,
     env: &Env,
@@ -1074,6 +1264,26 @@ pub fn declare_default_from_new(
     Ok(())
 }
 
+pub fn declare_default(
+    w: &mut dyn Write,
+    env: &Env,
+    name: &str,
+    default_impl: &analysis::default_impl::Info,
+) -> Result<()> {
+    writeln!(w)?;
+    version_condition(w, env, None, default_impl.version, false, 0)?;
+    cfg_condition(w, default_impl.cfg_condition.as_ref(), false, 0)?;
+    writeln!(
+        w,
+        "impl Default for {name} {{
+             fn default() -> Self {{
+                 {}
+             }}
+         }}",
+        default_impl.body
+    )
+}
+
 /// Escapes string in format suitable for placing inside double quotes.
 pub fn escape_string(s: &str) -> String {
     let mut es = String::with_capacity(s.len() * 2);
@@ -1089,7 +1299,181 @@ pub fn escape_string(s: &str) -> String {
 
 #[cfg(test)]
 mod tests {
+    use std::cell::RefCell;
+
     use super::*;
+    use crate::{
+        analysis::{class_hierarchy, symbols},
+        config::gobjects::GStatus,
+        library::{Class, Library, MAIN_NAMESPACE, Type},
+    };
+
+    fn fundamental_env(library_name: &str) -> (Env, TypeId, TypeId) {
+        let mut library = Library::new(library_name);
+        library.add_namespace("GLib", true);
+        let expression = library.add_type(
+            MAIN_NAMESPACE,
+            "Expression",
+            Type::Class(Class {
+                name: "Expression".to_owned(),
+                c_type: "TestExpression".to_owned(),
+                glib_get_type: "test_expression_get_type".to_owned(),
+                is_fundamental: true,
+                ref_fn: Some("test_expression_ref".to_owned()),
+                unref_fn: Some("test_expression_unref".to_owned()),
+                set_value_fn: Some("test_value_set_expression".to_owned()),
+                get_value_fn: Some("test_value_get_expression".to_owned()),
+                ..Default::default()
+            }),
+        );
+        let constant = library.add_type(
+            MAIN_NAMESPACE,
+            "ConstantExpression",
+            Type::Class(Class {
+                name: "ConstantExpression".to_owned(),
+                c_type: "TestConstantExpression".to_owned(),
+                glib_get_type: "test_constant_expression_get_type".to_owned(),
+                parent: Some(expression),
+                is_fundamental: true,
+                ..Default::default()
+            }),
+        );
+
+        let dir = std::env::temp_dir().join(format!("gir-fundamental-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let config_file = dir.join("Gir.toml");
+        std::fs::write(
+            &config_file,
+            format!(
+                "[options]\nwork_mode = \"normal\"\nlibrary = \"{library_name}\"\n\
+                 version = \"1.0\"\ntarget_path = \".\"\ngirs_directories = []\n"
+            ),
+        )
+        .unwrap();
+        let config = Config::new(
+            config_file.to_str(),
+            None,
+            &[],
+            None,
+            None,
+            None,
+            None,
+            false,
+            false,
+            false,
+            false,
+        )
+        .unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let namespaces = namespaces::run(&library);
+        let symbols = symbols::run(&library, &namespaces);
+        let class_hierarchy = class_hierarchy::run(&library);
+        let env = Env {
+            library,
+            config,
+            namespaces,
+            symbols: RefCell::new(symbols),
+            class_hierarchy,
+            analysis: Default::default(),
+        };
+        (env, expression, constant)
+    }
+
+    fn generate_fundamental(
+        env: &Env,
+        type_id: TypeId,
+        param_spec_func: Option<&str>,
+        parents: &[StatusedTypeId],
+    ) -> String {
+        let Type::Class(class) = env.library.type_(type_id) else {
+            unreachable!();
+        };
+        let mut w = Vec::new();
+        define_fundamental_type(
+            &mut w,
+            env,
+            &class.name,
+            &class.c_type,
+            &class.glib_get_type,
+            class.ref_fn.as_deref(),
+            class.unref_fn.as_deref(),
+            class.set_value_fn.as_deref(),
+            class.get_value_fn.as_deref(),
+            param_spec_func,
+            parents,
+            Visibility::Public,
+            type_id,
+        )
+        .unwrap();
+        String::from_utf8(w).unwrap()
+    }
+
+    #[test]
+    fn fundamental_value_type() {
+        let (env, expression, constant) = fundamental_env("Test");
+
+        let code = generate_fundamental(&env, expression, None, &[]);
+        assert!(code.contains("impl glib::value::ValueType for Expression {"));
+        assert!(code.contains("impl glib::value::ToValueOptional for Expression {"));
+        assert!(code.contains(
+            "from_glib_none(ffi::test_value_get_expression(value.to_glib_none().0) as *mut ffi::TestExpression)"
+        ));
+        assert!(code.contains(
+            "ffi::test_value_set_expression(value.to_glib_none_mut().0, self.as_ptr() as *mut _);"
+        ));
+
+        let parents = [StatusedTypeId {
+            type_id: expression,
+            name: "Expression".to_owned(),
+            status: GStatus::Generate,
+        }];
+        let code = generate_fundamental(&env, constant, None, &parents);
+        assert!(code.contains("impl glib::value::ValueType for ConstantExpression {"));
+        assert!(code.contains("impl From<ConstantExpression> for glib::Value {"));
+        assert!(code.contains(
+            "from_glib_none(ffi::test_value_get_expression(value.to_glib_none().0) as *mut ffi::TestConstantExpression)"
+        ));
+        assert!(code.contains(
+            "ffi::test_value_set_expression(value.to_glib_none_mut().0, self.as_ptr() as *mut _);"
+        ));
+    }
+
+    #[test]
+    fn fundamental_value_type_in_glib() {
+        let (env, expression, _) = fundamental_env("GObject");
+
+        let code = generate_fundamental(&env, expression, None, &[]);
+        assert!(code.contains("impl crate::value::ToValue for Expression {"));
+        assert!(code.contains("<Self as crate::types::StaticType>::static_type()"));
+        assert!(
+            code.contains("crate::Value::for_value_type::<Self>, crate::value::ToValue::to_value")
+        );
+        assert!(!code.contains("glib::"));
+    }
+
+    #[test]
+    fn fundamental_param_spec() {
+        let (env, expression, _) = fundamental_env("Test");
+
+        let code = generate_fundamental(&env, expression, None, &[]);
+        assert!(!code.contains("HasParamSpec"));
+
+        let code = generate_fundamental(&env, expression, Some("test_param_spec_expression"), &[]);
+        assert!(code.contains("impl glib::HasParamSpec for Expression {"));
+        assert!(code.contains("type BuilderFn = fn(&str) -> ParamSpecExpressionBuilder<'_>;"));
+        assert!(code.contains("pub struct ParamSpecExpressionBuilder<'a> {"));
+        assert!(code.contains(
+            "from_glib_none(ffi::test_param_spec_expression(\n                \
+             self.name.to_glib_none().0,\n                \
+             self.nick.to_glib_none().0,\n                \
+             self.blurb.to_glib_none().0,\n                \
+             self.flags.into_glib(),\n            ))"
+        ));
+        assert!(code.contains(
+            "impl<'a> glib::ParamSpecBuilderExt<'a> for ParamSpecExpressionBuilder<'a> {"
+        ));
+    }
 
     #[test]
     fn test_escape_string() {
@@ -0,0 +1,359 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io::{Result, Write},
+};
+
+use log::info;
+
+use crate::{
+    analysis::namespaces,
+    env::Env,
+    file_saver::save_to_file,
+    library::{self, Function, Type, TypeId},
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+enum EdgeKind {
+    Parent,
+    Implements,
+    Prerequisite,
+    Uses,
+}
+
+impl EdgeKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Parent => "parent",
+            Self::Implements => "implements",
+            Self::Prerequisite => "prerequisite",
+            Self::Uses => "uses",
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Node {
+    name: String,
+    namespace: String,
+    kind: &'static str,
+    status: &'static str,
+}
+
+#[derive(Debug, Default)]
+struct Graph {
+    nodes: BTreeMap<TypeId, Node>,
+    edges: BTreeSet<(TypeId, TypeId, EdgeKind)>,
+}
+
+pub fn generate(env: &Env) {
+    let graph = build_graph(env);
+    let base_name = env.config.library_full_name();
+
+    let path = env.config.target_path.join(format!("{base_name}.dot"));
+    info!("Generating type graph {path:?}");
+    save_to_file(&path, env.config.make_backup, |w| write_dot(w, env, &graph));
+
+    let path = env.config.target_path.join(format!("{base_name}.json"));
+    info!("Generating type graph {path:?}");
+    save_to_file(&path, env.config.make_backup, |w| {
+        write_json(w, env, &graph)
+    });
+}
+
+fn build_graph(env: &Env) -> Graph {
+    let mut graph = Graph::default();
+
+    for (tid, type_) in env.library.namespace_types(namespaces::MAIN) {
+        if !matches!(type_, Type::Class(_) | Type::Interface(_)) {
+            continue;
+        }
+        graph.add_node(env, tid);
+        graph.add_direct_supertypes(env, tid);
+
+        // Only the bound types matter for the cross-namespace dependencies
+        if env.type_status(&tid.full_name(&env.library)).ignored() {
+            continue;
+        }
+        for func in type_.functions() {
+            graph.add_uses(env, tid, func);
+        }
+    }
+
+    // Supertypes from other namespaces are already in the graph now, complete
+    // their own hierarchy so that the full chain up to the root is visible.
+    let external: Vec<_> = graph
+        .nodes
+        .keys()
+        .copied()
+        .filter(|tid| tid.ns_id != namespaces::MAIN)
+        .collect();
+    for tid in external {
+        let supertypes = env.class_hierarchy.supertypes(tid);
+        for &tid in std::iter::once(&tid).chain(supertypes) {
+            graph.add_direct_supertypes(env, tid);
+        }
+    }
+
+    graph
+}
+
+impl Graph {
+    fn add_node(&mut self, env: &Env, tid: TypeId) {
+        if self.nodes.contains_key(&tid) {
+            return;
+        }
+        let type_ = env.type_(tid);
+        let kind = match type_ {
+            Type::Class(_) => "class",
+            Type::Interface(_) => "interface",
+            Type::Record(_) => "record",
+            Type::Union(_) => "union",
+            Type::Enumeration(_) => "enum",
+            Type::Bitfield(_) => "flags",
+            Type::Alias(_) => "alias",
+            Type::Function(_) => "callback",
+            _ => "other",
+        };
+        let status = if tid.ns_id == namespaces::MAIN {
+            let status = env.type_status(&tid.full_name(&env.library));
+            if status.need_generate() {
+                "generate"
+            } else if status.manual() {
+                "manual"
+            } else {
+                "ignore"
+            }
+        } else {
+            "external"
+        };
+        self.nodes.insert(
+            tid,
+            Node {
+                name: type_.get_name(),
+                namespace: env.library.namespace(tid.ns_id).name.clone(),
+                kind,
+                status,
+            },
+        );
+    }
+
+    fn add_edge(&mut self, env: &Env, from: TypeId, to: TypeId, kind: EdgeKind) {
+        self.add_node(env, from);
+        self.add_node(env, to);
+        self.edges.insert((from, to, kind));
+    }
+
+    fn add_direct_supertypes(&mut self, env: &Env, tid: TypeId) {
+        match env.type_(tid) {
+            Type::Class(class) => {
+                if let Some(parent) = class.parent {
+                    self.add_edge(env, tid, parent, EdgeKind::Parent);
+                }
+                for &iface in &class.implements {
+                    self.add_edge(env, tid, iface, EdgeKind::Implements);
+                }
+            }
+            Type::Interface(iface) => {
+                for &prereq in &iface.prerequisites {
+                    self.add_edge(env, tid, prereq, EdgeKind::Prerequisite);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn add_uses(&mut self, env: &Env, from: TypeId, func: &Function) {
+        for tid in func
+            .parameters
+            .iter()
+            .map(library::Parameter::typ)
+            .chain(Some(func.ret.typ()))
+        {
+            let tid = inner_type_id(env, tid);
+            if tid.ns_id == namespaces::MAIN || tid.ns_id == namespaces::INTERNAL {
+                continue;
+            }
+            if matches!(
+                env.type_(tid),
+                Type::Class(_)
+                    | Type::Interface(_)
+                    | Type::Record(_)
+                    | Type::Union(_)
+                    | Type::Enumeration(_)
+                    | Type::Bitfield(_)
+                    | Type::Alias(_)
+            ) {
+                self.add_edge(env, from, tid, EdgeKind::Uses);
+            }
+        }
+    }
+}
+
+/// Unwraps containers (arrays, lists, ...) down to the element type.
+fn inner_type_id(env: &Env, tid: TypeId) -> TypeId {
+    match *env.type_(tid) {
+        Type::Array(t)
+        | Type::CArray(t)
+        | Type::FixedArray(t, ..)
+        | Type::PtrArray(t)
+        | Type::List(t)
+        | Type::SList(t) => inner_type_id(env, t),
+        _ => tid,
+    }
+}
+
+fn full_name(node: &Node) -> String {
+    format!("{}.{}", node.namespace, node.name)
+}
+
+fn write_dot(w: &mut dyn Write, env: &Env, graph: &Graph) -> Result<()> {
+    writeln!(
+        w,
+        "digraph {} {{",
+        dot_quote(&env.config.library_full_name())
+    )?;
+    writeln!(w, "\trankdir=BT;")?;
+    writeln!(w, "\tnode [shape=box, style=filled, fontname=\"sans\"];")?;
+
+    let mut by_namespace: BTreeMap<&str, Vec<&Node>> = BTreeMap::new();
+    for node in graph.nodes.values() {
+        by_namespace.entry(&node.namespace).or_default().push(node);
+    }
+    for (namespace, nodes) in by_namespace {
+        writeln!(w)?;
+        writeln!(
+            w,
+            "\tsubgraph {} {{",
+            dot_quote(&format!("cluster_{namespace}"))
+        )?;
+        writeln!(w, "\t\tlabel={};", dot_quote(namespace))?;
+        for node in nodes {
+            let shape = if node.kind == "interface" {
+                "ellipse"
+            } else {
+                "box"
+            };
+            let (fillcolor, style) = match node.status {
+                "generate" => ("palegreen", "filled"),
+                "manual" => ("lightblue", "filled"),
+                "ignore" => ("lightgrey", "filled,dashed"),
+                _ => ("white", "filled,dotted"),
+            };
+            writeln!(
+                w,
+                "\t\t{} [label={}, shape={shape}, fillcolor={fillcolor}, style={}];",
+                dot_quote(&full_name(node)),
+                dot_quote(&format!("{}\\n({}, {})", node.name, node.kind, node.status)),
+                dot_quote(style),
+            )?;
+        }
+        writeln!(w, "\t}}")?;
+    }
+
+    writeln!(w)?;
+    for (from, to, kind) in &graph.edges {
+        let style = match kind {
+            EdgeKind::Parent => "solid",
+            EdgeKind::Implements | EdgeKind::Prerequisite => "dashed",
+            EdgeKind::Uses => "dotted",
+        };
+        writeln!(
+            w,
+            "\t{} -> {} [label={}, style={style}];",
+            dot_quote(&full_name(&graph.nodes[from])),
+            dot_quote(&full_name(&graph.nodes[to])),
+            dot_quote(kind.as_str()),
+        )?;
+    }
+    writeln!(w, "}}")
+}
+
+fn write_json(w: &mut dyn Write, env: &Env, graph: &Graph) -> Result<()> {
+    writeln!(w, "{{")?;
+    writeln!(
+        w,
+        "\t\"library\": {},",
+        json_quote(&env.config.library_full_name())
+    )?;
+
+    writeln!(w, "\t\"nodes\": [")?;
+    let len = graph.nodes.len();
+    for (i, node) in graph.nodes.values().enumerate() {
+        writeln!(
+            w,
+            "\t\t{{\"name\": {}, \"namespace\": {}, \"kind\": {}, \"status\": {}}}{}",
+            json_quote(&full_name(node)),
+            json_quote(&node.namespace),
+            json_quote(node.kind),
+            json_quote(node.status),
+            if i + 1 < len { "," } else { "" },
+        )?;
+    }
+    writeln!(w, "\t],")?;
+
+    // Adjacency list: every node with its outgoing edges
+    let mut adjacency: BTreeMap<String, Vec<(String, EdgeKind)>> = BTreeMap::new();
+    for (from, to, kind) in &graph.edges {
+        adjacency
+            .entry(full_name(&graph.nodes[from]))
+            .or_default()
+            .push((full_name(&graph.nodes[to]), *kind));
+    }
+    writeln!(w, "\t\"edges\": {{")?;
+    let len = adjacency.len();
+    for (i, (from, targets)) in adjacency.iter().enumerate() {
+        let targets = targets
+            .iter()
+            .map(|(to, kind)| {
+                format!(
+                    "{{\"to\": {}, \"kind\": {}}}",
+                    json_quote(to),
+                    json_quote(kind.as_str())
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(
+            w,
+            "\t\t{}: [{targets}]{}",
+            json_quote(from),
+            if i + 1 < len { "," } else { "" },
+        )?;
+    }
+    writeln!(w, "\t}}")?;
+    writeln!(w, "}}")
+}
+
+fn dot_quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('"', "\\\""))
+}
+
+fn json_quote(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quoting() {
+        assert_eq!(dot_quote("Gtk.Widget"), "\"Gtk.Widget\"");
+        assert_eq!(dot_quote("a\"b"), "\"a\\\"b\"");
+        assert_eq!(json_quote("a\"b\\c"), "\"a\\\"b\\\\c\"");
+        assert_eq!(json_quote("a\nb\u{1}"), "\"a\\nb\\u0001\"");
+    }
+}
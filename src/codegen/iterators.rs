@@ -0,0 +1,129 @@
+use std::{
+    io::{Result, prelude::*},
+    path::Path,
+};
+
+use crate::{
+    analysis::iterators::{Info, Storage},
+    codegen::{
+        function::body_chunk,
+        general::{self, cfg_condition, cfg_condition_string, version_condition},
+        return_value::out_parameters_as_return,
+    },
+    config::gobjects::GObject,
+    env::Env,
+    file_saver,
+    writer::{primitives::tabs, to_code::ToCode},
+};
+
+pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
+    if env.analysis.iterators.is_empty() {
+        return;
+    }
+
+    let path = root_path.join("iterators.rs");
+    file_saver::save_to_file(path, env.config.make_backup, |w| {
+        general::start_comments(w, &env.config)?;
+        general::uses(w, env, &env.analysis.iterator_imports, None)?;
+
+        mod_rs.push("\nmod iterators;".into());
+        for iterator in &env.analysis.iterators {
+            let config = &env.config.objects[&iterator.full_name];
+
+            if let Some(cfg) = cfg_condition_string(config.cfg_condition.as_ref(), false, 0) {
+                mod_rs.push(cfg);
+            }
+            mod_rs.push(format!(
+                "{} use self::iterators::{};",
+                iterator.visibility.export_visibility(),
+                iterator.name
+            ));
+
+            writeln!(w)?;
+            generate_iterator(env, w, config, iterator)?;
+        }
+
+        Ok(())
+    });
+}
+
+fn conditions(env: &Env, w: &mut dyn Write, config: &GObject, info: &Info) -> Result<()> {
+    version_condition(w, env, None, info.version, false, 0)?;
+    cfg_condition(w, config.cfg_condition.as_ref(), false, 0)
+}
+
+fn generate_iterator(env: &Env, w: &mut dyn Write, config: &GObject, info: &Info) -> Result<()> {
+    // The analysis only keeps next functions returning an `Option`
+    let ret = out_parameters_as_return(env, &info.next);
+    let Some(item) = ret
+        .strip_prefix(" -> Option<")
+        .and_then(|s| s.strip_suffix('>'))
+    else {
+        return Ok(());
+    };
+
+    conditions(env, w, config, info)?;
+    writeln!(
+        w,
+        "{} struct {}<'a> {{
+    iter: {},
+    owner: PhantomData<&'a {}>,
+}}",
+        info.visibility, info.name, info.iter_type, info.owner_name
+    )?;
+    writeln!(w)?;
+
+    let init_call = format!("{}({})", info.init, info.init_arguments.join(", "));
+    conditions(env, w, config, info)?;
+    writeln!(w, "impl<'a> {}<'a> {{", info.name)?;
+    writeln!(w, "\t/// Iterates over the contents of `owner`.")?;
+    writeln!(w, "\t///")?;
+    writeln!(w, "\t/// # Safety")?;
+    writeln!(w, "\t///")?;
+    writeln!(
+        w,
+        "\t/// The C iterator points into `owner`, which must not be modified while \
+         the iterator is alive."
+    )?;
+    writeln!(
+        w,
+        "\t{} unsafe fn new(owner: &'a {}) -> Self {{",
+        info.visibility, info.owner_name
+    )?;
+    writeln!(w, "\t\tunsafe {{")?;
+    match info.storage {
+        Storage::Inline => {
+            writeln!(w, "\t\t\tlet mut iter = std::mem::MaybeUninit::uninit();")?;
+            writeln!(w, "\t\t\t{init_call};")?;
+            writeln!(
+                w,
+                "\t\t\tSelf {{ iter: iter.assume_init(), owner: PhantomData }}"
+            )?;
+        }
+        Storage::Boxed => {
+            writeln!(
+                w,
+                "\t\t\tSelf {{ iter: from_glib_full({init_call}), owner: PhantomData }}"
+            )?;
+        }
+    }
+    writeln!(w, "\t\t}}")?;
+    writeln!(w, "\t}}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    conditions(env, w, config, info)?;
+    writeln!(w, "impl Iterator for {}<'_> {{", info.name)?;
+    writeln!(w, "\ttype Item = {item};")?;
+    writeln!(w)?;
+    writeln!(w, "\t#[doc(alias = \"{}\")]", info.next.glib_name)?;
+    writeln!(w, "\tfn next(&mut self) -> Option<Self::Item> {{")?;
+    // The body closes the function
+    for s in body_chunk(env, &info.next, Some(info.owner_id)).to_code(env) {
+        writeln!(w, "{}{}", tabs(1), s)?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
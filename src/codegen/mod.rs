@@ -16,6 +16,7 @@ use crate::{
 
 mod alias;
 mod bound;
+mod callbacks;
 mod child_properties;
 mod constants;
 mod doc;
@@ -25,6 +26,8 @@ pub mod function;
 mod function_body_chunk;
 mod functions;
 mod general;
+mod graph;
+mod iterators;
 mod object;
 mod objects;
 mod parameter;
@@ -36,24 +39,29 @@ mod ref_mode;
 mod return_value;
 mod signal;
 mod signal_body;
+mod signal_stream;
+mod signal_weak;
 mod special_functions;
 mod sys;
 mod trait_impls;
 mod trampoline;
 mod trampoline_from_glib;
+mod unions;
 mod visibility;
 pub use visibility::Visibility;
 mod trampoline_to_glib;
 pub mod translate_from_glib;
 pub mod translate_to_glib;
 
-pub fn generate(env: &Env) {
+pub fn generate(env: &Env) -> std::result::Result<(), String> {
     match env.config.work_mode {
         WorkMode::Normal => normal_generate(env),
         WorkMode::Sys => sys::generate(env),
-        WorkMode::Doc => doc::generate(env),
+        WorkMode::Doc => return doc::generate(env),
+        WorkMode::Graph => graph::generate(env),
         WorkMode::DisplayNotBound => {}
     }
+    Ok(())
 }
 
 fn normal_generate(env: &Env) {
@@ -64,10 +72,15 @@ fn normal_generate(env: &Env) {
 
     generate_single_version_file(env);
     objects::generate(env, root_path, &mut mod_rs, &mut traits, &mut builders);
+    signal_stream::generate(env, root_path, &mut mod_rs);
+    signal_weak::generate(env, root_path, &mut mod_rs);
     records::generate(env, root_path, &mut mod_rs);
     enums::generate(env, root_path, &mut mod_rs);
     flags::generate(env, root_path, &mut mod_rs);
+    unions::generate(env, root_path, &mut mod_rs);
     alias::generate(env, root_path, &mut mod_rs);
+    callbacks::generate(env, root_path, &mut mod_rs);
+    iterators::generate(env, root_path, &mut mod_rs);
     functions::generate(env, root_path, &mut mod_rs);
     constants::generate(env, root_path, &mut mod_rs);
 
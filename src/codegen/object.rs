@@ -81,6 +81,9 @@ pub fn generate(w: &mut dyn Write, env: &Env, analysis: &analysis::object::Info)
                 &analysis.get_type,
                 analysis.ref_fn.as_deref(),
                 analysis.unref_fn.as_deref(),
+                analysis.set_value_fn.as_deref(),
+                analysis.get_value_fn.as_deref(),
+                analysis.param_spec_fn.as_deref(),
                 &analysis.supertypes,
                 analysis.visibility,
                 analysis.type_id,
@@ -215,6 +218,25 @@ pub fn generate(w: &mut dyn Write, env: &Env, analysis: &analysis::object::Info)
             )?;
         }
 
+        for default_const in &analysis.default_consts {
+            writeln!(w)?;
+            general::version_condition(w, env, None, default_const.version, false, 1)?;
+            general::cfg_condition(w, default_const.cfg_condition.as_ref(), false, 1)?;
+            general::cfg_deprecated(
+                w,
+                env,
+                Some(analysis.type_id),
+                default_const.deprecated_version,
+                false,
+                1,
+            )?;
+            writeln!(
+                w,
+                "\tpub const {}: {} = {};",
+                default_const.name, default_const.typ, default_const.value
+            )?;
+        }
+
         for func_analysis in &analysis.constructors() {
             function::generate(
                 w,
@@ -267,6 +289,10 @@ pub fn generate(w: &mut dyn Write, env: &Env, analysis: &analysis::object::Info)
                 properties::generate(w, env, property, false, false, 1)?;
             }
 
+            for binding in &analysis.property_bindings {
+                properties::generate_binding(w, env, binding, false, false, 1)?;
+            }
+
             for child_property in &analysis.child_properties {
                 child_properties::generate(w, env, child_property, false, false, 1)?;
             }
@@ -298,13 +324,19 @@ pub fn generate(w: &mut dyn Write, env: &Env, analysis: &analysis::object::Info)
 
         writeln!(w, "}}")?;
 
-        general::declare_default_from_new(
-            w,
-            env,
-            &analysis.name,
-            &analysis.functions,
-            has_builder_properties(&analysis.builder_properties),
-        )?;
+        if analysis.default_impl.is_none() {
+            general::declare_default_from_new(
+                w,
+                env,
+                &analysis.name,
+                &analysis.functions,
+                has_builder_properties(&analysis.builder_properties),
+            )?;
+        }
+    }
+
+    if let Some(default_impl) = analysis.default_impl.as_ref() {
+        general::declare_default(w, env, &analysis.name, default_impl)?;
     }
 
     trait_impls::generate(
@@ -346,6 +378,11 @@ pub fn generate(w: &mut dyn Write, env: &Env, analysis: &analysis::object::Info)
         writeln!(w)?;
         generate_trait(w, env, analysis)?;
     }
+
+    if analysis.need_generate_class_trait() {
+        writeln!(w)?;
+        generate_class_trait(w, env, analysis)?;
+    }
     Ok(())
 }
 
@@ -447,10 +484,22 @@ fn generate_builder(w: &mut dyn Write, env: &Env, analysis: &analysis::object::I
                 .map(|version| format!("{comment_prefix}{version}\n"))
                 .unwrap_or_default();
 
+            let mut doc_prefix = String::new();
+            if !property.value_doc.is_empty() {
+                doc_prefix.push_str(&format!(
+                    "{comment_prefix}// rustdoc-stripper-ignore-next\n"
+                ));
+                for (i, line) in property.value_doc.iter().enumerate() {
+                    if i > 0 {
+                        doc_prefix.push_str(&format!("{comment_prefix}///\n"));
+                    }
+                    doc_prefix.push_str(&format!("{comment_prefix}/// {line}\n"));
+                }
+            }
             writeln!(
                 w,
                 "
-                        {version_prefix}{deprecation_prefix}    {comment_prefix}pub fn {name}{bounds}(self, {name}: {param_type_str}) -> Self {{
+                        {doc_prefix}{version_prefix}{deprecation_prefix}    {comment_prefix}pub fn {name}{bounds}(self, {name}: {param_type_str}) -> Self {{
                         {comment_prefix}    Self {{ builder: self.builder.property(\"{property_name}\", {name}{conversion}), }}
                         {comment_prefix}}}",
                 property_name = property.name,
@@ -507,6 +556,9 @@ fn generate_trait(w: &mut dyn Write, env: &Env, analysis: &analysis::object::Inf
     for property in &analysis.properties {
         properties::generate(w, env, property, true, false, 1)?;
     }
+    for binding in &analysis.property_bindings {
+        properties::generate_binding(w, env, binding, true, false, 1)?;
+    }
     for child_property in &analysis.child_properties {
         child_properties::generate(w, env, child_property, true, false, 1)?;
     }
@@ -529,6 +581,58 @@ fn generate_trait(w: &mut dyn Write, env: &Env, analysis: &analysis::object::Inf
     Ok(())
 }
 
+fn generate_class_trait(
+    w: &mut dyn Write,
+    env: &Env,
+    analysis: &analysis::object::Info,
+) -> Result<()> {
+    let glib_crate_name = if env.namespaces.is_glib_crate {
+        "crate"
+    } else {
+        "glib"
+    };
+    let trait_name = analysis
+        .class_trait_name()
+        .expect("class methods without class struct");
+
+    write!(w, "pub trait {trait_name}: 'static {{")?;
+    for func_analysis in &analysis.class_methods {
+        function::generate(
+            w,
+            env,
+            Some(analysis.type_id),
+            func_analysis,
+            None,
+            analysis.version,
+            true,
+            true,
+            1,
+        )?;
+    }
+    writeln!(w, "}}")?;
+
+    writeln!(w)?;
+    write!(
+        w,
+        "impl<O: IsA<{name}> + {glib_crate_name}::object::IsClass> {trait_name} for {glib_crate_name}::Class<O> {{",
+        name = analysis.name,
+    )?;
+    for func_analysis in &analysis.class_methods {
+        function::generate(
+            w,
+            env,
+            Some(analysis.type_id),
+            func_analysis,
+            None,
+            analysis.version,
+            true,
+            false,
+            1,
+        )?;
+    }
+    writeln!(w, "}}")
+}
+
 pub fn generate_reexports(
     env: &Env,
     analysis: &analysis::object::Info,
@@ -576,6 +680,17 @@ pub fn generate_reexports(
         ));
     }
 
+    if analysis.need_generate_class_trait() {
+        for cfg in &cfgs {
+            traits.push(format!("\t{cfg}"));
+        }
+        traits.push(format!(
+            "\tpub use super::{}::{};",
+            module_name,
+            analysis.class_trait_name().unwrap(),
+        ));
+    }
+
     if has_builder_properties(&analysis.builder_properties) {
         for cfg in &cfgs {
             builders.push(format!("\t{cfg}"));
@@ -585,4 +700,14 @@ pub fn generate_reexports(
             module_name, analysis.name
         ));
     }
+
+    if analysis.param_spec_fn.is_some() {
+        for cfg in &cfgs {
+            builders.push(format!("\t{cfg}"));
+        }
+        builders.push(format!(
+            "\tpub use super::{}::ParamSpec{}Builder;",
+            module_name, analysis.name
+        ));
+    }
 }
@@ -5,10 +5,14 @@ use super::{
     property_body,
 };
 use crate::{
-    analysis::{properties::Property, rust_type::RustType},
+    analysis::{
+        properties::{Binding, Property},
+        rust_type::RustType,
+    },
     chunk::Chunk,
     env::Env,
     library,
+    nameutil::use_glib_type,
     traits::IntoString,
     writer::{ToCode, primitives::tabs},
 };
@@ -26,6 +30,88 @@ pub fn generate(
     Ok(())
 }
 
+/// Generates the `bind_*` helpers of a property: one binding the values as
+/// is, one transforming them and, for writable properties, one transforming
+/// them both ways.
+pub fn generate_binding(
+    w: &mut dyn Write,
+    env: &Env,
+    binding: &Binding,
+    in_trait: bool,
+    only_declaration: bool,
+    indent: usize,
+) -> Result<()> {
+    let pub_prefix = if in_trait { "" } else { "pub " };
+    let decl_suffix = if only_declaration { ";" } else { " {" };
+    let object = use_glib_type(env, "Object");
+    let glib_binding = use_glib_type(env, "Binding");
+    let value = use_glib_type(env, "Value");
+    let from_value = use_glib_type(env, "value::FromValue");
+    let builder = use_glib_type(env, "BindingBuilder<'a, 'a, 'a>");
+    let typ = &binding.typ;
+
+    let mut helpers = vec![(
+        binding.func_name.clone(),
+        String::new(),
+        String::new(),
+        format!(
+            "self.bind_property(\"{}\", target, target_property)",
+            binding.name
+        ),
+    )];
+    helpers.push((
+        format!("{}_transform", binding.func_name),
+        format!(
+            ", T: Into<{value}>, F: Fn(&{glib_binding}, {typ}) -> Option<T> + Send + Sync + 'static"
+        ),
+        ", transform_to: F".to_owned(),
+        format!(
+            "self.{}(target, target_property).transform_to(transform_to)",
+            binding.func_name
+        ),
+    ));
+    if binding.writable {
+        helpers.push((
+            format!("{}_transform_bidirectional", binding.func_name),
+            format!(
+                ", T: Into<{value}> + for<'v> {from_value}<'v>, \
+                 F: Fn(&{glib_binding}, {typ}) -> Option<T> + Send + Sync + 'static, \
+                 G: Fn(&{glib_binding}, T) -> Option<{typ}> + Send + Sync + 'static"
+            ),
+            ", transform_to: F, transform_from: G".to_owned(),
+            format!(
+                "self.{}(target, target_property).transform_to(transform_to).transform_from(transform_from).bidirectional()",
+                binding.func_name
+            ),
+        ));
+    }
+
+    for (func_name, bounds, params, body) in helpers {
+        writeln!(w)?;
+        cfg_deprecated(w, env, None, binding.deprecated_version, false, indent)?;
+        cfg_condition(w, binding.cfg_condition.as_ref(), false, indent)?;
+        version_condition(w, env, None, binding.version, false, indent)?;
+        writeln!(
+            w,
+            "{}{}fn {}<'a, O: IsA<{}>{}>(&'a self, target: &'a O, target_property: &'a str{}) -> {}{}",
+            tabs(indent),
+            pub_prefix,
+            func_name,
+            object,
+            bounds,
+            params,
+            builder,
+            decl_suffix
+        )?;
+        if !only_declaration {
+            writeln!(w, "{}{}", tabs(indent + 1), body)?;
+            writeln!(w, "{}}}", tabs(indent))?;
+        }
+    }
+
+    Ok(())
+}
+
 fn generate_prop_func(
     w: &mut dyn Write,
     env: &Env,
@@ -116,7 +116,11 @@ pub fn generate(w: &mut dyn Write, env: &Env, analysis: &analysis::record::Info)
         writeln!(w, "}}")?;
     }
 
-    general::declare_default_from_new(w, env, &analysis.name, &analysis.functions, false)?;
+    if let Some(default_impl) = analysis.default_impl.as_ref() {
+        general::declare_default(w, env, &analysis.name, default_impl)?;
+    } else {
+        general::declare_default_from_new(w, env, &analysis.name, &analysis.functions, false)?;
+    }
 
     trait_impls::generate(
         w,
@@ -3,12 +3,14 @@ use std::io::{Result, Write};
 use super::{
     general::{cfg_deprecated, doc_alias, doc_hidden, version_condition},
     signal_body,
-    trampoline::{self, func_string},
+    trampoline::{self, func_parameter, func_string},
 };
 use crate::{
-    analysis,
+    analysis::{self, trampolines::Trampoline},
     chunk::Chunk,
     env::Env,
+    library,
+    nameutil::use_glib_type,
     writer::{ToCode, primitives::tabs},
 };
 
@@ -84,6 +86,43 @@ pub fn generate(
         return Ok(());
     }
 
+    if let (Some(stream_name), Ok(trampoline)) = (&analysis.stream_name, &analysis.trampoline) {
+        writeln!(w)?;
+        cfg_deprecated(w, env, None, analysis.deprecated_version, commented, indent)?;
+        version_condition(w, env, None, analysis.version, commented, indent)?;
+        generate_stream(
+            w,
+            env,
+            analysis,
+            trampoline,
+            stream_name,
+            pub_prefix,
+            only_declaration,
+            indent,
+        )?;
+    }
+
+    if let (Some(weak_connect_name), Ok(trampoline), Some(function_type)) = (
+        &analysis.weak_connect_name,
+        &analysis.trampoline,
+        &function_type,
+    ) {
+        writeln!(w)?;
+        cfg_deprecated(w, env, None, analysis.deprecated_version, commented, indent)?;
+        version_condition(w, env, None, analysis.version, commented, indent)?;
+        generate_weak(
+            w,
+            env,
+            analysis,
+            trampoline,
+            weak_connect_name,
+            function_type,
+            pub_prefix,
+            only_declaration,
+            indent,
+        )?;
+    }
+
     if let Some(ref emit_name) = analysis.action_emit_name {
         writeln!(w)?;
         if !in_trait || only_declaration {
@@ -150,6 +189,217 @@ pub fn generate(
     Ok(())
 }
 
+fn generate_stream(
+    w: &mut dyn Write,
+    env: &Env,
+    analysis: &analysis::signals::Info,
+    trampoline: &Trampoline,
+    stream_name: &str,
+    pub_prefix: &str,
+    only_declaration: bool,
+    indent: usize,
+) -> Result<()> {
+    let mut names = Vec::new();
+    let mut types = Vec::new();
+    let mut values = Vec::new();
+    // Skip the self parameter
+    for par in trampoline.parameters.rust_parameters.iter().skip(1) {
+        let (type_, value) = owned_item(&func_parameter(env, par, &trampoline.bounds), &par.name);
+        names.push(par.name.as_str());
+        types.push(type_);
+        values.push(value);
+    }
+    let (item_type, item) = if types.len() == 1 {
+        (types.remove(0), values.remove(0))
+    } else {
+        (
+            format!("({})", types.join(", ")),
+            format!("({})", values.join(", ")),
+        )
+    };
+
+    let (param_str, detail_arg) = if analysis.is_detailed {
+        (", detail: Option<&str>", "detail, ")
+    } else {
+        ("", "")
+    };
+    writeln!(
+        w,
+        "{}{}fn {}(&self{}) -> impl {}<Item = {}> + Unpin + 'static{}",
+        tabs(indent),
+        pub_prefix,
+        stream_name,
+        param_str,
+        use_glib_type(env, "futures_core::Stream"),
+        item_type,
+        if only_declaration { ";" } else { " {" },
+    )?;
+    if only_declaration {
+        return Ok(());
+    }
+
+    writeln!(
+        w,
+        "{}let (sender, receiver) = {}();",
+        tabs(indent + 1),
+        use_glib_type(env, "futures_channel::mpsc::unbounded"),
+    )?;
+    writeln!(
+        w,
+        "{}let handler = self.{}({}move |_{}| {{",
+        tabs(indent + 1),
+        analysis.connect_name,
+        detail_arg,
+        names
+            .iter()
+            .map(|name| format!(", {name}"))
+            .collect::<String>(),
+    )?;
+    writeln!(
+        w,
+        "{}let _ = sender.unbounded_send({});",
+        tabs(indent + 2),
+        item
+    )?;
+    writeln!(w, "{}}});", tabs(indent + 1))?;
+    writeln!(
+        w,
+        "{}SignalStream::new(self, receiver, handler)",
+        tabs(indent + 1)
+    )?;
+    writeln!(w, "{}}}", tabs(indent))
+}
+
+fn generate_weak(
+    w: &mut dyn Write,
+    env: &Env,
+    analysis: &analysis::signals::Info,
+    trampoline: &Trampoline,
+    weak_connect_name: &str,
+    function_type: &str,
+    pub_prefix: &str,
+    only_declaration: bool,
+    indent: usize,
+) -> Result<()> {
+    let target_bound = match trampoline.concurrency {
+        // The weak reference to the target is moved into the handler
+        library::Concurrency::None => "",
+        _ => " + Send + Sync",
+    };
+    let function_type = function_type.replacen("Fn(", "Fn(&T, ", 1);
+    let (param_str, detail_arg) = if analysis.is_detailed {
+        (", detail: Option<&str>", "detail, ")
+    } else {
+        ("", "")
+    };
+    writeln!(
+        w,
+        "{}{}fn {}<T: IsA<{}>{}, F: {}>(&self{}, target: &T, f: F) -> SignalHandlerId{}",
+        tabs(indent),
+        pub_prefix,
+        weak_connect_name,
+        use_glib_type(env, "Object"),
+        target_bound,
+        function_type,
+        param_str,
+        if only_declaration { ";" } else { " {" },
+    )?;
+    if only_declaration {
+        return Ok(());
+    }
+
+    let args = trampoline
+        .parameters
+        .rust_parameters
+        .iter()
+        .skip(1)
+        .map(|par| format!(", {}", par.name))
+        .collect::<String>();
+    writeln!(
+        w,
+        "{}connect_weak(self, target, move |target_weak| {{",
+        tabs(indent + 1)
+    )?;
+    writeln!(
+        w,
+        "{}self.{}({}move |this{}| {{",
+        tabs(indent + 2),
+        analysis.connect_name,
+        detail_arg,
+        args,
+    )?;
+    // Don't shadow a signal argument with the upgraded target
+    let target = if trampoline
+        .parameters
+        .rust_parameters
+        .iter()
+        .any(|par| par.name == "target")
+    {
+        "target_obj"
+    } else {
+        "target"
+    };
+    writeln!(
+        w,
+        "{}if let Some({}) = target_weak.upgrade() {{",
+        tabs(indent + 3),
+        target
+    )?;
+    writeln!(w, "{}f(&{}, this{});", tabs(indent + 4), target, args)?;
+    writeln!(w, "{}}}", tabs(indent + 3))?;
+    writeln!(w, "{}}})", tabs(indent + 2))?;
+    writeln!(w, "{}}})", tabs(indent + 1))?;
+    writeln!(w, "{}}}", tabs(indent))
+}
+
+/// Returns the owned type of a signal argument that can outlive the emission,
+/// and the expression converting the argument `value` to it.
+fn owned_item(type_: &str, value: &str) -> (String, String) {
+    if let Some(inner) = type_
+        .strip_prefix("Option<&")
+        .and_then(|s| s.strip_suffix('>'))
+    {
+        let (owned, conversion) = owned_referent(inner, "v");
+        let conversion = if conversion == "v.to_owned()" {
+            "ToOwned::to_owned".to_owned()
+        } else {
+            format!("|v| {conversion}")
+        };
+        (
+            format!("Option<{owned}>"),
+            format!("{value}.map({conversion})"),
+        )
+    } else if let Some(inner) = type_.strip_prefix('&') {
+        owned_referent(inner, value)
+    } else {
+        (type_.to_owned(), value.to_owned())
+    }
+}
+
+/// Returns the owned type of a value borrowed as `&borrowed`, and the
+/// expression converting the reference `value` to it.
+fn owned_referent(borrowed: &str, value: &str) -> (String, String) {
+    match borrowed {
+        "str" => ("String".to_owned(), format!("{value}.to_owned()")),
+        "std::path::Path" => (
+            "std::path::PathBuf".to_owned(),
+            format!("{value}.to_owned()"),
+        ),
+        _ => match borrowed.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            // Slices of references, e.g. `&[&str]`, own their elements too
+            Some(element) if element.starts_with('&') => {
+                let (owned, conversion) = owned_item(element, "(*e)");
+                (
+                    format!("Vec<{owned}>"),
+                    format!("{value}.iter().map(|e| {conversion}).collect::<Vec<_>>()"),
+                )
+            }
+            Some(element) => (format!("Vec<{element}>"), format!("{value}.to_vec()")),
+            None => (borrowed.to_owned(), format!("{value}.to_owned()")),
+        },
+    }
+}
+
 fn function_type_string(
     env: &Env,
     analysis: &analysis::signals::Info,
@@ -205,3 +455,78 @@ fn body(analysis: &analysis::signals::Info, in_trait: bool) -> Chunk {
 
     builder.generate()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn owned(type_: &str) -> (String, String) {
+        owned_item(type_, "value")
+    }
+
+    fn expected(type_: &str, value: &str) -> (String, String) {
+        (type_.to_owned(), value.to_owned())
+    }
+
+    #[test]
+    fn owned_values() {
+        assert_eq!(owned("i32"), expected("i32", "value"));
+        assert_eq!(owned("Option<i32>"), expected("Option<i32>", "value"));
+    }
+
+    #[test]
+    fn owned_references() {
+        assert_eq!(owned("&str"), expected("String", "value.to_owned()"));
+        assert_eq!(
+            owned("&std::path::Path"),
+            expected("std::path::PathBuf", "value.to_owned()")
+        );
+        assert_eq!(
+            owned("&gio::File"),
+            expected("gio::File", "value.to_owned()")
+        );
+    }
+
+    #[test]
+    fn owned_options() {
+        assert_eq!(
+            owned("Option<&str>"),
+            expected("Option<String>", "value.map(ToOwned::to_owned)")
+        );
+        assert_eq!(
+            owned("Option<&std::path::Path>"),
+            expected("Option<std::path::PathBuf>", "value.map(ToOwned::to_owned)")
+        );
+        assert_eq!(
+            owned("Option<&glib::Variant>"),
+            expected("Option<glib::Variant>", "value.map(ToOwned::to_owned)")
+        );
+    }
+
+    #[test]
+    fn owned_slices() {
+        assert_eq!(owned("&[u8]"), expected("Vec<u8>", "value.to_vec()"));
+        assert_eq!(
+            owned("&[gio::File]"),
+            expected("Vec<gio::File>", "value.to_vec()")
+        );
+        assert_eq!(
+            owned("Option<&[i32]>"),
+            expected("Option<Vec<i32>>", "value.map(|v| v.to_vec())")
+        );
+        assert_eq!(
+            owned("&[&str]"),
+            expected(
+                "Vec<String>",
+                "value.iter().map(|e| (*e).to_owned()).collect::<Vec<_>>()"
+            )
+        );
+        assert_eq!(
+            owned("Option<&[&std::path::Path]>"),
+            expected(
+                "Option<Vec<std::path::PathBuf>>",
+                "value.map(|v| v.iter().map(|e| (*e).to_owned()).collect::<Vec<_>>())"
+            )
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use std::path::Path;
+
+use crate::{codegen::general, env::Env, file_saver, nameutil::use_glib_type};
+
+pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
+    let has_streams = env.analysis.objects.values().any(|obj| {
+        obj.signals
+            .iter()
+            .chain(obj.notify_signals.iter())
+            .any(|signal| signal.stream_name.is_some())
+    });
+    if !has_streams {
+        return;
+    }
+
+    let path = root_path.join("signal_stream.rs");
+    file_saver::save_to_file(path, env.config.make_backup, |w| {
+        general::start_comments(w, &env.config)?;
+
+        mod_rs.push("\nmod signal_stream;".into());
+
+        writeln!(w)?;
+        writeln!(w, "use {};", use_glib_type(env, "prelude::*"))?;
+        writeln!(
+            w,
+            "use std::{{pin::Pin, task::{{Context, Poll}}}};
+use {receiver_path};
+use {stream_path};
+
+/// Stream of the emissions of a signal, disconnecting its handler when dropped.
+pub(crate) struct SignalStream<O: ObjectType, T> {{
+    receiver: UnboundedReceiver<T>,
+    object: {weak_ref}<O>,
+    handler: Option<{handler_id}>,
+}}
+
+impl<O: ObjectType, T> SignalStream<O, T> {{
+    pub(crate) fn new(object: &O, receiver: UnboundedReceiver<T>, handler: {handler_id}) -> Self {{
+        Self {{
+            receiver,
+            object: object.downgrade(),
+            handler: Some(handler),
+        }}
+    }}
+}}
+
+impl<O: ObjectType, T> Stream for SignalStream<O, T> {{
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {{
+        Pin::new(&mut self.receiver).poll_next(cx)
+    }}
+}}
+
+impl<O: ObjectType, T> Drop for SignalStream<O, T> {{
+    fn drop(&mut self) {{
+        if let (Some(object), Some(handler)) = (self.object.upgrade(), self.handler.take()) {{
+            object.disconnect(handler);
+        }}
+    }}
+}}",
+            weak_ref = use_glib_type(env, "WeakRef"),
+            handler_id = use_glib_type(env, "SignalHandlerId"),
+            receiver_path = use_glib_type(env, "futures_channel::mpsc::UnboundedReceiver"),
+            stream_path = use_glib_type(env, "futures_core::Stream"),
+        )
+    });
+}
@@ -0,0 +1,197 @@
+use std::{
+    io::{Result, Write},
+    path::Path,
+};
+
+use crate::{codegen::general, env::Env, file_saver, nameutil::use_glib_type};
+
+pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
+    let has_weak_signals = env.analysis.objects.values().any(|obj| {
+        obj.signals
+            .iter()
+            .chain(obj.notify_signals.iter())
+            .any(|signal| signal.weak_connect_name.is_some())
+    });
+    if !has_weak_signals {
+        return;
+    }
+
+    let path = root_path.join("signal_weak.rs");
+    file_saver::save_to_file(path, env.config.make_backup, |w| {
+        general::start_comments(w, &env.config)?;
+
+        mod_rs.push("\nmod signal_weak;".into());
+
+        generate_helper(w, env)
+    });
+}
+
+fn generate_helper(w: &mut dyn Write, env: &Env) -> Result<()> {
+    writeln!(w)?;
+    writeln!(
+        w,
+        "use {};",
+        use_glib_type(env, "{prelude::*, translate::*}")
+    )?;
+    writeln!(
+        w,
+        "use std::{{ffi::c_ulong, sync::{{Arc, OnceLock}}}};
+
+/// Source and id of a weakly connected handler, set once it's connected.
+type Handler = OnceLock<({weak_ref}<{object}>, c_ulong)>;
+
+/// Weak reference to the target of a weakly connected handler.
+///
+/// It's moved into the handler. The weak notification of the target owns a
+/// second reference to the `Handler`, released either when the target is
+/// finalized, after disconnecting the handler, or when the handler is dropped
+/// first, after removing the notification.
+pub(crate) struct WeakTarget<T: ObjectType> {{
+    target: {weak_ref}<T>,
+    handler: Arc<Handler>,
+}}
+
+impl<T: ObjectType> WeakTarget<T> {{
+    pub(crate) fn upgrade(&self) -> Option<T> {{
+        self.target.upgrade()
+    }}
+}}
+
+impl<T: ObjectType> Drop for WeakTarget<T> {{
+    fn drop(&mut self) {{
+        // The weak reference is cleared before the weak notifications are
+        // called, if it can't be upgraded the notification releases its
+        // reference to the handler. Otherwise the upgraded target can't be
+        // finalized until the notification is removed.
+        if let Some(target) = self.target.upgrade() {{
+            let data = Arc::as_ptr(&self.handler);
+            unsafe {{
+                {gobject_ffi}::g_object_weak_unref(
+                    target.as_ptr() as *mut _,
+                    Some(disconnect_handler),
+                    data as {gpointer},
+                );
+                Arc::decrement_strong_count(data);
+            }}
+        }}
+    }}
+}}
+
+unsafe extern \"C\" fn disconnect_handler(data: {gpointer}, _target: *mut {gobject}) {{
+    unsafe {{
+        let handler = Arc::from_raw(data as *const Handler);
+        if let Some((source, handler)) = handler.get() {{
+            if let Some(source) = source.upgrade() {{
+                let instance = source.as_ptr() as {gpointer};
+                if {gobject_ffi}::g_signal_handler_is_connected(instance, *handler) != {gfalse} {{
+                    {gobject_ffi}::g_signal_handler_disconnect(instance, *handler);
+                }}
+            }}
+        }}
+    }}
+}}
+
+/// Connects a handler with `connect`, disconnecting it once `target` is finalized.
+pub(crate) fn connect_weak<O: ObjectType, T: ObjectType>(
+    source: &O,
+    target: &T,
+    connect: impl FnOnce(WeakTarget<T>) -> {handler_id},
+) -> {handler_id} {{
+    let handler = Arc::<Handler>::default();
+    // `target` is borrowed, the notification can't be called before returning
+    unsafe {{
+        {gobject_ffi}::g_object_weak_ref(
+            target.as_ptr() as *mut _,
+            Some(disconnect_handler),
+            Arc::into_raw(handler.clone()) as {gpointer},
+        );
+    }}
+    let handler_id = connect(WeakTarget {{
+        target: target.downgrade(),
+        handler: handler.clone(),
+    }});
+    let source: {object} = unsafe {{ from_glib_none(source.as_ptr() as *mut {gobject}) }};
+    let _ = handler.set((source.downgrade(), handler_id.as_raw()));
+    handler_id
+}}",
+        handler_id = use_glib_type(env, "SignalHandlerId"),
+        weak_ref = use_glib_type(env, "WeakRef"),
+        object = use_glib_type(env, "Object"),
+        gpointer = use_glib_type(env, "ffi::gpointer"),
+        gfalse = use_glib_type(env, "ffi::GFALSE"),
+        gobject = use_glib_type(env, "gobject_ffi::GObject"),
+        gobject_ffi = use_glib_type(env, "gobject_ffi"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::test_env::analyzed_env;
+
+    fn helper() -> String {
+        let env = analyzed_env("signal-weak", "", "");
+        let mut w = Vec::new();
+        generate_helper(&mut w, &env).unwrap();
+        String::from_utf8(w).unwrap()
+    }
+
+    fn section<'a>(code: &'a str, start: &str) -> &'a str {
+        let code = &code[code.find(start).unwrap()..];
+        &code[..code.find("\n}\n").unwrap()]
+    }
+
+    #[test]
+    fn no_send_sync_impls() {
+        // `WeakTarget` is only `Send` and `Sync` through its fields
+        assert!(!helper().contains("unsafe impl"));
+    }
+
+    #[test]
+    fn notification_owns_a_handler_reference() {
+        let code = helper();
+        let connect = section(&code, "pub(crate) fn connect_weak");
+        assert!(connect.contains(
+            "glib::gobject_ffi::g_object_weak_ref(\n            target.as_ptr() as *mut _,\n            \
+             Some(disconnect_handler),\n            Arc::into_raw(handler.clone()) as glib::ffi::gpointer,"
+        ));
+        assert!(connect.contains("handler: handler.clone(),"));
+    }
+
+    #[test]
+    fn disconnect_before_finalize() {
+        // Dropping the handler removes the notification, then releases its
+        // reference, only while the target can't be finalized
+        let code = helper();
+        let drop = section(&code, "impl<T: ObjectType> Drop for WeakTarget<T>");
+        let upgrade = drop
+            .find("if let Some(target) = self.target.upgrade()")
+            .unwrap();
+        let unref = drop.find("g_object_weak_unref(").unwrap();
+        let release = drop.find("Arc::decrement_strong_count(data);").unwrap();
+        assert!(upgrade < unref && unref < release);
+        assert!(drop.contains(
+            "Some(disconnect_handler),\n                    data as glib::ffi::gpointer,"
+        ));
+    }
+
+    #[test]
+    fn finalize_before_disconnect() {
+        // The notification takes its reference back, and only disconnects the
+        // handler if its source is alive and it's still connected
+        let code = helper();
+        let notify = section(&code, "unsafe extern \"C\" fn disconnect_handler");
+        let reclaim = notify
+            .find("let handler = Arc::from_raw(data as *const Handler);")
+            .unwrap();
+        let source = notify.find("source.upgrade()").unwrap();
+        let connected = notify
+            .find("g_signal_handler_is_connected(instance, *handler)")
+            .unwrap();
+        let disconnect = notify
+            .find("g_signal_handler_disconnect(instance, *handler);")
+            .unwrap();
+        assert!(reclaim < source && source < connected && connected < disconnect);
+        assert!(!notify.contains("g_object_weak_unref"));
+    }
+}
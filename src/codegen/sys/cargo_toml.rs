@@ -1,7 +1,7 @@
 use std::{collections::HashMap, fs::File, io::prelude::*};
 
 use log::info;
-use toml::{self, Value, value::Table};
+use toml_edit::{Array, DocumentMut, Item, Table, TableLike, Value};
 
 use super::collect_versions;
 use crate::{config::Config, env::Env, file_saver::save_to_file, nameutil, version::Version};
@@ -16,22 +16,24 @@ pub fn generate(env: &Env) -> String {
         file.read_to_string(&mut toml_str).unwrap();
     }
     let empty = toml_str.trim().is_empty();
-    let mut root_table = toml::from_str(&toml_str).unwrap_or_else(|_| Table::new());
-    let crate_name = get_crate_name(&env.config, &root_table);
+    // Edited in place to keep the formatting and comments of the manifest
+    let mut document = toml_str.parse::<DocumentMut>().unwrap_or_default();
+    let root_table = document.as_table_mut();
+    let crate_name = get_crate_name(&env.config, root_table);
 
     if empty {
-        fill_empty(&mut root_table, env, &crate_name);
+        fill_empty(root_table, env, &crate_name);
     }
-    fill_in(&mut root_table, env);
+    fill_in(root_table, env);
 
     save_to_file(&path, env.config.make_backup, |w| {
-        w.write_all(toml::to_string(&root_table).unwrap().as_bytes())
+        w.write_all(document.to_string().as_bytes())
     });
 
     crate_name
 }
 
-fn fill_empty(root: &mut Table, env: &Env, crate_name: &str) {
+fn fill_empty(root: &mut dyn TableLike, env: &Env, crate_name: &str) {
     let package_name = nameutil::exported_crate_name(crate_name);
 
     {
@@ -96,12 +98,10 @@ fn fill_empty(root: &mut Table, env: &Env, crate_name: &str) {
     }
 }
 
-fn fill_in(root: &mut Table, env: &Env) {
+fn fill_in(root: &mut dyn TableLike, env: &Env) {
     {
         let package = upsert_table(root, "package");
-        package
-            .entry("build")
-            .or_insert_with(|| Value::String("build.rs".into()));
+        set_default(package, "build", "build.rs");
         // set_string(package, "version", "0.2.0");
     }
 
@@ -126,12 +126,11 @@ fn fill_in(root: &mut Table, env: &Env) {
         let features = upsert_table(root, "features");
         let versions = collect_versions(env);
         versions.keys().fold(None::<Version>, |prev, &version| {
-            let prev_array: Vec<Value> =
+            let prev_array: Array =
                 get_feature_dependencies(version, prev, &env.config.feature_dependencies)
                     .iter()
-                    .map(|s| Value::String(s.clone()))
                     .collect();
-            features.insert(version.to_feature(), Value::Array(prev_array));
+            set_value(features, &version.to_feature(), prev_array.into());
             Some(version)
         });
     }
@@ -145,10 +144,8 @@ fn fill_in(root: &mut Table, env: &Env) {
         if let Some(lib_name) = ns.package_names.first() {
             let meta = upsert_table(meta, nameutil::lib_name_to_toml(lib_name));
             // Allow both the name and version of a system dep to be overridden by hand
-            meta.entry("name")
-                .or_insert_with(|| Value::String(lib_name.clone()));
-            meta.entry("version")
-                .or_insert_with(|| Value::String(env.config.min_cfg_version.to_string()));
+            set_default(meta, "name", lib_name.as_str());
+            set_default(meta, "version", env.config.min_cfg_version.to_string());
 
             // Old version API
             unset(meta, "feature-versions");
@@ -159,9 +156,7 @@ fn fill_in(root: &mut Table, env: &Env) {
                 .for_each(|(v, lib_version)| {
                     let version_section = upsert_table(meta, v.to_feature());
                     // Allow system-deps version for this feature level to be overridden by hand
-                    version_section
-                        .entry("version")
-                        .or_insert_with(|| Value::String(lib_version.to_string()));
+                    set_default(version_section, "version", lib_version.to_string());
                 });
         }
     }
@@ -174,15 +169,15 @@ fn fill_in(root: &mut Table, env: &Env) {
         let docs_rs_metadata = upsert_table(docs_rs_metadata, "docs");
         let docs_rs_metadata = upsert_table(docs_rs_metadata, "rs");
 
-        docs_rs_metadata.entry("rustdoc-args").or_insert_with(|| {
-            Value::Array(vec![Value::String(
-                "--generate-link-to-definition".to_string(),
-            )])
-        });
+        set_default(
+            docs_rs_metadata,
+            "rustdoc-args",
+            Array::from_iter(["--generate-link-to-definition"]),
+        );
 
         // Generate docs for all features unless a list of features to be activated on docs.rs was specified
-        if let toml::map::Entry::Vacant(_) = docs_rs_metadata.entry("features") {
-            docs_rs_metadata.insert("all-features".to_string(), Value::Boolean(true));
+        if !docs_rs_metadata.contains_key("features") {
+            set_value(docs_rs_metadata, "all-features", true.into());
         }
     }
 }
@@ -203,36 +198,120 @@ fn get_feature_dependencies(
 }
 
 /// Returns the name of crate being currently generated.
-fn get_crate_name(config: &Config, root: &Table) -> String {
-    if let Some(Value::Table(lib)) = root.get("lib")
-        && let Some(Value::String(lib_name)) = lib.get("name")
+fn get_crate_name(config: &Config, root: &dyn TableLike) -> String {
+    if let Some(lib_name) = root
+        .get("lib")
+        .and_then(|lib| lib.get("name"))
+        .and_then(Item::as_str)
     {
         // Converting don't needed as library target names cannot contain hyphens
-        return lib_name.clone();
+        return lib_name.to_owned();
     }
-    if let Some(Value::Table(package)) = root.get("package")
-        && let Some(Value::String(package_name)) = package.get("name")
+    if let Some(package_name) = root
+        .get("package")
+        .and_then(|package| package.get("name"))
+        .and_then(Item::as_str)
     {
         return nameutil::crate_name(package_name);
     }
     format!("{}_sys", nameutil::crate_name(&config.library_name))
 }
 
-fn set_string<S: Into<String>>(table: &mut Table, name: &str, new_value: S) {
-    table.insert(name.into(), Value::String(new_value.into()));
+fn set_string<S: Into<String>>(table: &mut dyn TableLike, name: &str, new_value: S) {
+    set_value(table, name, new_value.into().into());
+}
+
+/// Sets `name` to `new_value`, keeping the formatting of an existing entry
+/// and leaving it untouched if it already has this value.
+fn set_value(table: &mut dyn TableLike, name: &str, new_value: Value) {
+    match table.get_mut(name).and_then(Item::as_value_mut) {
+        Some(value) if same_value(value, &new_value) => (),
+        Some(value) => {
+            let decor = value.decor().clone();
+            *value = new_value;
+            *value.decor_mut() = decor;
+        }
+        None => {
+            table.insert(name, Item::Value(new_value));
+        }
+    }
+}
+
+/// Sets `name` to `value` unless it's already set, allowing it to be
+/// overridden by hand.
+fn set_default<V: Into<Value>>(table: &mut dyn TableLike, name: &str, value: V) {
+    if !table.contains_key(name) {
+        table.insert(name, Item::Value(value.into()));
+    }
 }
 
-fn unset(table: &mut Table, name: &str) {
+fn same_value(value: &Value, other: &Value) -> bool {
+    match (value, other) {
+        (Value::String(value), Value::String(other)) => value.value() == other.value(),
+        (Value::Boolean(value), Value::Boolean(other)) => value.value() == other.value(),
+        (Value::Array(value), Value::Array(other)) => {
+            value.len() == other.len()
+                && value
+                    .iter()
+                    .zip(other.iter())
+                    .all(|(value, other)| same_value(value, other))
+        }
+        _ => false,
+    }
+}
+
+fn unset(table: &mut dyn TableLike, name: &str) {
     table.remove(name);
 }
 
-fn upsert_table<S: Into<String>>(parent: &mut Table, name: S) -> &mut Table {
-    if let Value::Table(table) = parent
-        .entry(name.into())
-        .or_insert_with(|| Value::Table(toml::map::Map::new()))
-    {
-        table
-    } else {
-        unreachable!()
+fn upsert_table<S: AsRef<str>>(parent: &mut dyn TableLike, name: S) -> &mut dyn TableLike {
+    let name = name.as_ref();
+    if !parent.contains_key(name) {
+        let mut table = Table::new();
+        // Only shown if it has values, not just subtables
+        table.set_implicit(true);
+        parent.insert(name, Item::Table(table));
+    }
+    parent
+        .get_mut(name)
+        .and_then(Item::as_table_like_mut)
+        .unwrap_or_else(|| panic!("`{name}` must be a table in Cargo.toml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn update_keeps_formatting() {
+        let input = r#"# Comment
+[dependencies]
+libc = "0.1" # outdated
+foo = { path = "../foo" }
+
+[package]
+name = "foo-sys"
+"#;
+        let mut document = input.parse::<DocumentMut>().unwrap();
+        let root = document.as_table_mut();
+        set_string(upsert_table(root, "dependencies"), "libc", "0.2");
+        set_string(upsert_table(root, "package"), "name", "foo-sys");
+        let meta = upsert_table(upsert_table(root, "package"), "metadata");
+        set_default(upsert_table(meta, "system-deps"), "name", "foo");
+
+        assert_eq!(
+            document.to_string(),
+            r#"# Comment
+[dependencies]
+libc = "0.2" # outdated
+foo = { path = "../foo" }
+
+[package]
+name = "foo-sys"
+
+[package.metadata.system-deps]
+name = "foo"
+"#
+        );
     }
 }
@@ -175,6 +175,7 @@ fn ffi_inner(env: &Env, tid: library::TypeId, mut inner: String) -> Result {
                 .map_any(|rust_type| rust_type.alter_type(|typ_| format!("[{typ_}; {size}]")))
         }
         Type::Array(..)
+        | Type::ByteArray
         | Type::PtrArray(..)
         | Type::List(..)
         | Type::SList(..)
@@ -230,6 +231,7 @@ fn fix_name(env: &Env, type_id: library::TypeId, name: &str) -> Result {
     if type_id.ns_id == library::INTERNAL_NAMESPACE {
         match env.library.type_(type_id) {
             Type::Array(..)
+            | Type::ByteArray
             | Type::PtrArray(..)
             | Type::List(..)
             | Type::SList(..)
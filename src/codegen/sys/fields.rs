@@ -18,6 +18,7 @@ pub struct Fields {
     pub kind: &'static str,
     /// specified GObject cfg condition
     pub cfg_condition: Option<String>,
+    pub doc: Option<String>,
     pub fields: Vec<FieldInfo>,
 }
 
@@ -30,6 +31,7 @@ pub struct FieldInfo {
     unsafe_access: bool,
     /// Include this field in Debug impl?
     pub debug: bool,
+    pub doc: Option<String>,
 }
 
 impl Fields {
@@ -66,6 +68,7 @@ pub fn from_record(env: &Env, record: &Record) -> Fields {
         derives_copy,
         kind: "struct",
         cfg_condition: get_gobject_cfg_condition(env, &record.name),
+        doc: record.doc.clone(),
         fields,
     }
 }
@@ -80,6 +83,7 @@ pub fn from_class(env: &Env, klass: &Class) -> Fields {
         derives_copy,
         kind: "struct",
         cfg_condition: get_gobject_cfg_condition(env, &klass.name),
+        doc: klass.doc.clone(),
         fields,
     }
 }
@@ -94,6 +98,7 @@ pub fn from_union(env: &Env, union: &Union) -> Fields {
         derives_copy,
         kind: "union",
         cfg_condition: None,
+        doc: union.doc.clone(),
         fields,
     }
 }
@@ -132,6 +137,7 @@ fn analyze_fields(
             typ: typ.into_string(),
             debug,
             unsafe_access,
+            doc: field.doc.clone(),
         });
     }
 
@@ -3,7 +3,7 @@ use std::{
     sync::OnceLock,
 };
 
-use super::ffi_type::*;
+use super::{doc_comment, ffi_type::*};
 use crate::{
     codegen::general::{cfg_condition, version_condition},
     config::{functions::Function, gobjects::GObject},
@@ -252,6 +252,9 @@ fn generate_object_funcs(
             .or(func.version)
             .or(version);
 
+        if !commented {
+            doc_comment(w, env, func.doc.as_deref(), 1)?;
+        }
         version_condition(w, env, None, version, commented, 1)?;
         let name = &func.c_identifier;
         generate_cfg_configure(w, &configured_functions, commented)?;
@@ -272,6 +275,9 @@ pub fn generate_callbacks(
     for func in callbacks {
         let (commented, sig) = function_signature(env, func, true);
         let comment = if commented { "//" } else { "" };
+        if !commented {
+            doc_comment(w, env, func.doc.as_deref(), 0)?;
+        }
         writeln!(
             w,
             "{}pub type {} = Option<unsafe extern \"C\" fn{}>;",
@@ -5,7 +5,7 @@ use std::{
 
 use log::info;
 
-use super::{ffi_type::ffi_type, fields, functions, statics};
+use super::{doc_comment, ffi_type::ffi_type, fields, functions, statics};
 use crate::{
     codegen::general::{self, cfg_condition, version_condition},
     config::constants,
@@ -179,6 +179,9 @@ fn generate_aliases(w: &mut dyn Write, env: &Env, items: &[&Alias]) -> Result<()
             .objects
             .get(&full_name)
             .and_then(|obj| obj.cfg_condition.as_ref());
+        if comment.is_empty() {
+            doc_comment(w, env, item.doc.as_deref(), 0)?;
+        }
         cfg_condition(w, cfg_condition_, false, 0)?;
         writeln!(w, "{}pub type {} = {};", comment, item.c_identifier, c_type)?;
     }
@@ -199,6 +202,7 @@ fn generate_bitfields(w: &mut dyn Write, env: &Env, items: &[&Bitfield]) -> Resu
         if let Some(false) = config.map(|c| c.status.need_generate()) {
             continue;
         }
+        doc_comment(w, env, item.doc.as_deref(), 0)?;
         writeln!(w, "pub type {} = c_uint;", item.c_type)?;
         for member in &item.members {
             let member_config = config
@@ -215,6 +219,7 @@ fn generate_bitfields(w: &mut dyn Write, env: &Env, items: &[&Bitfield]) -> Resu
 
             let val: i64 = member.value.parse().unwrap();
 
+            doc_comment(w, env, member.doc.as_deref(), 0)?;
             version_condition(w, env, None, version, false, 0)?;
             writeln!(
                 w,
@@ -274,6 +279,9 @@ fn generate_constants(w: &mut dyn Write, env: &Env, constants: &[Constant]) -> R
             value = (val as u32).to_string();
         }
 
+        if comment.is_empty() {
+            doc_comment(w, env, constant.doc.as_deref(), 0)?;
+        }
         if let Some(obj) = config {
             let configured_constants = obj.constants.matched(&full_name);
             generate_constant_cfg_configure(w, &configured_constants, !comment.is_empty())?;
@@ -307,6 +315,7 @@ fn generate_enums(w: &mut dyn Write, env: &Env, items: &[&Enumeration]) -> Resul
             .objects
             .get(&full_name)
             .and_then(|obj| obj.cfg_condition.as_ref());
+        doc_comment(w, env, item.doc.as_deref(), 0)?;
         cfg_condition(w, cfg_condition_, false, 0)?;
         writeln!(w, "pub type {} = c_int;", item.c_type)?;
         for member in &item.members {
@@ -322,6 +331,7 @@ fn generate_enums(w: &mut dyn Write, env: &Env, items: &[&Enumeration]) -> Resul
                 continue;
             }
 
+            doc_comment(w, env, member.doc.as_deref(), 0)?;
             cfg_condition(w, cfg_condition_, false, 0)?;
             version_condition(w, env, None, version, false, 0)?;
             writeln!(
@@ -353,7 +363,7 @@ fn generate_unions(w: &mut dyn Write, env: &Env, unions: &[&Union]) -> Result<()
 
         let align = config.and_then(|c| c.align);
         let fields = fields::from_union(env, union);
-        generate_from_fields(w, &fields, align)?;
+        generate_from_fields(w, env, &fields, align)?;
     }
     Ok(())
 }
@@ -383,7 +393,7 @@ fn generate_classes_structs(w: &mut dyn Write, env: &Env, classes: &[&Class]) ->
 
         let align = config.and_then(|c| c.align);
         let fields = fields::from_class(env, class);
-        generate_from_fields(w, &fields, align)?;
+        generate_from_fields(w, env, &fields, align)?;
     }
     Ok(())
 }
@@ -419,6 +429,7 @@ fn generate_interfaces_structs(
             .objects
             .get(&full_name)
             .and_then(|obj| obj.cfg_condition.as_ref());
+        doc_comment(w, env, interface.doc.as_deref(), 0)?;
         cfg_condition(w, cfg_condition_, false, 0)?;
         generate_opaque_type(w, &interface.c_type)?;
         cfg_condition(w, cfg_condition_, false, 0)?;
@@ -463,7 +474,7 @@ fn generate_records(w: &mut dyn Write, env: &Env, records: &[&Record]) -> Result
         } else {
             let align = config.and_then(|c| c.align);
             let fields = fields::from_record(env, record);
-            generate_from_fields(w, &fields, align)?;
+            generate_from_fields(w, env, &fields, align)?;
         }
     }
     Ok(())
@@ -504,6 +515,7 @@ fn generate_disguised(w: &mut dyn Write, env: &Env, record: &Record) -> Result<(
         .and_then(|obj| obj.cfg_condition.as_ref());
     cfg_condition(w, cfg_condition_, false, 0)?;
     generate_opaque_type(w, &format!("_{}", record.c_type))?;
+    doc_comment(w, env, record.doc.as_deref(), 0)?;
     cfg_condition(w, cfg_condition_, false, 0)?;
     if record.pointer {
         writeln!(w, "pub type {name} = *mut _{name};", name = record.c_type)?;
@@ -515,9 +527,11 @@ fn generate_disguised(w: &mut dyn Write, env: &Env, record: &Record) -> Result<(
 
 fn generate_from_fields(
     w: &mut dyn Write,
+    env: &Env,
     fields: &fields::Fields,
     align: Option<u32>,
 ) -> Result<()> {
+    doc_comment(w, env, fields.doc.as_deref(), 0)?;
     cfg_condition(w, fields.cfg_condition.as_ref(), false, 0)?;
     if let Some(align) = align {
         writeln!(w, "#[repr(align({align}))]")?;
@@ -545,6 +559,7 @@ fn generate_from_fields(
             name = &fields.name
         )?;
         for field in &fields.fields {
+            doc_comment(w, env, field.doc.as_deref(), 1)?;
             writeln!(
                 w,
                 "\tpub {field_name}: {field_type},",
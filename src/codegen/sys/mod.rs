@@ -1,6 +1,14 @@
-use std::collections::BTreeMap;
+use std::{
+    collections::BTreeMap,
+    io::{Result, Write},
+};
 
-use crate::{codegen::generate_single_version_file, env::Env, version::Version};
+use crate::{
+    codegen::{doc::reformat_sys_doc, generate_single_version_file},
+    env::Env,
+    version::Version,
+    writer::primitives::tabs,
+};
 
 mod build;
 mod cargo_toml;
@@ -39,3 +47,21 @@ pub fn collect_versions(env: &Env) -> BTreeMap<Version, Version> {
 
     versions
 }
+
+/// Writes `doc` as a doc comment if `generate_sys_docs` is enabled
+pub fn doc_comment(w: &mut dyn Write, env: &Env, doc: Option<&str>, indent: usize) -> Result<()> {
+    if !env.config.generate_sys_docs {
+        return Ok(());
+    }
+    let Some(doc) = doc else {
+        return Ok(());
+    };
+    for line in reformat_sys_doc(doc, env).lines() {
+        if line.is_empty() {
+            writeln!(w, "{}///", tabs(indent))?;
+        } else {
+            writeln!(w, "{}/// {}", tabs(indent), line)?;
+        }
+    }
+    Ok(())
+}
@@ -124,7 +124,7 @@ fn func_parameters(
     param_str
 }
 
-fn func_parameter(env: &Env, par: &RustParameter, bounds: &Bounds) -> String {
+pub fn func_parameter(env: &Env, par: &RustParameter, bounds: &Bounds) -> String {
     // TODO: restore mutable support
     let ref_mode = if par.ref_mode == RefMode::ByRefMut {
         RefMode::ByRef
@@ -1,6 +1,11 @@
 use crate::{
     analysis::{
-        self, conversion_type::ConversionType, rust_type::RustType, try_from_glib::TryFromGlib,
+        self,
+        conversion_type::{ConversionType, is_direct_fixed_array, is_scalar_array_element},
+        garray,
+        hash_table::{self, Element, ElementKind},
+        rust_type::RustType,
+        try_from_glib::TryFromGlib,
     },
     chunk::conversion_from_glib::Mode,
     env::Env,
@@ -58,6 +63,11 @@ impl TranslateFromGlib for Mode {
             Pointer => {
                 let trans = from_glib_xxx(self.transfer, array_length);
                 match env.type_(self.typ) {
+                    library::Type::CArray(inner_tid)
+                        if is_scalar_array_element(env, *inner_tid) =>
+                    {
+                        scalar_array_from_glib(env, self.transfer, array_length)
+                    }
                     library::Type::List(..)
                     | library::Type::SList(..)
                     | library::Type::PtrArray(..)
@@ -68,6 +78,22 @@ impl TranslateFromGlib for Mode {
                             (format!("FromGlibPtrContainer::{}", trans.0), trans.1)
                         }
                     }
+                    library::Type::Array(inner_tid) => {
+                        garray_from_glib(env, *inner_tid, self.transfer)
+                    }
+                    library::Type::FixedArray(..) if is_direct_fixed_array(env, self.typ) => {
+                        // Copies the array out of the returned pointer
+                        (
+                            "*(".into(),
+                            format!(
+                                " as *const {})",
+                                RustType::try_new(env, self.typ).into_string()
+                            ),
+                        )
+                    }
+                    library::Type::HashTable(..) => {
+                        hash_table_from_glib(env, self.typ, self.transfer)
+                    }
                     _ => trans,
                 }
             }
@@ -83,14 +109,22 @@ impl TranslateFromGlib for analysis::return_value::Info {
         env: &Env,
         array_length: Option<&str>,
     ) -> (String, String) {
-        match self.parameter {
+        // A sunk floating reference is owned
+        let transfer = |par: &analysis::Parameter| {
+            if self.floating {
+                gir_parser::TransferOwnership::Full
+            } else {
+                par.lib_par.transfer_ownership()
+            }
+        };
+        let (prefix, suffix) = match self.parameter {
             Some(ref par) => match self.base_tid {
                 Some(tid) => {
                     let rust_type = RustType::builder(env, tid)
                         .direction(par.lib_par.direction())
                         .try_from_glib(&par.try_from_glib)
                         .try_build();
-                    let from_glib_xxx = from_glib_xxx(par.lib_par.transfer_ownership(), None);
+                    let from_glib_xxx = from_glib_xxx(transfer(par), None);
 
                     let prefix = if par.lib_par.is_nullable() {
                         format!("Option::<{}>::{}", rust_type.into_string(), from_glib_xxx.0)
@@ -124,7 +158,9 @@ impl TranslateFromGlib for analysis::return_value::Info {
                     format!(", \"{}\")", self.bool_return_is_error.as_ref().unwrap()),
                 ),
                 None if self.nullable_return_is_error.is_some() => {
-                    let res = Mode::from(par).translate_from_glib_as_function(env, array_length);
+                    let mut mode = Mode::from(par);
+                    mode.transfer = transfer(par);
+                    let res = mode.translate_from_glib_as_function(env, array_length);
                     if let Some(ref msg) = self.nullable_return_is_error {
                         assert!(par.lib_par.is_nullable());
                         (
@@ -140,9 +176,32 @@ impl TranslateFromGlib for analysis::return_value::Info {
                         res
                     }
                 }
-                None => Mode::from(par).translate_from_glib_as_function(env, array_length),
+                None => {
+                    let mut mode = Mode::from(par);
+                    mode.transfer = transfer(par);
+                    mode.translate_from_glib_as_function(env, array_length)
+                }
             },
             None => (String::new(), ";".into()),
+        };
+
+        match self.parameter {
+            Some(ref par) if self.floating => {
+                let ref_sink = format!(
+                    "{}(ptr as *mut _);",
+                    use_glib_type(env, "gobject_ffi::g_object_ref_sink")
+                );
+                let ref_sink = if par.lib_par.is_nullable() {
+                    format!("if !ptr.is_null() {{ {ref_sink} }}")
+                } else {
+                    ref_sink
+                };
+                (
+                    "{ let ptr = ".into(),
+                    format!("; {ref_sink} {prefix}ptr{suffix} }}"),
+                )
+            }
+            _ => (prefix, suffix),
         }
     }
 }
@@ -169,3 +228,121 @@ fn from_glib_xxx(
         ),
     }
 }
+
+/// Copies the entries of a `GHashTable` into a `HashMap`, releasing the table
+/// afterwards if ownership of it was transferred.
+fn hash_table_from_glib(
+    env: &Env,
+    typ: library::TypeId,
+    transfer: gir_parser::TransferOwnership,
+) -> (String, String) {
+    let Some((key, value)) = hash_table::elements(env, typ) else {
+        return ("/*Unimplemented*/".into(), String::new());
+    };
+    let glib_ffi = use_glib_type(env, "ffi");
+    let unref = if transfer == gir_parser::TransferOwnership::None {
+        String::new()
+    } else {
+        format!("{glib_ffi}::g_hash_table_unref(table);")
+    };
+    (
+        format!("{{ let table: *mut {glib_ffi}::GHashTable = "),
+        format!(
+            "; let mut map = HashMap::<{key_type}, {value_type}>::new(); \
+             if !table.is_null() {{ \
+             let mut iter = std::mem::MaybeUninit::uninit(); \
+             {glib_ffi}::g_hash_table_iter_init(iter.as_mut_ptr(), table); \
+             let mut key = std::ptr::null_mut(); \
+             let mut value = std::ptr::null_mut(); \
+             while {glib_ffi}::g_hash_table_iter_next(iter.as_mut_ptr(), &mut key, &mut value) != {glib_ffi}::GFALSE {{ \
+             map.insert({key_from}, {value_from}); \
+             }} \
+             {unref} \
+             }} \
+             map }}",
+            key_type = key.rust_type.as_str(),
+            value_type = value.rust_type.as_str(),
+            key_from = element_from_glib(&key, "key"),
+            value_from = element_from_glib(&value, "value"),
+        ),
+    )
+}
+
+fn element_from_glib(element: &Element, name: &str) -> String {
+    match element.kind {
+        ElementKind::Integer => format!("{name} as isize as {}", element.rust_type.as_str()),
+        ElementKind::String | ElementKind::Pointer => {
+            format!("from_glib_none({name} as {})", element.ffi_type)
+        }
+    }
+}
+
+/// Converts a C array of enums, flags or booleans into a `Vec`, freeing the
+/// array afterwards if ownership of it was transferred.
+fn scalar_array_from_glib(
+    env: &Env,
+    transfer: gir_parser::TransferOwnership,
+    array_length: Option<&str>,
+) -> (String, String) {
+    let Some(array_length) = array_length else {
+        return ("/*Unimplemented*/".into(), String::new());
+    };
+    let free = if transfer == gir_parser::TransferOwnership::None {
+        String::new()
+    } else {
+        format!("{}(ptr as *mut _);", use_glib_type(env, "ffi::g_free"))
+    };
+    (
+        "{ let ptr = ".into(),
+        format!(
+            "; let vec = if ptr.is_null() {{ Vec::new() }} else {{ \
+             std::slice::from_raw_parts(ptr, {array_length}.assume_init() as usize).iter().map(|e| from_glib(*e)).collect::<Vec<_>>() \
+             }}; \
+             {free} \
+             vec }}"
+        ),
+    )
+}
+
+/// Copies the elements of a `GArray` into a `Vec`, releasing the array
+/// afterwards if ownership of it was transferred.
+fn garray_from_glib(
+    env: &Env,
+    inner_tid: library::TypeId,
+    transfer: gir_parser::TransferOwnership,
+) -> (String, String) {
+    let Some(element) = garray::element(env, inner_tid) else {
+        return ("/*Unimplemented*/".into(), String::new());
+    };
+    let glib_ffi = use_glib_type(env, "ffi");
+    let owns_elements = transfer == gir_parser::TransferOwnership::Full
+        && matches!(
+            element.kind,
+            garray::ElementKind::String | garray::ElementKind::Object
+        );
+    let unref = match transfer {
+        gir_parser::TransferOwnership::None => String::new(),
+        // The elements were moved into the `Vec`, so the array must not clear them
+        _ if owns_elements => format!(
+            "{glib_ffi}::g_array_set_clear_func(array, None); {glib_ffi}::g_array_unref(array);"
+        ),
+        _ => format!("{glib_ffi}::g_array_unref(array);"),
+    };
+    let value = match element.kind {
+        garray::ElementKind::Direct => "*e",
+        garray::ElementKind::Scalar => "from_glib(*e)",
+        _ if owns_elements => "from_glib_full(*e)",
+        garray::ElementKind::String | garray::ElementKind::Object => "from_glib_none(*e)",
+    };
+    (
+        format!("{{ let array: *mut {glib_ffi}::GArray = "),
+        format!(
+            "; let vec = if array.is_null() || (*array).len == 0 {{ Vec::new() }} else {{ \
+             std::slice::from_raw_parts((*array).data as *const {ffi_type}, (*array).len as usize).iter().map(|e| {value}).collect::<Vec<_>>() \
+             }}; \
+             {unref} \
+             vec }}",
+            ffi_type = element.ffi_type,
+        ),
+    )
+}
@@ -1,4 +1,16 @@
-use crate::analysis::{function_parameters::TransformationType, ref_mode::RefMode};
+use crate::{
+    analysis::{
+        function_parameters::TransformationType,
+        garray,
+        hash_table::{self, Element, ElementKind},
+        ref_mode::RefMode,
+        rust_type::RustType,
+    },
+    env::Env,
+    library,
+    nameutil::use_glib_type,
+    traits::*,
+};
 
 pub trait TranslateToGlib {
     fn translate_to_glib(&self) -> String;
@@ -43,6 +55,17 @@ impl TranslateToGlib for TransformationType {
                     format!("{left}{name}{to_glib_extra}{right}{pointer_cast}")
                 }
             }
+            // The container itself is built by the function body
+            ToGlibContainer { ref name, .. } => name.clone(),
+            ToGlibScalarArray {
+                ref name, mut_ptr, ..
+            } => {
+                if mut_ptr {
+                    format!("{name}_glib.as_mut_ptr()")
+                } else {
+                    format!("{name}_glib.as_ptr()")
+                }
+            }
             ToGlibBorrow => "/*Not applicable conversion Borrow*/".to_owned(),
             ToGlibUnknown { ref name } => format!("/*Unknown conversion*/{name}"),
             ToSome(ref name) => format!("Some({name})"),
@@ -91,3 +114,107 @@ fn to_glib_xxx(
         Container => ("".into(), ".to_glib_container().0"),
     }
 }
+
+/// Builds a new `GHashTable` owning copies of the entries of the `HashMap` `name`.
+pub fn hash_table_to_glib(env: &Env, typ: library::TypeId, name: &str) -> String {
+    let Some((key, value)) = hash_table::elements(env, typ) else {
+        return format!("/*Unimplemented*/{name}");
+    };
+    let glib_ffi = use_glib_type(env, "ffi");
+    let (hash, equal) = if key.kind == ElementKind::String {
+        ("g_str_hash", "g_str_equal")
+    } else {
+        ("g_direct_hash", "g_direct_equal")
+    };
+    format!(
+        "{{ {key_free}{value_free}\
+         let table = {glib_ffi}::g_hash_table_new_full(Some({glib_ffi}::{hash}), Some({glib_ffi}::{equal}), {key_destroy}, {value_destroy}); \
+         for (key, value) in {name}.iter() {{ \
+         {glib_ffi}::g_hash_table_insert(table, {key_to}, {value_to}); \
+         }} \
+         table }}",
+        key_free = element_free_func(env, &key, "free_key"),
+        value_free = element_free_func(env, &value, "free_value"),
+        key_destroy = element_destroy(&key, "free_key"),
+        value_destroy = element_destroy(&value, "free_value"),
+        key_to = element_to_glib(env, &key, "key"),
+        value_to = element_to_glib(env, &value, "value"),
+    )
+}
+
+fn element_free_func(env: &Env, element: &Element, func_name: &str) -> String {
+    if element.kind == ElementKind::Integer {
+        return String::new();
+    }
+    format!(
+        "extern \"C\" fn {func_name}(ptr: {gpointer}) {{ \
+         unsafe {{ let _: {typ} = from_glib_full(ptr as {ffi_type}); }} \
+         }} ",
+        gpointer = use_glib_type(env, "ffi::gpointer"),
+        typ = element.rust_type.as_str(),
+        ffi_type = element.ffi_type,
+    )
+}
+
+fn element_destroy(element: &Element, func_name: &str) -> String {
+    if element.kind == ElementKind::Integer {
+        "None".to_owned()
+    } else {
+        format!("Some({func_name})")
+    }
+}
+
+fn element_to_glib(env: &Env, element: &Element, name: &str) -> String {
+    let gpointer = use_glib_type(env, "ffi::gpointer");
+    match element.kind {
+        ElementKind::Integer => format!("*{name} as isize as {gpointer}"),
+        ElementKind::String | ElementKind::Pointer => format!(
+            "ToGlibPtr::<{}>::to_glib_full({name}) as {gpointer}",
+            element.ffi_type
+        ),
+    }
+}
+
+/// Builds a new `GArray` owning copies of the elements of the slice `name`.
+pub fn garray_to_glib(env: &Env, typ: library::TypeId, name: &str) -> String {
+    let library::Type::Array(inner_tid) = *env.type_(typ) else {
+        return format!("/*Unimplemented*/{name}");
+    };
+    let Some(element) = garray::element(env, inner_tid) else {
+        return format!("/*Unimplemented*/{name}");
+    };
+    let glib_ffi = use_glib_type(env, "ffi");
+    let ffi_type = &element.ffi_type;
+    let owned_type = match element.kind {
+        garray::ElementKind::String => Some(use_glib_type(env, "GString")),
+        garray::ElementKind::Object => Some(RustType::try_new(env, inner_tid).into_string()),
+        garray::ElementKind::Direct | garray::ElementKind::Scalar => None,
+    };
+    let (clear_func, set_clear_func) = match owned_type {
+        Some(owned_type) => (
+            format!(
+                "extern \"C\" fn clear(ptr: {glib_ffi}::gpointer) {{ \
+                 unsafe {{ let _: {owned_type} = from_glib_full(*(ptr as *mut {ffi_type})); }} \
+                 }} "
+            ),
+            format!("{glib_ffi}::g_array_set_clear_func(array, Some(clear)); "),
+        ),
+        None => (String::new(), String::new()),
+    };
+    let value = match element.kind {
+        garray::ElementKind::Direct => "*e".to_owned(),
+        garray::ElementKind::Scalar => "e.into_glib()".to_owned(),
+        garray::ElementKind::String => format!("ToGlibPtr::<{ffi_type}>::to_glib_full(&**e)"),
+        garray::ElementKind::Object => format!("ToGlibPtr::<{ffi_type}>::to_glib_full(e)"),
+    };
+    format!(
+        "{{ {clear_func}\
+         let array = {glib_ffi}::g_array_sized_new({glib_ffi}::GFALSE, {glib_ffi}::GFALSE, std::mem::size_of::<{ffi_type}>() as u32, {name}.len() as u32); \
+         {set_clear_func}\
+         for e in {name}.iter() {{ \
+         let value: {ffi_type} = {value}; \
+         {glib_ffi}::g_array_append_vals(array, &value as *const {ffi_type} as {glib_ffi}::gconstpointer, 1); \
+         }} \
+         array }}"
+    )
+}
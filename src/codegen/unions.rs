@@ -0,0 +1,212 @@
+use std::{
+    io::{Result, prelude::*},
+    path::Path,
+};
+
+use crate::{
+    analysis::{
+        conversion_type::ConversionType,
+        unions::{Info, PayloadConversion},
+    },
+    codegen::general::{self, cfg_condition, cfg_condition_string, doc_alias},
+    config::gobjects::GObject,
+    env::Env,
+    file_saver,
+    nameutil::{module_name, use_glib_type},
+};
+
+pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
+    if env.analysis.unions.is_empty() {
+        return;
+    }
+
+    let path = root_path.join("unions.rs");
+    file_saver::save_to_file(path, env.config.make_backup, |w| {
+        general::start_comments(w, &env.config)?;
+        general::uses(w, env, &env.analysis.union_imports, None)?;
+        writeln!(w)?;
+
+        mod_rs.push("\nmod unions;".into());
+        for union_analysis in &env.analysis.unions {
+            let config = &env.config.objects[&union_analysis.full_name];
+
+            if let Some(cfg) = cfg_condition_string(config.cfg_condition.as_ref(), false, 0) {
+                mod_rs.push(cfg);
+            }
+            mod_rs.push(format!(
+                "{} use self::unions::{};",
+                union_analysis.visibility.export_visibility(),
+                union_analysis.name
+            ));
+
+            generate_union(env, w, config, union_analysis)?;
+        }
+
+        Ok(())
+    });
+}
+
+fn generate_union(env: &Env, w: &mut dyn Write, config: &GObject, analysis: &Info) -> Result<()> {
+    let union = analysis.type_(&env.library);
+    let sys_crate_name = env.sys_crate_import(analysis.type_id);
+    let c_type = union.c_type.as_deref().unwrap_or(&union.name);
+    let ffi_type = format!("{sys_crate_name}::{c_type}");
+
+    cfg_condition(w, config.cfg_condition.as_ref(), false, 0)?;
+    if config.must_use {
+        writeln!(w, "#[must_use]")?;
+    }
+    writeln!(w, "#[derive(Debug, Clone)]")?;
+    writeln!(w, "#[non_exhaustive]")?;
+    doc_alias(w, c_type, "", 0)?;
+    writeln!(w, "{} enum {} {{", analysis.visibility, analysis.name)?;
+    for variant in &analysis.variants {
+        if let Some(ref c_identifier) = variant.c_identifier {
+            doc_alias(w, c_identifier, "", 1)?;
+        }
+        match variant.payload {
+            Some(ref payload) if payload.conversion == PayloadConversion::Pointer => {
+                writeln!(w, "\t{}(Option<{}>),", variant.name, payload.rust_type)?;
+            }
+            Some(ref payload) => writeln!(w, "\t{}({}),", variant.name, payload.rust_type)?,
+            None => writeln!(w, "\t{},", variant.name)?,
+        }
+    }
+    writeln!(
+        w,
+        "\t#[doc(hidden)]
+    __Unknown({}),",
+        analysis.discriminant_type
+    )?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    // Accessors
+    cfg_condition(w, config.cfg_condition.as_ref(), false, 0)?;
+    writeln!(w, "impl {} {{", analysis.name)?;
+    writeln!(
+        w,
+        "\tpub fn discriminant(&self) -> {} {{",
+        analysis.discriminant_type
+    )?;
+    writeln!(w, "\t\tmatch self {{")?;
+    for variant in &analysis.variants {
+        let pattern = if variant.payload.is_some() {
+            format!("Self::{}(_)", variant.name)
+        } else {
+            format!("Self::{}", variant.name)
+        };
+        writeln!(w, "\t\t\t{pattern} => {},", variant.discriminant)?;
+    }
+    writeln!(w, "\t\t\tSelf::__Unknown(value) => *value,")?;
+    writeln!(w, "\t\t}}")?;
+    writeln!(w, "\t}}")?;
+    for variant in &analysis.variants {
+        let Some(ref payload) = variant.payload else {
+            continue;
+        };
+        let value = match payload.conversion {
+            PayloadConversion::Pointer => "v.as_ref()",
+            _ => "Some(v)",
+        };
+        writeln!(w)?;
+        doc_alias(w, &payload.field, "", 1)?;
+        writeln!(
+            w,
+            "\tpub fn as_{}(&self) -> Option<&{}> {{",
+            module_name(&variant.name),
+            payload.rust_type
+        )?;
+        writeln!(w, "\t\tmatch self {{")?;
+        writeln!(w, "\t\t\tSelf::{}(v) => {value},", variant.name)?;
+        writeln!(w, "\t\t\t_ => None,")?;
+        writeln!(w, "\t\t}}")?;
+        writeln!(w, "\t}}")?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    // Conversions from the FFI union
+    let unknown = if analysis.discriminant_conversion == ConversionType::Scalar {
+        "from_glib(value)"
+    } else {
+        "value"
+    };
+    cfg_condition(w, config.cfg_condition.as_ref(), false, 0)?;
+    writeln!(w, "#[doc(hidden)]")?;
+    writeln!(
+        w,
+        "impl FromGlibPtrNone<*const {ffi_type}> for {} {{",
+        analysis.name
+    )?;
+    writeln!(w, "\t#[inline]")?;
+    writeln!(
+        w,
+        "\tunsafe fn from_glib_none(ptr: *const {ffi_type}) -> Self {{"
+    )?;
+    writeln!(w, "\t\tdebug_assert!(!ptr.is_null());")?;
+    writeln!(w, "\t\tunsafe {{")?;
+    writeln!(w, "\t\t\tmatch (*ptr).{} {{", analysis.discriminant_field)?;
+    for variant in &analysis.variants {
+        let value = match variant.payload {
+            Some(ref payload) => {
+                let field = format!("(*ptr).{}", payload.field);
+                let value = match payload.conversion {
+                    PayloadConversion::Direct => field,
+                    PayloadConversion::Scalar => format!("from_glib({field})"),
+                    PayloadConversion::Pointer => format!("from_glib_none({field})"),
+                    PayloadConversion::Inline => {
+                        format!("from_glib_none(&{field} as *const {})", payload.ffi_type)
+                    }
+                };
+                format!("Self::{}({value})", variant.name)
+            }
+            None => format!("Self::{}", variant.name),
+        };
+        writeln!(w, "\t\t\t\t{} => {value},", variant.pattern)?;
+    }
+    writeln!(w, "\t\t\t\tvalue => Self::__Unknown({unknown}),")?;
+    writeln!(w, "\t\t\t}}")?;
+    writeln!(w, "\t\t}}")?;
+    writeln!(w, "\t}}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    cfg_condition(w, config.cfg_condition.as_ref(), false, 0)?;
+    writeln!(w, "#[doc(hidden)]")?;
+    writeln!(
+        w,
+        "impl FromGlibPtrNone<*mut {ffi_type}> for {} {{
+    #[inline]
+    unsafe fn from_glib_none(ptr: *mut {ffi_type}) -> Self {{
+        unsafe {{ from_glib_none(ptr as *const {ffi_type}) }}
+    }}
+}}",
+        analysis.name
+    )?;
+
+    if let Some(ref get_type) = union.glib_get_type {
+        let gobject_ffi = use_glib_type(env, "gobject_ffi");
+        writeln!(w)?;
+        cfg_condition(w, config.cfg_condition.as_ref(), false, 0)?;
+        writeln!(w, "#[doc(hidden)]")?;
+        writeln!(
+            w,
+            "impl FromGlibPtrFull<*mut {ffi_type}> for {} {{
+    #[inline]
+    unsafe fn from_glib_full(ptr: *mut {ffi_type}) -> Self {{
+        unsafe {{
+            let value = from_glib_none(ptr as *const {ffi_type});
+            {gobject_ffi}::g_boxed_free({sys_crate_name}::{get_type}(), ptr as *mut _);
+            value
+        }}
+    }}
+}}",
+            analysis.name
+        )?;
+    }
+
+    writeln!(w)?;
+
+    Ok(())
+}
@@ -14,10 +14,7 @@ pub struct ChildProperty {
 
 impl Parse for ChildProperty {
     fn parse(toml: &Value, object_name: &str) -> Option<Self> {
-        let name = toml
-            .lookup("name")
-            .and_then(Value::as_str)
-            .map(ToOwned::to_owned);
+        let name = toml.lookup_opt_str("name").map(ToOwned::to_owned);
         let name = if let Some(name) = name {
             name
         } else {
@@ -26,32 +23,26 @@ impl Parse for ChildProperty {
         };
 
         toml.check_unwanted(
-            &["name", "type", "doc_hidden", "rename_getter"],
+            &[
+                "name",
+                "type",
+                "doc_hidden",
+                "rename_getter",
+                "generate_doc",
+            ],
             &format!("child property {object_name}"),
         );
 
-        let type_name = toml
-            .lookup("type")
-            .and_then(Value::as_str)
-            .map(ToOwned::to_owned);
+        let type_name = toml.lookup_opt_str("type").map(ToOwned::to_owned);
         let type_name = if let Some(type_name) = type_name {
             type_name
         } else {
             error!("No type for child property `{name}` for `{object_name}`");
             return None;
         };
-        let doc_hidden = toml
-            .lookup("doc_hidden")
-            .and_then(Value::as_bool)
-            .unwrap_or(false);
-        let rename_getter = toml
-            .lookup("rename_getter")
-            .and_then(Value::as_str)
-            .map(ToOwned::to_owned);
-        let generate_doc = toml
-            .lookup("generate_doc")
-            .and_then(Value::as_bool)
-            .unwrap_or(true);
+        let doc_hidden = toml.lookup_opt_bool("doc_hidden").unwrap_or(false);
+        let rename_getter = toml.lookup_opt_str("rename_getter").map(ToOwned::to_owned);
+        let generate_doc = toml.lookup_opt_bool("generate_doc").unwrap_or(true);
 
         Some(Self {
             name,
@@ -73,12 +64,10 @@ pub struct ChildProperties {
 impl Parse for ChildProperties {
     fn parse(toml_object: &Value, object_name: &str) -> Option<Self> {
         let child_name = toml_object
-            .lookup("child_name")
-            .and_then(Value::as_str)
+            .lookup_opt_str("child_name")
             .map(ToOwned::to_owned);
         let child_type = toml_object
-            .lookup("child_type")
-            .and_then(Value::as_str)
+            .lookup_opt_str("child_type")
             .map(ToOwned::to_owned);
         let mut properties: Vec<ChildProperty> = Vec::new();
         if let Some(configs) = toml_object.lookup("child_prop").and_then(Value::as_array) {
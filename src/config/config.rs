@@ -9,9 +9,10 @@ use std::{
 use log::warn;
 
 use super::{
-    WorkMode,
+    FutureStyle, WorkMode,
+    doc_sections::{DocSection, read_doc_sections},
     external_libraries::{ExternalLibrary, read_external_libraries},
-    gobjects,
+    gobjects, validation,
 };
 use crate::{
     analysis::namespaces::{self, Namespace, NsId},
@@ -111,6 +112,8 @@ pub struct Config {
     pub deprecate_by_min_version: bool,
     pub show_statistics: bool,
     pub concurrency: library::Concurrency,
+    /// Default return type of the `_future` variant of async functions
+    pub future_style: FutureStyle,
     pub single_version_file: Option<PathBuf>,
     pub trust_return_value_nullability: bool,
     pub disable_format: bool,
@@ -122,6 +125,14 @@ pub struct Config {
     /// to another doc source, for example when builds on docs.rs
     /// are limited due to license issues.
     pub external_docs_url: Option<String>,
+    /// Attach the GIR documentation to the items generated in sys mode
+    pub generate_sys_docs: bool,
+    /// Fail the doc mode run if some gi-docgen links couldn't be resolved
+    pub deny_broken_doc_links: bool,
+    /// Generate a shared trait and trampoline for the callback types used by
+    /// several functions
+    pub shared_callbacks: bool,
+    pub doc_sections: Vec<DocSection>,
 }
 
 impl Config {
@@ -136,6 +147,7 @@ impl Config {
         make_backup: bool,
         show_statistics: bool,
         disable_format: bool,
+        strict: bool,
     ) -> Result<Self, String>
     where
         S: Into<Option<&'a str>>,
@@ -152,7 +164,7 @@ impl Config {
             None => PathBuf::new(),
         };
 
-        let toml = match read_toml(&config_file) {
+        let (input, toml) = match read_toml(&config_file) {
             Ok(toml) => toml,
             Err(e) => {
                 return Err(format!(
@@ -163,6 +175,53 @@ impl Config {
             }
         };
 
+        let located = validation::locate(&config_file, &input, &toml);
+        toml.check_unwanted(
+            &[
+                "options",
+                "object",
+                "crate_name_overrides",
+                "external_libraries",
+                "lib_version_overrides",
+                "feature_dependencies",
+                "docsection",
+            ],
+            "root",
+        );
+        if let Some(options) = toml.lookup("options") {
+            options.check_unwanted(
+                &[
+                    "work_mode",
+                    "girs_directories",
+                    "library",
+                    "version",
+                    "target_path",
+                    "auto_path",
+                    "doc_target_path",
+                    "generate_builder",
+                    "concurrency",
+                    "future_style",
+                    "trust_return_value_nullability",
+                    "generate",
+                    "manual",
+                    "ignore",
+                    "external_libraries",
+                    "min_cfg_version",
+                    "generate_safety_asserts",
+                    "deprecate_by_min_version",
+                    "single_version_file",
+                    "disable_format",
+                    "split_build_rs",
+                    "extra_versions",
+                    "external_docs_url",
+                    "generate_sys_docs",
+                    "deny_broken_doc_links",
+                    "shared_callbacks",
+                ],
+                "options",
+            );
+        }
+
         let overrides = read_crate_name_overrides(&toml);
         if !overrides.is_empty() {
             set_crate_name_overrides(overrides);
@@ -225,8 +284,7 @@ impl Config {
         };
 
         let generate_builder: bool = toml
-            .lookup("options.generate_builder")
-            .and_then(|a| a.as_bool())
+            .lookup_opt_bool("options.generate_builder")
             .unwrap_or(false);
 
         let auto_path = match toml.lookup("options.auto_path") {
@@ -252,6 +310,11 @@ impl Config {
             None => Default::default(),
         };
 
+        let future_style = match toml.lookup("options.future_style") {
+            Some(v) => v.as_result_str("options.future_style")?.parse()?,
+            None => Default::default(),
+        };
+
         let trust_return_value_nullability =
             match toml.lookup("options.trust_return_value_nullability") {
                 Some(v) => v.as_result_bool("options.trust_return_value_nullability")?,
@@ -327,6 +390,33 @@ impl Config {
         let feature_dependencies = read_feature_dependencies(&toml)?;
         let external_docs_url = read_external_docs_url(&toml)?;
 
+        let generate_sys_docs = match toml.lookup("options.generate_sys_docs") {
+            Some(v) => v.as_result_bool("options.generate_sys_docs")?,
+            None => false,
+        };
+
+        let deny_broken_doc_links = match toml.lookup("options.deny_broken_doc_links") {
+            Some(v) => v.as_result_bool("options.deny_broken_doc_links")?,
+            None => false,
+        };
+
+        let shared_callbacks = match toml.lookup("options.shared_callbacks") {
+            Some(v) => v.as_result_bool("options.shared_callbacks")?,
+            None => false,
+        };
+
+        let doc_sections = read_doc_sections(&toml)?;
+
+        let problems = located.finish();
+        if strict && !problems.is_empty() {
+            return Err(format!(
+                "{} problem{} found in \"{}\"",
+                problems.len(),
+                if problems.len() > 1 { "s" } else { "" },
+                config_file.display()
+            ));
+        }
+
         Ok(Self {
             work_mode,
             girs_dirs,
@@ -344,6 +434,7 @@ impl Config {
             deprecate_by_min_version,
             show_statistics,
             concurrency,
+            future_style,
             single_version_file,
             trust_return_value_nullability,
             disable_format,
@@ -352,6 +443,10 @@ impl Config {
             lib_version_overrides,
             feature_dependencies,
             external_docs_url,
+            generate_sys_docs,
+            deny_broken_doc_links,
+            shared_callbacks,
+            doc_sections,
         })
     }
 
@@ -398,7 +493,7 @@ impl Config {
     }
 }
 
-fn read_toml<P: AsRef<Path>>(filename: P) -> Result<toml::Value, String> {
+fn read_toml<P: AsRef<Path>>(filename: P) -> Result<(String, toml::Value), String> {
     if !filename.as_ref().is_file() {
         return Err("Config don't exists or not file".to_owned());
     }
@@ -408,13 +503,15 @@ fn read_toml<P: AsRef<Path>>(filename: P) -> Result<toml::Value, String> {
     let input = String::from_utf8(input)
         .map_err(|e| format!("File is not valid UTF-8 \"{:?}\": {}", filename.as_ref(), e))?;
 
-    toml::from_str(&input).map_err(|e| {
+    let toml = toml::from_str(&input).map_err(|e| {
         format!(
             "Invalid toml format in \"{}\": {}",
             filename.as_ref().display(),
             e
         )
-    })
+    })?;
+
+    Ok((input, toml))
 }
 
 fn make_single_version_file(configured: Option<&str>, target_path: &Path) -> PathBuf {
@@ -469,6 +566,7 @@ fn read_lib_version_overrides(toml: &toml::Value) -> Result<HashMap<Version, Ver
 
     let mut map = HashMap::with_capacity(v.len());
     for o in v {
+        o.check_unwanted(&["version", "lib_version"], "lib_version_overrides");
         let cfg = o
             .lookup_str("version", "No version in lib_version_overrides")?
             .parse()?;
@@ -489,6 +587,7 @@ fn read_feature_dependencies(toml: &toml::Value) -> Result<HashMap<Version, Vec<
 
     let mut map = HashMap::with_capacity(v.len());
     for o in v {
+        o.check_unwanted(&["version", "dependencies"], "feature_dependencies");
         let cfg = o
             .lookup_str("version", "No version in feature_dependencies")?
             .parse()?;
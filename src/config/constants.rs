@@ -32,36 +32,19 @@ impl Parse for Constant {
             &format!("function {object_name}"),
         );
 
-        let version = toml
-            .lookup("version")
-            .and_then(Value::as_str)
-            .and_then(|s| s.parse().ok());
-        let cfg_condition = toml
-            .lookup("cfg_condition")
-            .and_then(Value::as_str)
-            .map(ToOwned::to_owned);
+        let version = toml.lookup_version("version");
+        let cfg_condition = toml.lookup_opt_str("cfg_condition").map(ToOwned::to_owned);
 
         let status = {
-            if toml
-                .lookup("ignore")
-                .and_then(Value::as_bool)
-                .unwrap_or(false)
-            {
+            if toml.lookup_opt_bool("ignore").unwrap_or(false) {
                 GStatus::Ignore
-            } else if toml
-                .lookup("manual")
-                .and_then(Value::as_bool)
-                .unwrap_or(false)
-            {
+            } else if toml.lookup_opt_bool("manual").unwrap_or(false) {
                 GStatus::Manual
             } else {
                 GStatus::Generate
             }
         };
-        let generate_doc = toml
-            .lookup("generate_doc")
-            .and_then(Value::as_bool)
-            .unwrap_or(true);
+        let generate_doc = toml.lookup_opt_bool("generate_doc").unwrap_or(true);
 
         Some(Self {
             ident,
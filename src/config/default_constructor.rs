@@ -0,0 +1,36 @@
+use std::str::FromStr;
+
+/// How the `Default` implementation of an object or record creates its value.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DefaultConstructor {
+    /// A constructor without arguments, by its GIR name
+    Function(String),
+    /// `glib::Object::new()`, setting no property
+    ObjectNew,
+}
+
+impl FromStr for DefaultConstructor {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err("Empty default constructor".to_owned()),
+            "Object::new" => Ok(Self::ObjectNew),
+            _ => Ok(Self::Function(s.to_owned())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_constructor_from_str() {
+        assert_eq!(
+            "new_default".parse(),
+            Ok(DefaultConstructor::Function("new_default".to_owned()))
+        );
+        assert_eq!("Object::new".parse(), Ok(DefaultConstructor::ObjectNew));
+        assert!("".parse::<DefaultConstructor>().is_err());
+    }
+}
@@ -11,7 +11,7 @@ pub struct Derive {
 
 impl Parse for Derive {
     fn parse(toml: &Value, object_name: &str) -> Option<Self> {
-        let names = match toml.lookup("name").and_then(Value::as_str) {
+        let names = match toml.lookup_opt_str("name") {
             Some(names) => names,
             None => {
                 error!("No 'name' given for derive for object {object_name}");
@@ -20,10 +20,7 @@ impl Parse for Derive {
         };
         toml.check_unwanted(&["name", "cfg_condition"], &format!("derive {object_name}"));
 
-        let cfg_condition = toml
-            .lookup("cfg_condition")
-            .and_then(Value::as_str)
-            .map(ToOwned::to_owned);
+        let cfg_condition = toml.lookup_opt_str("cfg_condition").map(ToOwned::to_owned);
 
         let mut names_vec = Vec::new();
         for name in names.split(',') {
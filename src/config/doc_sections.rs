@@ -0,0 +1,108 @@
+use super::error::*;
+
+/// Where the content of a GIR `<docsection>` ends up in doc mode
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DocSectionTarget {
+    /// Module level (`//!`) docs of the given source file, e.g. `src/lib.rs`
+    Module(String),
+    /// Standalone markdown page, relative to the target path
+    Page(String),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DocSection {
+    pub name: String,
+    pub target: DocSectionTarget,
+}
+
+pub fn read_doc_sections(toml: &toml::Value) -> Result<Vec<DocSection>, String> {
+    let v = match toml.lookup("docsection") {
+        Some(a) => a.as_result_vec("docsection")?,
+        None => return Ok(Vec::new()),
+    };
+
+    let mut doc_sections = Vec::with_capacity(v.len());
+    for o in v {
+        let name = o.lookup_str("name", "No name in docsection")?;
+        o.check_unwanted(&["name", "module", "page"], &format!("docsection {name}"));
+
+        let module = o.lookup("module").map(|m| m.as_result_str("module"));
+        let page = o.lookup("page").map(|p| p.as_result_str("page"));
+        let target = match (module, page) {
+            (Some(module), None) => DocSectionTarget::Module(module?.to_owned()),
+            (None, Some(page)) => DocSectionTarget::Page(page?.to_owned()),
+            (None, None) => DocSectionTarget::Page(format!("docs/{name}.md")),
+            (Some(_), Some(_)) => {
+                return Err(format!(
+                    "docsection {name}: `module` and `page` can't be used together"
+                ));
+            }
+        };
+        doc_sections.push(DocSection {
+            name: name.to_owned(),
+            target,
+        });
+    }
+
+    Ok(doc_sections)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn toml(input: &str) -> ::toml::Value {
+        let value = ::toml::from_str(input);
+        assert!(value.is_ok());
+        value.unwrap()
+    }
+
+    #[test]
+    fn test_read_doc_sections() {
+        let toml = toml(
+            r#"
+[[docsection]]
+name = "main-loop"
+module = "src/main_context.rs"
+
+[[docsection]]
+name = "threads"
+page = "docs/threading.md"
+
+[[docsection]]
+name = "overview"
+"#,
+        );
+        let sections = read_doc_sections(&toml).unwrap();
+        assert_eq!(
+            sections,
+            vec![
+                DocSection {
+                    name: "main-loop".to_owned(),
+                    target: DocSectionTarget::Module("src/main_context.rs".to_owned()),
+                },
+                DocSection {
+                    name: "threads".to_owned(),
+                    target: DocSectionTarget::Page("docs/threading.md".to_owned()),
+                },
+                DocSection {
+                    name: "overview".to_owned(),
+                    target: DocSectionTarget::Page("docs/overview.md".to_owned()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_doc_sections_conflicting_targets() {
+        let toml = toml(
+            r#"
+[[docsection]]
+name = "main-loop"
+module = "src/lib.rs"
+page = "docs/main-loop.md"
+"#,
+        );
+        assert!(read_doc_sections(&toml).is_err());
+    }
+}
@@ -1,4 +1,7 @@
-use log::error;
+use std::{fmt::Display, str::FromStr};
+
+use super::validation;
+use crate::version::Version;
 
 pub trait TomlHelper
 where
@@ -8,6 +11,17 @@ where
     fn lookup<'a>(&'a self, option: &str) -> Option<&'a toml::Value>;
     fn lookup_str<'a>(&'a self, option: &'a str, err: &str) -> Result<&'a str, String>;
     fn lookup_vec<'a>(&'a self, option: &'a str, err: &str) -> Result<&'a Vec<Self>, String>;
+    /// Looks up an optional boolean, reporting values of another type.
+    fn lookup_opt_bool(&self, option: &str) -> Option<bool>;
+    /// Looks up an optional string, reporting values of another type.
+    fn lookup_opt_str<'a>(&'a self, option: &str) -> Option<&'a str>;
+    /// Looks up and parses an optional string, reporting invalid values.
+    fn lookup_parsed<T>(&self, option: &str) -> Option<T>
+    where
+        T: FromStr,
+        T::Err: Display;
+    /// Looks up an optional version, reporting malformed ones.
+    fn lookup_version(&self, option: &str) -> Option<Version>;
     fn as_result_str<'a>(&'a self, option: &'a str) -> Result<&'a str, String>;
     fn as_result_vec<'a>(&'a self, option: &'a str) -> Result<&'a Vec<Self>, String>;
     fn as_result_bool<'a>(&'a self, option: &'a str) -> Result<bool, String>;
@@ -15,20 +29,16 @@ where
 
 impl TomlHelper for toml::Value {
     fn check_unwanted(&self, options: &[&str], err_msg: &str) {
-        let mut ret = Vec::new();
         let Some(table) = self.as_table() else { return };
         for (key, _) in table.iter() {
-            if !options.contains(&key.as_str()) {
-                ret.push(key.clone());
+            if options.contains(&key.as_str()) {
+                continue;
             }
-        }
-        if !ret.is_empty() {
-            error!(
-                "\"{}\": Unknown key{}: {:?}",
-                err_msg,
-                if ret.len() > 1 { "s" } else { "" },
-                ret
-            );
+            let mut message = format!("\"{err_msg}\": Unknown key `{key}`");
+            if let Some(suggestion) = validation::closest(key, options) {
+                message.push_str(&format!(", did you mean `{suggestion}`?"));
+            }
+            validation::report_key(self, key, message);
         }
     }
     fn lookup<'a>(&'a self, option: &str) -> Option<&'a toml::Value> {
@@ -47,6 +57,49 @@ impl TomlHelper for toml::Value {
         let value = self.lookup(option).ok_or(err)?;
         value.as_result_vec(option)
     }
+    fn lookup_opt_bool(&self, option: &str) -> Option<bool> {
+        let value = self.lookup(option)?;
+        if value.as_bool().is_none() {
+            report_invalid(
+                self,
+                option,
+                format!(", expected a boolean, found {}", value.type_str()),
+            );
+        }
+        value.as_bool()
+    }
+    fn lookup_opt_str<'a>(&'a self, option: &str) -> Option<&'a str> {
+        let value = self.lookup(option)?;
+        if value.as_str().is_none() {
+            report_invalid(
+                self,
+                option,
+                format!(", expected a string, found {}", value.type_str()),
+            );
+        }
+        value.as_str()
+    }
+    fn lookup_parsed<T>(&self, option: &str) -> Option<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        match self.lookup_opt_str(option)?.parse() {
+            Ok(value) => Some(value),
+            Err(e) => {
+                report_invalid(self, option, format!(": {e}"));
+                None
+            }
+        }
+    }
+    fn lookup_version(&self, option: &str) -> Option<Version> {
+        let value = self.lookup_opt_str(option)?;
+        let parts = value.split('.').collect::<Vec<_>>();
+        if parts.len() > 3 || parts.iter().any(|part| part.parse::<u16>().is_err()) {
+            report_invalid(self, option, format!(": Invalid version \"{value}\""));
+        }
+        value.parse().ok()
+    }
     fn as_result_str<'a>(&'a self, option: &'a str) -> Result<&'a str, String> {
         self.as_str().ok_or_else(|| {
             format!(
@@ -75,3 +128,13 @@ impl TomlHelper for toml::Value {
         })
     }
 }
+
+/// Reports the invalid value of `option` in `toml`, `details` follow the
+/// option name in the message.
+fn report_invalid(toml: &toml::Value, option: &str, details: String) {
+    let (table, key) = match option.rsplit_once('.') {
+        Some((path, key)) => (toml.lookup(path).unwrap_or(toml), key),
+        None => (toml, option),
+    };
+    validation::report_value(table, key, format!("Invalid `{option}` value{details}"));
+}
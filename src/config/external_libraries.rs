@@ -36,6 +36,10 @@ pub fn read_external_libraries(toml: &toml::Value) -> Result<Vec<ExternalLibrary
         for custom_lib in custom_libs {
             if let Some(info) = custom_lib.1.as_table() {
                 let namespace = custom_lib.0.as_str();
+                custom_lib.1.check_unwanted(
+                    &["crate", "min_version"],
+                    &format!("external library {namespace}"),
+                );
                 let crate_name_ = info.get("crate").map_or_else(
                     || crate_name(namespace),
                     |c| c.as_str().expect("crate name must be a string").to_string(),
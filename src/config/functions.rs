@@ -5,6 +5,7 @@ use toml::Value;
 
 use super::{
     error::TomlHelper,
+    future_style::FutureStyle,
     gobjects::GStatus,
     ident::Ident,
     parameter_matchable::Functionlike,
@@ -29,9 +30,12 @@ impl Parse for CallbackParameter {
             error!("No 'name' or 'pattern' given for parameter for object {object_name}");
             return None;
         };
-        toml.check_unwanted(&["nullable"], &format!("callback parameter {object_name}"));
+        toml.check_unwanted(
+            &["name", "pattern", "nullable"],
+            &format!("callback parameter {object_name}"),
+        );
 
-        let nullable = toml.lookup("nullable").and_then(Value::as_bool);
+        let nullable = toml.lookup_opt_bool("nullable");
 
         Some(Self { ident, nullable })
     }
@@ -80,20 +84,16 @@ impl Parse for Parameter {
             &format!("function parameter {object_name}"),
         );
 
-        let constant = toml
-            .lookup("const")
-            .and_then(Value::as_bool)
-            .unwrap_or(false);
-        let move_ = toml.lookup("move").and_then(Value::as_bool);
-        let nullable = toml.lookup("nullable").and_then(Value::as_bool);
-        let mandatory = toml.lookup("mandatory").and_then(Value::as_bool);
-        let infallible = toml.lookup("infallible").and_then(Value::as_bool);
+        let constant = toml.lookup_opt_bool("const").unwrap_or(false);
+        let move_ = toml.lookup_opt_bool("move");
+        let nullable = toml.lookup_opt_bool("nullable");
+        let mandatory = toml.lookup_opt_bool("mandatory");
+        let infallible = toml.lookup_opt_bool("infallible");
         let length_of = toml
-            .lookup("length_of")
-            .and_then(Value::as_str)
+            .lookup_opt_str("length_of")
             .map(|s| if s == "return" { "" } else { s })
             .map(ToOwned::to_owned);
-        let string_type = toml.lookup("string_type").and_then(Value::as_str);
+        let string_type = toml.lookup_opt_str("string_type");
         let string_type = match string_type {
             None => None,
             Some(val) => match StringType::from_str(val) {
@@ -139,6 +139,8 @@ pub struct Return {
     pub use_return_for_result: Option<bool>,
     pub string_type: Option<StringType>,
     pub type_name: Option<String>,
+    /// Whether the returned object may be floating and has to be sunk.
+    pub floating: Option<bool>,
 }
 
 impl Return {
@@ -153,6 +155,7 @@ impl Return {
                 use_return_for_result: None,
                 string_type: None,
                 type_name: None,
+                floating: None,
             };
         }
 
@@ -167,23 +170,23 @@ impl Return {
                 "use_return_for_result",
                 "string_type",
                 "type",
+                "floating",
             ],
             "return",
         );
 
-        let nullable = v.lookup("nullable").and_then(Value::as_bool);
-        let mandatory = v.lookup("mandatory").and_then(Value::as_bool);
-        let infallible = v.lookup("infallible").and_then(Value::as_bool);
+        let nullable = v.lookup_opt_bool("nullable");
+        let mandatory = v.lookup_opt_bool("mandatory");
+        let infallible = v.lookup_opt_bool("infallible");
         let bool_return_is_error = v
-            .lookup("bool_return_is_error")
-            .and_then(Value::as_str)
+            .lookup_opt_str("bool_return_is_error")
             .map(ToOwned::to_owned);
         let nullable_return_is_error = v
-            .lookup("nullable_return_is_error")
-            .and_then(Value::as_str)
+            .lookup_opt_str("nullable_return_is_error")
             .map(ToOwned::to_owned);
-        let use_return_for_result = v.lookup("use_return_for_result").and_then(Value::as_bool);
-        let string_type = v.lookup("string_type").and_then(Value::as_str);
+        let use_return_for_result = v.lookup_opt_bool("use_return_for_result");
+        let floating = v.lookup_opt_bool("floating");
+        let string_type = v.lookup_opt_str("string_type");
         let string_type = match string_type {
             None => None,
             Some(v) => match StringType::from_str(v) {
@@ -194,10 +197,7 @@ impl Return {
                 }
             },
         };
-        let type_name = v
-            .lookup("type")
-            .and_then(Value::as_str)
-            .map(ToOwned::to_owned);
+        let type_name = v.lookup_opt_str("type").map(ToOwned::to_owned);
         if string_type.is_some() && type_name.is_some() {
             error!(
                 "\"string_type\" and \"type\" parameters can't be passed at the same time for \
@@ -214,6 +214,7 @@ impl Return {
             use_return_for_result,
             string_type,
             type_name,
+            floating,
         }
     }
 }
@@ -246,6 +247,7 @@ pub struct Function {
     pub doc_trait_name: Option<String>,
     pub doc_struct_name: Option<String>,
     pub no_future: bool,
+    pub future_style: Option<FutureStyle>,
     pub unsafe_: bool,
     pub rename: Option<String>,
     pub visibility: Option<Visibility>,
@@ -277,6 +279,7 @@ impl Parse for Function {
                 "doc_trait_name",
                 "doc_struct_name",
                 "no_future",
+                "future_style",
                 "unsafe",
                 "rename",
                 "bypass_auto_rename",
@@ -289,36 +292,19 @@ impl Parse for Function {
         );
 
         let status = {
-            if toml
-                .lookup("ignore")
-                .and_then(Value::as_bool)
-                .unwrap_or(false)
-            {
+            if toml.lookup_opt_bool("ignore").unwrap_or(false) {
                 GStatus::Ignore
-            } else if toml
-                .lookup("manual")
-                .and_then(Value::as_bool)
-                .unwrap_or(false)
-            {
+            } else if toml.lookup_opt_bool("manual").unwrap_or(false) {
                 GStatus::Manual
             } else {
                 GStatus::Generate
             }
         };
-        let version = toml
-            .lookup("version")
-            .and_then(Value::as_str)
-            .and_then(|s| s.parse().ok());
-        let cfg_condition = toml
-            .lookup("cfg_condition")
-            .and_then(Value::as_str)
-            .map(ToOwned::to_owned);
+        let version = toml.lookup_version("version");
+        let cfg_condition = toml.lookup_opt_str("cfg_condition").map(ToOwned::to_owned);
         let parameters = Parameters::parse(toml.lookup("parameter"), object_name);
         let ret = Return::parse(toml.lookup("return"), object_name);
-        let doc_hidden = toml
-            .lookup("doc_hidden")
-            .and_then(Value::as_bool)
-            .unwrap_or(false);
+        let doc_hidden = toml.lookup_opt_bool("doc_hidden").unwrap_or(false);
         let doc_ignore_parameters = toml
             .lookup_vec("doc_ignore_parameters", "Invalid doc_ignore_parameters")
             .map(|v| {
@@ -328,40 +314,30 @@ impl Parse for Function {
             })
             .unwrap_or_default();
         let disable_length_detect = toml
-            .lookup("disable_length_detect")
-            .and_then(Value::as_bool)
+            .lookup_opt_bool("disable_length_detect")
             .unwrap_or(false);
-        let doc_trait_name = toml
-            .lookup("doc_trait_name")
-            .and_then(Value::as_str)
-            .map(ToOwned::to_owned);
+        let doc_trait_name = toml.lookup_opt_str("doc_trait_name").map(ToOwned::to_owned);
         let doc_struct_name = toml
-            .lookup("doc_struct_name")
-            .and_then(Value::as_str)
-            .map(ToOwned::to_owned);
-        let no_future = toml
-            .lookup("no_future")
-            .and_then(Value::as_bool)
-            .unwrap_or(false);
-        let unsafe_ = toml
-            .lookup("unsafe")
-            .and_then(Value::as_bool)
-            .unwrap_or(false);
-        let rename = toml
-            .lookup("rename")
-            .and_then(Value::as_str)
+            .lookup_opt_str("doc_struct_name")
             .map(ToOwned::to_owned);
+        let no_future = toml.lookup_opt_bool("no_future").unwrap_or(false);
+        let future_style = toml
+            .lookup_opt_str("future_style")
+            .map(str::parse::<FutureStyle>)
+            .transpose();
+        if let Err(ref err) = future_style {
+            error!("{err}");
+        }
+        let future_style = future_style.ok().flatten();
+        let unsafe_ = toml.lookup_opt_bool("unsafe").unwrap_or(false);
+        let rename = toml.lookup_opt_str("rename").map(ToOwned::to_owned);
         if !check_rename(&rename, object_name, &ident) {
             return None;
         }
-        let bypass_auto_rename = toml
-            .lookup("bypass_auto_rename")
-            .and_then(Value::as_bool)
-            .unwrap_or(false);
-        let is_constructor = toml.lookup("constructor").and_then(Value::as_bool);
+        let bypass_auto_rename = toml.lookup_opt_bool("bypass_auto_rename").unwrap_or(false);
+        let is_constructor = toml.lookup_opt_bool("constructor");
         let assertion = toml
-            .lookup("assertion")
-            .and_then(Value::as_str)
+            .lookup_opt_str("assertion")
             .map(|s| s.parse::<SafetyAssertionMode>())
             .transpose();
         if let Err(ref err) = assertion {
@@ -369,18 +345,14 @@ impl Parse for Function {
         }
         let assertion = assertion.ok().flatten();
         let visibility = toml
-            .lookup("visibility")
-            .and_then(Value::as_str)
+            .lookup_opt_str("visibility")
             .map(std::str::FromStr::from_str)
             .transpose();
         if let Err(ref err) = visibility {
             error!("{err}");
         }
         let visibility = visibility.ok().flatten();
-        let generate_doc = toml
-            .lookup("generate_doc")
-            .and_then(Value::as_bool)
-            .unwrap_or(true);
+        let generate_doc = toml.lookup_opt_bool("generate_doc").unwrap_or(true);
         Some(Self {
             ident,
             status,
@@ -394,6 +366,7 @@ impl Parse for Function {
             doc_trait_name,
             doc_struct_name,
             no_future,
+            future_style,
             unsafe_,
             rename,
             visibility,
@@ -604,6 +577,20 @@ nullable = true
         assert_eq!(f.ret.nullable, Some(true));
     }
 
+    #[test]
+    fn function_parse_return_floating() {
+        let toml = toml(
+            r#"
+name = "func1"
+[return]
+floating = false
+"#,
+        );
+        let f = Function::parse(&toml, "a").unwrap();
+        assert_eq!(f.ret.floating, Some(false));
+        assert_eq!(f.ret.nullable, None);
+    }
+
     #[test]
     fn function_parse_generate_doc() {
         let r = toml(
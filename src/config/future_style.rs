@@ -0,0 +1,22 @@
+use std::str::FromStr;
+
+/// Return type of the `_future` variant of async functions.
+#[derive(Default, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FutureStyle {
+    /// `Pin<Box<dyn Future>>`
+    #[default]
+    Boxed,
+    /// `impl Future`, without allocating the future
+    Impl,
+}
+
+impl FromStr for FutureStyle {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "boxed" => Ok(Self::Boxed),
+            "impl" => Ok(Self::Impl),
+            _ => Err(format!("Wrong future style '{s}'")),
+        }
+    }
+}
@@ -10,12 +10,15 @@ use toml::Value;
 use super::{
     child_properties::ChildProperties,
     constants::Constants,
+    default_constructor::DefaultConstructor,
     derives::Derives,
     functions::Functions,
     ident::Ident,
+    iterator::IteratorFunctions,
     members::Members,
     properties::Properties,
     signals::{Signal, Signals},
+    union_variants::UnionVariants,
     virtual_methods::VirtualMethods,
 };
 use crate::{
@@ -66,6 +69,8 @@ impl FromStr for GStatus {
 pub struct GObject {
     pub name: String,
     pub functions: Functions,
+    /// Methods of the class struct
+    pub class_methods: Functions,
     pub virtual_methods: VirtualMethods,
     pub constants: Constants,
     pub signals: Signals,
@@ -90,6 +95,9 @@ pub struct GObject {
     pub manual_traits: Vec<String>,
     pub align: Option<u32>,
     pub generate_builder: bool,
+    pub generate_signal_streams: bool,
+    pub generate_weak_signals: bool,
+    pub generate_property_bindings: bool,
     pub builder_postprocess: Option<String>,
     pub boxed_inline: bool,
     pub init_function_expression: Option<String>,
@@ -97,7 +105,11 @@ pub struct GObject {
     pub clear_function_expression: Option<String>,
     pub visibility: Visibility,
     pub default_value: Option<String>,
+    pub default_constructor: Option<DefaultConstructor>,
     pub generate_doc: bool,
+    pub discriminant: Option<String>,
+    pub variants: UnionVariants,
+    pub iterator: Option<IteratorFunctions>,
 }
 
 impl Default for GObject {
@@ -105,6 +117,7 @@ impl Default for GObject {
         GObject {
             name: "Default".into(),
             functions: Functions::new(),
+            class_methods: Functions::new(),
             virtual_methods: VirtualMethods::new(),
             constants: Constants::new(),
             signals: Signals::new(),
@@ -129,6 +142,9 @@ impl Default for GObject {
             manual_traits: Vec::default(),
             align: None,
             generate_builder: false,
+            generate_signal_streams: false,
+            generate_weak_signals: false,
+            generate_property_bindings: false,
             builder_postprocess: None,
             boxed_inline: false,
             init_function_expression: None,
@@ -136,7 +152,11 @@ impl Default for GObject {
             clear_function_expression: None,
             visibility: Default::default(),
             default_value: None,
+            default_constructor: None,
             generate_doc: true,
+            discriminant: None,
+            variants: Vec::new(),
+            iterator: None,
         }
     }
 }
@@ -240,6 +260,8 @@ fn parse_object(
             "name",
             "status",
             "function",
+            "class_method",
+            "virtual_method",
             "constant",
             "signal",
             "member",
@@ -264,6 +286,9 @@ fn parse_object(
             "manual_traits",
             "align",
             "generate_builder",
+            "generate_signal_streams",
+            "generate_weak_signals",
+            "generate_property_bindings",
             "builder_postprocess",
             "boxed_inline",
             "init_function_expression",
@@ -271,7 +296,11 @@ fn parse_object(
             "clear_function_expression",
             "visibility",
             "default_value",
+            "default_constructor",
             "generate_doc",
+            "discriminant",
+            "variant",
+            "iterator",
         ],
         &format!("object {name}"),
     );
@@ -291,6 +320,13 @@ fn parse_object(
             assert!(function_names.insert(name), "{name} already defined!");
         }
     }
+    let class_methods = Functions::parse(toml_object.lookup("class_method"), &name);
+    let mut class_method_names = HashSet::new();
+    for f in &class_methods {
+        if let Ident::Name(name) = &f.ident {
+            assert!(class_method_names.insert(name), "{name} already defined!");
+        }
+    }
     let virtual_methods = VirtualMethods::parse(toml_object.lookup("virtual_method"), &name);
     let mut virtual_methods_names = HashSet::new();
     for f in &virtual_methods {
@@ -320,51 +356,30 @@ fn parse_object(
         .lookup("derive")
         .map(|derives| Derives::parse(Some(derives), &name));
     let module_name = toml_object
-        .lookup("module_name")
-        .and_then(Value::as_str)
+        .lookup_opt_str("module_name")
         .map(ToOwned::to_owned);
-    let version = toml_object
-        .lookup("version")
-        .and_then(Value::as_str)
-        .and_then(|s| s.parse().ok());
+    let version = toml_object.lookup_version("version");
     let cfg_condition = toml_object
-        .lookup("cfg_condition")
-        .and_then(Value::as_str)
+        .lookup_opt_str("cfg_condition")
         .map(ToOwned::to_owned);
-    let generate_trait = toml_object.lookup("trait").and_then(Value::as_bool);
+    let generate_trait = toml_object.lookup_opt_bool("trait");
     let final_type = toml_object
-        .lookup("final_type")
-        .and_then(Value::as_bool)
+        .lookup_opt_bool("final_type")
         .or_else(|| generate_trait.map(|t| !t));
-    let fundamental_type = toml_object
-        .lookup("fundamental_type")
-        .and_then(Value::as_bool);
-    let exhaustive = toml_object
-        .lookup("exhaustive")
-        .and_then(Value::as_bool)
-        .unwrap_or(false);
+    let fundamental_type = toml_object.lookup_opt_bool("fundamental_type");
+    let exhaustive = toml_object.lookup_opt_bool("exhaustive").unwrap_or(false);
     let trait_name = toml_object
-        .lookup("trait_name")
-        .and_then(Value::as_str)
+        .lookup_opt_str("trait_name")
         .map(ToOwned::to_owned);
     let concurrency = toml_object
-        .lookup("concurrency")
-        .and_then(Value::as_str)
-        .and_then(|v| v.parse().ok())
+        .lookup_parsed("concurrency")
         .unwrap_or(concurrency);
-    let ref_mode = toml_object
-        .lookup("ref_mode")
-        .and_then(Value::as_str)
-        .and_then(|v| v.parse().ok());
+    let ref_mode = toml_object.lookup_parsed("ref_mode");
     let conversion_type = parse_conversion_type(toml_object.lookup("conversion_type"), &name);
     let child_properties = ChildProperties::parse(toml_object, &name);
-    let must_use = toml_object
-        .lookup("must_use")
-        .and_then(Value::as_bool)
-        .unwrap_or(false);
+    let must_use = toml_object.lookup_opt_bool("must_use").unwrap_or(false);
     let trust_return_value_nullability = toml_object
-        .lookup("trust_return_value_nullability")
-        .and_then(Value::as_bool)
+        .lookup_opt_bool("trust_return_value_nullability")
         .unwrap_or(trust_return_value_nullability);
     let manual_traits = toml_object
         .lookup_vec("manual_traits", "IGNORED ERROR")
@@ -384,39 +399,46 @@ fn parse_object(
             }
         });
     let generate_builder = toml_object
-        .lookup("generate_builder")
-        .and_then(Value::as_bool)
+        .lookup_opt_bool("generate_builder")
         .unwrap_or(generate_builder);
-
-    let boxed_inline = toml_object
-        .lookup("boxed_inline")
-        .and_then(Value::as_bool)
+    let generate_signal_streams = toml_object
+        .lookup_opt_bool("generate_signal_streams")
+        .unwrap_or(false);
+    let generate_weak_signals = toml_object
+        .lookup_opt_bool("generate_weak_signals")
+        .unwrap_or(false);
+    let generate_property_bindings = toml_object
+        .lookup_opt_bool("generate_property_bindings")
         .unwrap_or(false);
 
+    let boxed_inline = toml_object.lookup_opt_bool("boxed_inline").unwrap_or(false);
+
     let builder_postprocess = toml_object
-        .lookup("builder_postprocess")
-        .and_then(Value::as_str)
+        .lookup_opt_str("builder_postprocess")
         .map(String::from);
     let init_function_expression = toml_object
-        .lookup("init_function_expression")
-        .and_then(Value::as_str)
+        .lookup_opt_str("init_function_expression")
         .map(ToOwned::to_owned);
     let copy_into_function_expression = toml_object
-        .lookup("copy_into_function_expression")
-        .and_then(Value::as_str)
+        .lookup_opt_str("copy_into_function_expression")
         .map(ToOwned::to_owned);
     let clear_function_expression = toml_object
-        .lookup("clear_function_expression")
-        .and_then(Value::as_str)
+        .lookup_opt_str("clear_function_expression")
         .map(ToOwned::to_owned);
     let default_value = toml_object
-        .lookup("default_value")
-        .and_then(Value::as_str)
+        .lookup_opt_str("default_value")
         .map(ToOwned::to_owned);
+    let default_constructor = toml_object
+        .lookup_opt_str("default_constructor")
+        .map(str::parse)
+        .transpose();
+    if let Err(ref err) = default_constructor {
+        error!("{err} for object {name}");
+    }
+    let default_constructor = default_constructor.ok().flatten();
 
     let visibility = toml_object
-        .lookup("visibility")
-        .and_then(Value::as_str)
+        .lookup_opt_str("visibility")
         .map(|v| v.parse())
         .transpose();
     if let Err(ref err) = visibility {
@@ -460,10 +482,19 @@ fn parse_object(
         );
     }
 
-    let generate_doc = toml_object
-        .lookup("generate_doc")
-        .and_then(Value::as_bool)
-        .unwrap_or(true);
+    let generate_doc = toml_object.lookup_opt_bool("generate_doc").unwrap_or(true);
+
+    let discriminant = toml_object
+        .lookup_opt_str("discriminant")
+        .map(ToOwned::to_owned);
+    let variants = UnionVariants::parse(toml_object.lookup("variant"), &name);
+    if discriminant.is_none() && !variants.is_empty() {
+        warn!("`variant` configuration used without `discriminant` for object {name}");
+    }
+
+    let iterator = toml_object
+        .lookup("iterator")
+        .and_then(|v| IteratorFunctions::parse(v, &name));
 
     if generate_trait.is_some() {
         warn!("`trait` configuration is deprecated and replaced by `final_type` for object {name}");
@@ -472,6 +503,7 @@ fn parse_object(
     GObject {
         name,
         functions,
+        class_methods,
         virtual_methods,
         constants,
         signals,
@@ -496,6 +528,9 @@ fn parse_object(
         manual_traits,
         align,
         generate_builder,
+        generate_signal_streams,
+        generate_weak_signals,
+        generate_property_bindings,
         builder_postprocess,
         boxed_inline,
         init_function_expression,
@@ -503,7 +538,11 @@ fn parse_object(
         clear_function_expression,
         visibility,
         default_value,
+        default_constructor,
         generate_doc,
+        discriminant,
+        variants,
+        iterator,
     }
 }
 
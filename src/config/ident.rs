@@ -35,7 +35,7 @@ impl Eq for Ident {}
 
 impl Ident {
     pub fn parse(toml: &Value, object_name: &str, what: &str) -> Option<Self> {
-        match toml.lookup("pattern").and_then(Value::as_str) {
+        match toml.lookup_opt_str("pattern") {
             Some(s) => Regex::new(&format!("^{s}$"))
                 .map(Box::new)
                 .map(Self::Pattern)
@@ -44,7 +44,7 @@ impl Ident {
                     e
                 })
                 .ok(),
-            None => match toml.lookup("name").and_then(Value::as_str) {
+            None => match toml.lookup_opt_str("name") {
                 Some(name) => {
                     if name.contains(['.', '+', '*'].as_ref()) {
                         error!(
@@ -0,0 +1,77 @@
+use log::error;
+use toml::Value;
+
+use super::{error::TomlHelper, parsable::Parse};
+
+/// C functions iterating over the contents of an object or record.
+#[derive(Clone, Debug)]
+pub struct IteratorFunctions {
+    /// C identifier of the function creating or initializing the iterator.
+    pub init: String,
+    /// C identifier of the function advancing the iterator, returning `FALSE`
+    /// once it is exhausted and the current item in its out parameters.
+    pub next: String,
+    /// Name of the generated iterator type.
+    pub name: Option<String>,
+}
+
+impl Parse for IteratorFunctions {
+    fn parse(toml: &Value, object_name: &str) -> Option<Self> {
+        toml.check_unwanted(
+            &["init", "next", "name"],
+            &format!("iterator {object_name}"),
+        );
+
+        let Some(init) = toml.lookup_opt_str("init") else {
+            error!("No 'init' function given for iterator for object {object_name}");
+            return None;
+        };
+        let Some(next) = toml.lookup_opt_str("next") else {
+            error!("No 'next' function given for iterator for object {object_name}");
+            return None;
+        };
+        let name = toml.lookup_opt_str("name").map(ToOwned::to_owned);
+
+        Some(Self {
+            init: init.to_owned(),
+            next: next.to_owned(),
+            name,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn toml(input: &str) -> ::toml::Value {
+        let value = ::toml::from_str(input);
+        assert!(value.is_ok());
+        value.unwrap()
+    }
+
+    #[test]
+    fn iterator_functions_parse() {
+        let r = toml(
+            r#"
+init = "g_hash_table_iter_init"
+next = "g_hash_table_iter_next"
+name = "Iter"
+"#,
+        );
+        let i = IteratorFunctions::parse(&r, "a").unwrap();
+        assert_eq!(i.init, "g_hash_table_iter_init");
+        assert_eq!(i.next, "g_hash_table_iter_next");
+        assert_eq!(i.name.as_deref(), Some("Iter"));
+    }
+
+    #[test]
+    fn iterator_functions_parse_no_next() {
+        let r = toml(
+            r#"
+init = "g_hash_table_iter_init"
+"#,
+        );
+        assert!(IteratorFunctions::parse(&r, "a").is_none());
+    }
+}
@@ -2,12 +2,16 @@ mod child_properties;
 #[allow(clippy::module_inception)]
 pub mod config;
 pub mod constants;
+pub mod default_constructor;
 pub mod derives;
+pub mod doc_sections;
 pub mod error;
 mod external_libraries;
 pub mod functions;
+pub mod future_style;
 pub mod gobjects;
 pub mod ident;
+pub mod iterator;
 pub mod matchable;
 pub mod members;
 pub mod parameter_matchable;
@@ -16,12 +20,16 @@ pub mod properties;
 pub mod property_generate_flags;
 pub mod signals;
 pub mod string_type;
+pub mod union_variants;
+pub mod validation;
 pub mod virtual_methods;
 pub mod work_mode;
 
 pub use self::{
     child_properties::{ChildProperties, ChildProperty},
     config::Config,
+    default_constructor::DefaultConstructor,
+    future_style::FutureStyle,
     gobjects::GObject,
     property_generate_flags::PropertyGenerateFlags,
     string_type::StringType,
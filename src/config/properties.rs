@@ -17,6 +17,11 @@ pub struct Property {
     pub bypass_auto_rename: bool,
     pub doc_trait_name: Option<String>,
     pub generate_doc: bool,
+    pub default_value: Option<String>,
+    pub minimum: Option<String>,
+    pub maximum: Option<String>,
+    pub generate_default_const: bool,
+    pub bind: Option<bool>,
 }
 
 impl Parse for Property {
@@ -38,52 +43,53 @@ impl Parse for Property {
                 "bypass_auto_rename",
                 "doc_trait_name",
                 "generate_doc",
+                "default_value",
+                "minimum",
+                "maximum",
+                "generate_default_const",
+                "bind",
             ],
             &format!("property {object_name}"),
         );
 
         let status = {
-            if toml
-                .lookup("ignore")
-                .and_then(Value::as_bool)
-                .unwrap_or(false)
-            {
+            if toml.lookup_opt_bool("ignore").unwrap_or(false) {
                 GStatus::Ignore
-            } else if toml
-                .lookup("manual")
-                .and_then(Value::as_bool)
-                .unwrap_or(false)
-            {
+            } else if toml.lookup_opt_bool("manual").unwrap_or(false) {
                 GStatus::Manual
             } else {
                 GStatus::Generate
             }
         };
-        let version = toml
-            .lookup("version")
-            .and_then(Value::as_str)
-            .and_then(|s| s.parse().ok());
-        let cfg_condition = toml
-            .lookup("cfg_condition")
-            .and_then(Value::as_str)
-            .map(ToOwned::to_owned);
+        let version = toml.lookup_version("version");
+        let cfg_condition = toml.lookup_opt_str("cfg_condition").map(ToOwned::to_owned);
         let generate = toml.lookup("generate").and_then(|v| {
             PropertyGenerateFlags::parse_flags(v, "generate")
                 .map_err(|e| error!("{e} for object {object_name}"))
                 .ok()
         });
-        let bypass_auto_rename = toml
-            .lookup("bypass_auto_rename")
-            .and_then(Value::as_bool)
+        let bypass_auto_rename = toml.lookup_opt_bool("bypass_auto_rename").unwrap_or(false);
+        let doc_trait_name = toml.lookup_opt_str("doc_trait_name").map(ToOwned::to_owned);
+        let generate_doc = toml.lookup_opt_bool("generate_doc").unwrap_or(true);
+        let value = |key: &str| {
+            toml.lookup(key).and_then(|v| match v {
+                Value::String(s) => Some(s.clone()),
+                Value::Integer(i) => Some(i.to_string()),
+                Value::Float(f) => Some(f.to_string()),
+                Value::Boolean(b) => Some(b.to_string()),
+                _ => {
+                    error!("Invalid `{key}` for property in object {object_name}");
+                    None
+                }
+            })
+        };
+        let default_value = value("default_value");
+        let minimum = value("minimum");
+        let maximum = value("maximum");
+        let generate_default_const = toml
+            .lookup_opt_bool("generate_default_const")
             .unwrap_or(false);
-        let doc_trait_name = toml
-            .lookup("doc_trait_name")
-            .and_then(Value::as_str)
-            .map(ToOwned::to_owned);
-        let generate_doc = toml
-            .lookup("generate_doc")
-            .and_then(Value::as_bool)
-            .unwrap_or(true);
+        let bind = toml.lookup_opt_bool("bind");
 
         Some(Self {
             ident,
@@ -94,6 +100,11 @@ impl Parse for Property {
             bypass_auto_rename,
             doc_trait_name,
             generate_doc,
+            default_value,
+            minimum,
+            maximum,
+            generate_default_const,
+            bind,
         })
     }
 }
@@ -213,6 +224,53 @@ name = "prop"
         assert!(p.generate_doc);
     }
 
+    #[test]
+    fn property_default_value_and_range() {
+        let r = toml(
+            r#"
+name = "prop"
+default_value = "GTK_ALIGN_FILL"
+minimum = -1
+maximum = 1.5
+generate_default_const = true
+"#,
+        );
+        let p = Property::parse(&r, "a").unwrap();
+        assert_eq!(p.default_value.as_deref(), Some("GTK_ALIGN_FILL"));
+        assert_eq!(p.minimum.as_deref(), Some("-1"));
+        assert_eq!(p.maximum.as_deref(), Some("1.5"));
+        assert!(p.generate_default_const);
+
+        let r = toml(
+            r#"
+name = "prop"
+"#,
+        );
+        let p = Property::parse(&r, "a").unwrap();
+        assert_eq!(p.default_value, None);
+        assert!(!p.generate_default_const);
+    }
+
+    #[test]
+    fn property_bind() {
+        let r = toml(
+            r#"
+name = "prop"
+bind = true
+"#,
+        );
+        let p = Property::parse(&r, "a").unwrap();
+        assert_eq!(p.bind, Some(true));
+
+        let r = toml(
+            r#"
+name = "prop"
+"#,
+        );
+        let p = Property::parse(&r, "a").unwrap();
+        assert_eq!(p.bind, None);
+    }
+
     #[test]
     fn properties_parse_empty_for_none() {
         let props = Properties::parse(None, "a");
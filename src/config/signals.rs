@@ -52,22 +52,16 @@ impl Parse for Parameter {
             &format!("parameter {object_name}"),
         );
 
-        let nullable = toml.lookup("nullable").and_then(Value::as_bool);
-        let transformation = toml
-            .lookup("transformation")
-            .and_then(Value::as_str)
-            .and_then(|s| {
-                TransformationType::from_str(s)
-                    .map_err(|err| {
-                        error!("{err}");
-                        err
-                    })
-                    .ok()
-            });
-        let new_name = toml
-            .lookup("new_name")
-            .and_then(Value::as_str)
-            .map(ToOwned::to_owned);
+        let nullable = toml.lookup_opt_bool("nullable");
+        let transformation = toml.lookup_opt_str("transformation").and_then(|s| {
+            TransformationType::from_str(s)
+                .map_err(|err| {
+                    error!("{err}");
+                    err
+                })
+                .ok()
+        });
+        let new_name = toml.lookup_opt_str("new_name").map(ToOwned::to_owned);
 
         Some(Self {
             ident,
@@ -99,6 +93,8 @@ pub struct Signal {
     pub doc_trait_name: Option<String>,
     pub generate_doc: bool,
     pub cfg_condition: Option<String>,
+    pub stream: Option<bool>,
+    pub weak: Option<bool>,
 }
 
 impl Signal {
@@ -116,6 +112,8 @@ impl Signal {
                 "ignore",
                 "manual",
                 "inhibit",
+                "stream",
+                "weak",
                 "version",
                 "cfg_condition",
                 "parameter",
@@ -131,56 +129,28 @@ impl Signal {
         );
 
         let status = {
-            if toml
-                .lookup("ignore")
-                .and_then(Value::as_bool)
-                .unwrap_or(false)
-            {
+            if toml.lookup_opt_bool("ignore").unwrap_or(false) {
                 GStatus::Ignore
-            } else if toml
-                .lookup("manual")
-                .and_then(Value::as_bool)
-                .unwrap_or(false)
-            {
+            } else if toml.lookup_opt_bool("manual").unwrap_or(false) {
                 GStatus::Manual
             } else {
                 GStatus::Generate
             }
         };
 
-        let inhibit = toml
-            .lookup("inhibit")
-            .and_then(Value::as_bool)
-            .unwrap_or(false);
-        let version = toml
-            .lookup("version")
-            .and_then(Value::as_str)
-            .and_then(|s| s.parse().ok());
-        let cfg_condition = toml
-            .lookup("cfg_condition")
-            .and_then(Value::as_str)
-            .map(ToOwned::to_owned);
+        let inhibit = toml.lookup_opt_bool("inhibit").unwrap_or(false);
+        let version = toml.lookup_version("version");
+        let cfg_condition = toml.lookup_opt_str("cfg_condition").map(ToOwned::to_owned);
         let parameters = Parameters::parse(toml.lookup("parameter"), object_name);
         let ret = Return::parse(toml.lookup("return"), object_name);
 
-        let concurrency = toml
-            .lookup("concurrency")
-            .and_then(Value::as_str)
-            .and_then(|v| v.parse().ok())
-            .unwrap_or(concurrency);
+        let concurrency = toml.lookup_parsed("concurrency").unwrap_or(concurrency);
 
-        let doc_hidden = toml
-            .lookup("doc_hidden")
-            .and_then(Value::as_bool)
-            .unwrap_or(false);
-        let doc_trait_name = toml
-            .lookup("doc_trait_name")
-            .and_then(Value::as_str)
-            .map(ToOwned::to_owned);
-        let generate_doc = toml
-            .lookup("generate_doc")
-            .and_then(Value::as_bool)
-            .unwrap_or(true);
+        let doc_hidden = toml.lookup_opt_bool("doc_hidden").unwrap_or(false);
+        let doc_trait_name = toml.lookup_opt_str("doc_trait_name").map(ToOwned::to_owned);
+        let generate_doc = toml.lookup_opt_bool("generate_doc").unwrap_or(true);
+        let stream = toml.lookup_opt_bool("stream");
+        let weak = toml.lookup_opt_bool("weak");
 
         Some(Self {
             ident,
@@ -194,6 +164,8 @@ impl Signal {
             doc_trait_name,
             generate_doc,
             cfg_condition,
+            stream,
+            weak,
         })
     }
 }
@@ -280,4 +252,27 @@ name = "prop"
         let f = Signal::parse(&r, "a", Default::default()).unwrap();
         assert!(f.generate_doc);
     }
+
+    #[test]
+    fn signal_parse_variants() {
+        let r = toml(
+            r#"
+name = "signal1"
+stream = true
+weak = false
+"#,
+        );
+        let f = Signal::parse(&r, "a", Default::default()).unwrap();
+        assert_eq!(f.stream, Some(true));
+        assert_eq!(f.weak, Some(false));
+
+        let r = toml(
+            r#"
+name = "signal1"
+"#,
+        );
+        let f = Signal::parse(&r, "a", Default::default()).unwrap();
+        assert_eq!(f.stream, None);
+        assert_eq!(f.weak, None);
+    }
 }
@@ -0,0 +1,93 @@
+use log::error;
+use toml::Value;
+
+use super::{error::TomlHelper, parsable::Parse};
+
+/// Maps a value of a union's discriminant field to the union member that is
+/// valid for it.
+#[derive(Clone, Debug)]
+pub struct UnionVariant {
+    /// Discriminant value: an enumeration member name or an integer.
+    pub value: String,
+    /// Union member holding the payload, `None` for variants without payload.
+    pub member: Option<String>,
+    /// Name of the generated Rust variant.
+    pub name: Option<String>,
+}
+
+impl Parse for UnionVariant {
+    fn parse(toml: &Value, object_name: &str) -> Option<Self> {
+        toml.check_unwanted(
+            &["value", "member", "name"],
+            &format!("variant {object_name}"),
+        );
+
+        let value = match toml.lookup("value") {
+            Some(Value::String(s)) => s.clone(),
+            Some(Value::Integer(i)) => i.to_string(),
+            _ => {
+                error!("No valid 'value' given for variant for object {object_name}");
+                return None;
+            }
+        };
+        let member = toml.lookup_opt_str("member").map(ToOwned::to_owned);
+        let name = toml.lookup_opt_str("name").map(ToOwned::to_owned);
+
+        Some(Self {
+            value,
+            member,
+            name,
+        })
+    }
+}
+
+pub type UnionVariants = Vec<UnionVariant>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn toml(input: &str) -> ::toml::Value {
+        let value = ::toml::from_str(input);
+        assert!(value.is_ok());
+        value.unwrap()
+    }
+
+    #[test]
+    fn union_variant_parse() {
+        let r = toml(
+            r#"
+value = "key_press"
+member = "key"
+name = "Key"
+"#,
+        );
+        let v = UnionVariant::parse(&r, "a").unwrap();
+        assert_eq!(v.value, "key_press");
+        assert_eq!(v.member.as_deref(), Some("key"));
+        assert_eq!(v.name.as_deref(), Some("Key"));
+    }
+
+    #[test]
+    fn union_variant_parse_integer_value() {
+        let r = toml(
+            r#"
+value = 3
+"#,
+        );
+        let v = UnionVariant::parse(&r, "a").unwrap();
+        assert_eq!(v.value, "3");
+        assert_eq!(v.member, None);
+        assert_eq!(v.name, None);
+    }
+
+    #[test]
+    fn union_variant_parse_no_value() {
+        let r = toml(
+            r#"
+member = "key"
+"#,
+        );
+        assert!(UnionVariant::parse(&r, "a").is_none());
+    }
+}
@@ -0,0 +1,320 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt::{self, Display},
+    path::Path,
+};
+
+use log::error;
+use toml::{
+    Spanned,
+    de::{DeTable, DeValue},
+};
+
+thread_local! {
+    static SOURCE: RefCell<Option<Source>> = const { RefCell::new(None) };
+}
+
+/// Line and column, both starting at 1, of a position in a configuration file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// A problem found while parsing a configuration file.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Problem {
+    pub position: Option<Position>,
+    pub message: String,
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.position {
+            Some(Position { line, column }) => write!(f, "{line}:{column}: {}", self.message),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+/// Positions of the entries of a table.
+#[derive(Default)]
+struct Entries {
+    keys: HashMap<String, Position>,
+    values: HashMap<String, Position>,
+}
+
+/// Configuration file being parsed.
+struct Source {
+    file: String,
+    /// Entries of the tables of the parsed configuration, by table address.
+    tables: HashMap<*const toml::Table, Entries>,
+    problems: Vec<Problem>,
+}
+
+/// Locates the problems reported while parsing the configuration `toml`,
+/// read from `input`, until the returned guard is finished or dropped.
+///
+/// `toml` must stay in place and unchanged while the guard is alive.
+pub fn locate(file: &Path, input: &str, toml: &toml::Value) -> Located {
+    let mut tables = HashMap::new();
+    if let Ok(root) = DeTable::parse(input) {
+        let lines = input
+            .match_indices('\n')
+            .map(|(pos, _)| pos + 1)
+            .collect::<Vec<_>>();
+        if let toml::Value::Table(table) = toml {
+            index_table(&mut tables, input, &lines, table, root.get_ref());
+        }
+    }
+    SOURCE.with_borrow_mut(|source| {
+        *source = Some(Source {
+            file: file.display().to_string(),
+            tables,
+            problems: Vec::new(),
+        });
+    });
+    Located(())
+}
+
+/// Guard returned by [`locate`].
+pub struct Located(());
+
+impl Located {
+    /// Returns the problems reported since [`locate`].
+    pub fn finish(self) -> Vec<Problem> {
+        SOURCE.with_borrow_mut(|source| {
+            source
+                .as_mut()
+                .map(|source| std::mem::take(&mut source.problems))
+                .unwrap_or_default()
+        })
+    }
+}
+
+impl Drop for Located {
+    fn drop(&mut self) {
+        SOURCE.with_borrow_mut(|source| *source = None);
+    }
+}
+
+fn index_table(
+    tables: &mut HashMap<*const toml::Table, Entries>,
+    input: &str,
+    lines: &[usize],
+    table: &toml::Table,
+    de_table: &DeTable<'_>,
+) {
+    let mut entries = Entries::default();
+    for (key, de_value) in de_table {
+        let Some(value) = table.get(key.get_ref().as_ref()) else {
+            continue;
+        };
+        entries
+            .keys
+            .insert(key.get_ref().to_string(), position(input, lines, key));
+        entries
+            .values
+            .insert(key.get_ref().to_string(), position(input, lines, de_value));
+        index_value(tables, input, lines, value, de_value.get_ref());
+    }
+    tables.insert(table, entries);
+}
+
+fn index_value(
+    tables: &mut HashMap<*const toml::Table, Entries>,
+    input: &str,
+    lines: &[usize],
+    value: &toml::Value,
+    de_value: &DeValue<'_>,
+) {
+    match (value, de_value) {
+        (toml::Value::Table(table), DeValue::Table(de_table)) => {
+            index_table(tables, input, lines, table, de_table);
+        }
+        (toml::Value::Array(array), DeValue::Array(de_array)) => {
+            for (value, de_value) in array.iter().zip(de_array) {
+                index_value(tables, input, lines, value, de_value.get_ref());
+            }
+        }
+        _ => (),
+    }
+}
+
+fn position<T>(input: &str, lines: &[usize], spanned: &Spanned<T>) -> Position {
+    let start = spanned.span().start;
+    let line = lines.partition_point(|&line_start| line_start <= start);
+    let line_start = line.checked_sub(1).map_or(0, |i| lines[i]);
+    Position {
+        line: line + 1,
+        column: input[line_start..start].chars().count() + 1,
+    }
+}
+
+/// Reports a problem with the key `key` of `table`.
+pub fn report_key(table: &toml::Value, key: &str, message: String) {
+    report(table, key, message, |entries| &entries.keys);
+}
+
+/// Reports a problem with the value of the key `key` of `table`.
+pub fn report_value(table: &toml::Value, key: &str, message: String) {
+    report(table, key, message, |entries| &entries.values);
+}
+
+fn report(
+    table: &toml::Value,
+    key: &str,
+    message: String,
+    positions: impl FnOnce(&Entries) -> &HashMap<String, Position>,
+) {
+    SOURCE.with_borrow_mut(|source| {
+        let Some(source) = source else {
+            error!("{message}");
+            return;
+        };
+        let position = table
+            .as_table()
+            .and_then(|table| source.tables.get(&std::ptr::from_ref(table)))
+            .and_then(|entries| positions(entries).get(key))
+            .copied();
+        let problem = Problem { position, message };
+        error!("{}:{problem}", source.file);
+        source.problems.push(problem);
+    });
+}
+
+/// Returns the allowed key closest to the unknown `name`, if any is close
+/// enough to be a likely typo.
+pub fn closest<'a>(name: &str, keys: &[&'a str]) -> Option<&'a str> {
+    keys.iter()
+        .map(|key| (edit_distance(name, key), *key))
+        .filter(|&(distance, key)| distance <= (key.len() / 3).max(1))
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, key)| key)
+}
+
+/// Levenshtein distance between `a` and `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                previous
+            } else {
+                previous.min(current).min(row[j]) + 1
+            };
+            previous = current;
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{error::TomlHelper, functions::Functions, parsable::Parsable};
+
+    fn problems(input: &str, parse: impl FnOnce(&toml::Value)) -> Vec<(usize, usize, String)> {
+        let toml = toml::from_str(input).unwrap();
+        let located = locate(Path::new("Gir.toml"), input, &toml);
+        parse(&toml);
+        located
+            .finish()
+            .into_iter()
+            .map(|problem| {
+                let position = problem.position.unwrap();
+                (position.line, position.column, problem.message)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn valid_function() {
+        let input = r#"
+[[function]]
+name = "show"
+version = "4.2"
+    [[function.parameter]]
+    name = "widget"
+    nullable = true
+    [function.return]
+    nullable = false
+"#;
+        let problems = problems(input, |toml| {
+            Functions::parse(toml.lookup("function"), "Gtk.Widget");
+        });
+        assert_eq!(problems, Vec::new());
+    }
+
+    #[test]
+    fn unknown_keys() {
+        let input = r#"
+[[function]]
+name = "show"
+cfg_conditon = "feature = \"v4_6\""
+    [[function.parameter]]
+    name = "widget"
+    colour = true
+"#;
+        let problems = problems(input, |toml| {
+            Functions::parse(toml.lookup("function"), "Gtk.Widget");
+        });
+        assert_eq!(
+            problems,
+            vec![
+                (
+                    4,
+                    1,
+                    "\"function Gtk.Widget\": Unknown key `cfg_conditon`, did you mean `cfg_condition`?"
+                        .into()
+                ),
+                (
+                    7,
+                    5,
+                    "\"function parameter Gtk.Widget\": Unknown key `colour`".into()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_values() {
+        let input = r#"
+[[function]]
+name = "show"
+version = "4.x"
+unsafe = "true"
+"#;
+        let problems = problems(input, |toml| {
+            Functions::parse(toml.lookup("function"), "Gtk.Widget");
+        });
+        assert_eq!(
+            problems,
+            vec![
+                (
+                    4,
+                    11,
+                    "Invalid `version` value: Invalid version \"4.x\"".into()
+                ),
+                (
+                    5,
+                    10,
+                    "Invalid `unsafe` value, expected a boolean, found string".into()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn closest_key() {
+        let keys = ["name", "cfg_condition", "nullable"];
+        assert_eq!(closest("cfg-condition", &keys), Some("cfg_condition"));
+        assert_eq!(closest("nulable", &keys), Some("nullable"));
+        assert_eq!(closest("colour", &keys), None);
+    }
+}
@@ -57,36 +57,19 @@ impl Parse for VirtualMethod {
         );
 
         let status = {
-            if toml
-                .lookup("ignore")
-                .and_then(Value::as_bool)
-                .unwrap_or(false)
-            {
+            if toml.lookup_opt_bool("ignore").unwrap_or(false) {
                 GStatus::Ignore
-            } else if toml
-                .lookup("manual")
-                .and_then(Value::as_bool)
-                .unwrap_or(false)
-            {
+            } else if toml.lookup_opt_bool("manual").unwrap_or(false) {
                 GStatus::Manual
             } else {
                 GStatus::Generate
             }
         };
-        let version = toml
-            .lookup("version")
-            .and_then(Value::as_str)
-            .and_then(|s| s.parse().ok());
-        let cfg_condition = toml
-            .lookup("cfg_condition")
-            .and_then(Value::as_str)
-            .map(ToOwned::to_owned);
+        let version = toml.lookup_version("version");
+        let cfg_condition = toml.lookup_opt_str("cfg_condition").map(ToOwned::to_owned);
         let parameters = Parameters::parse(toml.lookup("parameter"), object_name);
         let ret = Return::parse(toml.lookup("return"), object_name);
-        let doc_hidden = toml
-            .lookup("doc_hidden")
-            .and_then(Value::as_bool)
-            .unwrap_or(false);
+        let doc_hidden = toml.lookup_opt_bool("doc_hidden").unwrap_or(false);
         let doc_ignore_parameters = toml
             .lookup_vec("doc_ignore_parameters", "Invalid doc_ignore_parameters")
             .map(|v| {
@@ -95,30 +78,15 @@ impl Parse for VirtualMethod {
                     .collect()
             })
             .unwrap_or_default();
-        let doc_trait_name = toml
-            .lookup("doc_trait_name")
-            .and_then(Value::as_str)
-            .map(ToOwned::to_owned);
-        let unsafe_ = toml
-            .lookup("unsafe")
-            .and_then(Value::as_bool)
-            .unwrap_or(false);
-        let rename = toml
-            .lookup("rename")
-            .and_then(Value::as_str)
-            .map(ToOwned::to_owned);
+        let doc_trait_name = toml.lookup_opt_str("doc_trait_name").map(ToOwned::to_owned);
+        let unsafe_ = toml.lookup_opt_bool("unsafe").unwrap_or(false);
+        let rename = toml.lookup_opt_str("rename").map(ToOwned::to_owned);
         if !check_rename(&rename, object_name, &ident) {
             return None;
         }
-        let bypass_auto_rename = toml
-            .lookup("bypass_auto_rename")
-            .and_then(Value::as_bool)
-            .unwrap_or(false);
+        let bypass_auto_rename = toml.lookup_opt_bool("bypass_auto_rename").unwrap_or(false);
 
-        let generate_doc = toml
-            .lookup("generate_doc")
-            .and_then(Value::as_bool)
-            .unwrap_or(true);
+        let generate_doc = toml.lookup_opt_bool("generate_doc").unwrap_or(true);
         Some(Self {
             ident,
             status,
@@ -7,6 +7,7 @@ pub enum WorkMode {
     Sys,             // generate -sys with FFI
     Doc,             // generate documentation file
     DisplayNotBound, // Show not bound types
+    Graph,           // export type hierarchy and dependency graph
 }
 
 impl WorkMode {
@@ -27,6 +28,7 @@ impl FromStr for WorkMode {
             "sys" => Ok(Self::Sys),
             "doc" => Ok(Self::Doc),
             "not_bound" => Ok(Self::DisplayNotBound),
+            "graph" => Ok(Self::Graph),
             _ => Err(format!("Wrong work mode '{s}'")),
         }
     }
@@ -145,6 +145,32 @@ impl Basic {
                 | Self::Bool
         )
     }
+
+    pub fn is_numeric(&self) -> bool {
+        matches!(
+            self,
+            Self::Int8
+                | Self::UInt8
+                | Self::Int16
+                | Self::UInt16
+                | Self::Int32
+                | Self::UInt32
+                | Self::Int64
+                | Self::UInt64
+                | Self::Char
+                | Self::UChar
+                | Self::Short
+                | Self::UShort
+                | Self::Int
+                | Self::UInt
+                | Self::Long
+                | Self::ULong
+                | Self::Size
+                | Self::SSize
+                | Self::Float
+                | Self::Double
+        )
+    }
 }
 
 const BASIC: &[(&str, Basic)] = &[
@@ -390,6 +416,12 @@ pub struct Property {
     pub doc_deprecated: Option<String>,
     pub getter: Option<String>,
     pub setter: Option<String>,
+    /// The `default-value` from the GIR, as written there (e.g. `TRUE` or
+    /// `GTK_ALIGN_FILL`)
+    pub default_value: Option<String>,
+    /// Only set through the configuration, the GIR doesn't contain ranges
+    pub minimum: Option<String>,
+    pub maximum: Option<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -711,7 +743,7 @@ impl Parameter {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Function {
     pub name: String,
     pub c_identifier: String,
@@ -787,6 +819,9 @@ pub struct Class {
     /// Specific to fundamental types
     pub ref_fn: Option<String>,
     pub unref_fn: Option<String>,
+    /// Specific to fundamental types, accessors of their `GValue`s
+    pub set_value_fn: Option<String>,
+    pub get_value_fn: Option<String>,
 }
 
 #[derive(Debug)]
@@ -847,6 +882,7 @@ pub enum Type {
     Class(Class),
     Custom(Custom),
     Array(TypeId),
+    ByteArray,
     CArray(TypeId),
     FixedArray(TypeId, u16, Option<String>),
     PtrArray(TypeId),
@@ -869,6 +905,7 @@ impl fmt::Display for Type {
             Self::Class(_) => "Class",
             Self::Custom(_) => "Custom",
             Self::Array(_) => "Array",
+            Self::ByteArray => "ByteArray",
             Self::CArray(_) => "CArray",
             Self::FixedArray(_, _, _) => "FixedArray",
             Self::PtrArray(_) => "PtrArray",
@@ -891,6 +928,7 @@ impl Type {
             Self::Function(func) => func.name.clone(),
             Self::Interface(interface) => interface.name.clone(),
             Self::Array(type_id) => format!("Array {type_id:?}"),
+            Self::ByteArray => "ByteArray".to_owned(),
             Self::Class(class) => class.name.clone(),
             Self::Custom(custom) => custom.name.clone(),
             Self::CArray(type_id) => format!("CArray {type_id:?}"),
@@ -915,6 +953,7 @@ impl Type {
             Self::Function(func) => func.deprecated_version,
             Self::Interface(interface) => interface.deprecated_version,
             Self::Array(_) => None,
+            Self::ByteArray => None,
             Self::Class(class) => class.deprecated_version,
             Self::Custom(_) => None,
             Self::CArray(_) => None,
@@ -979,6 +1018,7 @@ impl Type {
                 let tid = inner.remove(0);
                 Some((format!("Array(#{tid:?})"), Self::Array(tid)))
             }
+            ("GLib.ByteArray", 0) => Some(("ByteArray".to_owned(), Self::ByteArray)),
             ("GLib.PtrArray", 1) => {
                 let tid = inner.remove(0);
                 Some((format!("PtrArray(#{tid:?})"), Self::PtrArray(tid)))
@@ -1153,6 +1193,13 @@ impl<U> MaybeRefAs for U {
     }
 }
 
+/// A `<docsection>`: conceptual documentation not attached to any item
+#[derive(Debug)]
+pub struct DocSection {
+    pub name: String,
+    pub doc: String,
+}
+
 #[derive(Debug, Default)]
 pub struct Namespace {
     pub name: String,
@@ -1165,6 +1212,7 @@ pub struct Namespace {
     pub versions: BTreeSet<Version>,
     pub doc: Option<String>,
     pub doc_deprecated: Option<String>,
+    pub doc_sections: Vec<DocSection>,
     pub shared_library: Vec<String>,
     pub identifier_prefixes: Vec<String>,
     pub symbol_prefixes: Vec<String>,
@@ -44,6 +44,7 @@ impl Library {
         self.mark_final_types(config);
         self.update_error_domain_functions(config);
         self.mark_ignored_enum_members(config);
+        self.override_property_values(config);
     }
 
     fn fix_gtype(&mut self) {
@@ -657,4 +658,37 @@ impl Library {
             };
         }
     }
+
+    fn override_property_values(&mut self, config: &Config) {
+        for (full_name, obj) in &config.objects {
+            if !obj
+                .properties
+                .iter()
+                .any(|p| p.default_value.is_some() || p.minimum.is_some() || p.maximum.is_some())
+            {
+                continue;
+            }
+            let Some(tid) = self.find_type(0, full_name) else {
+                continue;
+            };
+            let properties = match self.type_mut(tid) {
+                Type::Class(Class { properties, .. })
+                | Type::Interface(Interface { properties, .. }) => properties,
+                _ => continue,
+            };
+            for property in properties {
+                for configured in obj.properties.matched(&property.name) {
+                    if let Some(ref default_value) = configured.default_value {
+                        property.default_value = Some(default_value.clone());
+                    }
+                    if let Some(ref minimum) = configured.minimum {
+                        property.minimum = Some(minimum.clone());
+                    }
+                    if let Some(ref maximum) = configured.maximum {
+                        property.maximum = Some(maximum.clone());
+                    }
+                }
+            }
+        }
+    }
 }
@@ -49,7 +49,7 @@ fn build_config() -> Result<RunKind, String> {
     options.optopt(
         "m",
         "mode",
-        "Work mode: doc, normal, sys or not_bound",
+        "Work mode: doc, normal, sys, not_bound or graph",
         "MODE",
     );
     options.optopt("o", "target", "Target path", "PATH");
@@ -57,6 +57,11 @@ fn build_config() -> Result<RunKind, String> {
     options.optflag("b", "make-backup", "Make backup before generating");
     options.optflag("s", "stats", "Show statistics");
     options.optflag("", "disable-format", "Disable formatting generated code");
+    options.optflag(
+        "",
+        "strict",
+        "Fail on unknown keys or invalid values in the config file",
+    );
     options.optopt(
         "",
         "check-gir-file",
@@ -97,6 +102,7 @@ fn build_config() -> Result<RunKind, String> {
         matches.opt_present("b"),
         matches.opt_present("s"),
         matches.opt_present("disable-format"),
+        matches.opt_present("strict"),
     )
     .map(RunKind::Config)
 }
@@ -198,7 +204,7 @@ fn main() -> Result<(), String> {
 
     if env.config.work_mode != WorkMode::DisplayNotBound {
         let _watcher = statistics.enter("Generating");
-        gir::codegen_generate(&env);
+        gir::codegen_generate(&env)?;
     }
 
     if !env.config.disable_format && env.config.work_mode.is_generate_rust_files() {
@@ -1,6 +1,10 @@
-use std::path::{Path, PathBuf};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
 
 use log::{error, trace, warn};
+use xmlserde_derives::XmlDeserialize;
 
 use crate::{library::*, version::Version};
 
@@ -29,15 +33,27 @@ impl Library {
             let dir: &Path = dir.as_ref();
             let file_name = make_file_name(dir, &libs[libs.len() - 1]);
             trace!("Reading GIR file {}", file_name.display());
-            let mut repo = match Repository::from_path(&file_name) {
+            let Ok(content) = fs::read_to_string(&file_name) else {
+                continue;
+            };
+            let mut repo = match content.parse::<Repository>() {
                 Ok(repo) => repo,
-                Err(gir_parser::ParserError::IO(_)) => continue,
                 Err(e) => {
                     warn!("Failed to parse GIR file {}: {e}", file_name.display());
                     continue;
                 }
             };
             self.read_repository(dirs, &mut repo, libs)?;
+            if let Some((ns_id, _)) = self.find_namespace(repo.namespace().name()) {
+                self.namespace_mut(ns_id).doc_sections = read_doc_sections(&content)
+                    .unwrap_or_else(|e| {
+                        warn!(
+                            "Failed to read doc sections of {}: {e}",
+                            file_name.display()
+                        );
+                        Vec::new()
+                    });
+            }
             return Ok(());
         }
         Err(format!("Couldn't find `{}`...", &libs[libs.len() - 1]))
@@ -237,13 +253,15 @@ impl Library {
         let version = self.read_version(ns_id, elem.version());
         let deprecated_version = self.read_version(ns_id, elem.deprecated_version());
         let is_fundamental = elem.is_fundamental();
-        let (ref_fn, unref_fn) = if is_fundamental {
+        let (ref_fn, unref_fn, set_value_fn, get_value_fn) = if is_fundamental {
             (
                 elem.g_ref_func().map(ToOwned::to_owned),
                 elem.g_unref_func().map(ToOwned::to_owned),
+                elem.g_set_value_func().map(ToOwned::to_owned),
+                elem.g_get_value_func().map(ToOwned::to_owned),
             )
         } else {
-            (None, None)
+            (None, None, None, None)
         };
 
         let is_abstract = elem.is_abstract();
@@ -380,6 +398,8 @@ impl Library {
             is_fundamental,
             ref_fn,
             unref_fn,
+            set_value_fn,
+            get_value_fn,
         });
         self.add_type(ns_id, &name, typ);
         Ok(())
@@ -1395,6 +1415,9 @@ impl Library {
             doc_deprecated,
             getter,
             setter,
+            default_value: elem.default_value().map(ToOwned::to_owned),
+            minimum: None,
+            maximum: None,
         })
     }
 
@@ -1411,7 +1434,8 @@ impl Library {
             let (tid, c_type, _) = self.read_type(ns_id, elem.ty())?;
             Type::c_array(self, tid, elem.fixed_size(), c_type)
         } else if type_name == "GLib.ByteArray" {
-            self.find_or_stub_type(ns_id, type_name)
+            // The element type is always `guint8`
+            Type::container(self, type_name, Vec::new()).expect("GLib.ByteArray container")
         } else {
             let inner = elem.ty();
             let (inner_ty, _c_type, _) = self.read_type(ns_id, inner)?;
@@ -1471,9 +1495,80 @@ impl Library {
     }
 }
 
+/// Repository with only the `<docsection>` elements of its namespace, which
+/// `gir_parser::Namespace` doesn't expose.
+#[derive(XmlDeserialize)]
+#[xmlserde(root = b"repository")]
+struct DocSectionRepository {
+    #[xmlserde(name = b"namespace", ty = "child")]
+    namespace: DocSectionNamespace,
+}
+
+#[derive(XmlDeserialize)]
+struct DocSectionNamespace {
+    #[xmlserde(name = b"docsection", ty = "child")]
+    doc_sections: Vec<DocSectionElement>,
+}
+
+#[derive(XmlDeserialize)]
+struct DocSectionElement {
+    #[xmlserde(name = b"name", ty = "attr")]
+    name: String,
+    #[xmlserde(name = b"doc", ty = "child")]
+    doc: Option<gir_parser::Documentation>,
+}
+
+/// Reads the doc sections of the GIR `content` with the XML deserializer of
+/// `gir-parser`, doc sections without documentation are skipped.
+fn read_doc_sections(content: &str) -> Result<Vec<DocSection>, String> {
+    let repo: DocSectionRepository = xmlserde::xml_deserialize_from_str(content)?;
+    Ok(repo
+        .namespace
+        .doc_sections
+        .into_iter()
+        .filter_map(|section| {
+            Some(DocSection {
+                name: section.name,
+                doc: section.doc?.text().to_owned(),
+            })
+        })
+        .collect())
+}
+
 fn make_file_name(dir: &Path, name: &str) -> PathBuf {
     let mut path = dir.to_path_buf();
     let name = format!("{name}.gir");
     path.push(name);
     path
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn doc_sections() {
+        let sections = read_doc_sections(
+            r#"<repository version="1.2" xmlns="http://www.gtk.org/introspection/core/1.0">
+  <namespace name="Test">
+    <!-- <docsection name="commented"><doc>Commented out</doc></docsection> -->
+    <docsection name="main-loop">
+      <doc xml:space="preserve" filename="gmain.c" line="12">The main loop, see [struct@GLib.MainContext].</doc>
+    </docsection>
+    <docsection name="empty"/>
+    <docsection name="no-doc">
+    </docsection>
+    <class name="Widget"/>
+  </namespace>
+  <doc:format name="gi-docgen"/>
+</repository>"#,
+        )
+        .unwrap();
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].name, "main-loop");
+        assert_eq!(
+            sections[0].doc,
+            "The main loop, see [struct@GLib.MainContext]."
+        );
+    }
+}
@@ -2,8 +2,11 @@ use std::fmt::Write;
 
 use super::primitives::*;
 use crate::{
+    analysis::conversion_type::is_direct_fixed_array,
     chunk::{Chunk, Param, TupleMode},
-    codegen::{translate_from_glib::TranslateFromGlib, translate_to_glib::TranslateToGlib},
+    codegen::{
+        Visibility, translate_from_glib::TranslateFromGlib, translate_to_glib::TranslateToGlib,
+    },
     env::Env,
     nameutil::use_glib_type,
 };
@@ -49,6 +52,9 @@ impl ToCode for Chunk {
             FfiCallOutParameter { ref par } => {
                 let s = if par.caller_allocates {
                     format!("{}.to_glib_none_mut().0", par.name)
+                } else if par.is_uninitialized && is_direct_fixed_array(env, par.typ) {
+                    // Points to the whole array, the function takes its first element
+                    format!("{}.as_mut_ptr() as *mut _", par.name)
                 } else if par.is_uninitialized && !par.is_error {
                     format!("{}.as_mut_ptr()", par.name)
                 } else {
@@ -176,12 +182,18 @@ impl ToCode for Chunk {
             Name(ref name) => vec![name.clone()],
             ExternCFunc {
                 ref name,
+                visibility,
                 ref parameters,
                 ref body,
                 ref return_value,
                 ref bounds,
             } => {
-                let prefix = format!(r#"unsafe extern "C" fn {name}{bounds}("#);
+                let visibility = if visibility == Visibility::Private {
+                    String::new()
+                } else {
+                    format!("{visibility} ")
+                };
+                let prefix = format!(r#"{visibility}unsafe extern "C" fn {name}{bounds}("#);
                 let suffix = ")".to_string();
                 let params: Vec<_> = parameters
                     .iter()